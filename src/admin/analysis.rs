@@ -6,6 +6,8 @@ use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
+use crate::models::analysis::{EeatSignalAnalysis, IssueSeverity, LinkingOpportunity, NamedEntity, RelatedKeyword, ScoreTrend, ParagraphDistribution, PassageScore};
+
 /// SEO Analysis overview
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnalysisOverview {
@@ -60,35 +62,6 @@ pub struct TopIssue {
     pub how_to_fix: String,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum IssueSeverity {
-    Critical,
-    Warning,
-    Suggestion,
-    Info,
-}
-
-impl IssueSeverity {
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            IssueSeverity::Critical => "critical",
-            IssueSeverity::Warning => "warning",
-            IssueSeverity::Suggestion => "suggestion",
-            IssueSeverity::Info => "info",
-        }
-    }
-
-    pub fn color(&self) -> &'static str {
-        match self {
-            IssueSeverity::Critical => "#dc3545",
-            IssueSeverity::Warning => "#ffc107",
-            IssueSeverity::Suggestion => "#17a2b8",
-            IssueSeverity::Info => "#6c757d",
-        }
-    }
-}
-
 /// Detailed content analysis result for admin
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContentAnalysisResult {
@@ -103,11 +76,20 @@ pub struct ContentAnalysisResult {
     pub meta_analysis: MetaAnalysisResult,
     pub content_analysis: ContentAnalysisDetail,
     pub keyword_analysis: Option<KeywordAnalysisResult>,
+    #[serde(default)]
+    pub secondary_keyword_analyses: Vec<KeywordAnalysisResult>,
     pub readability_analysis: ReadabilityResult,
     pub link_analysis: LinkAnalysisResult,
     pub image_analysis: ImageAnalysisResult,
     pub schema_analysis: SchemaAnalysisResult,
     pub social_analysis: SocialAnalysisResult,
+    /// Top 10 pages that mention the keywords but don't link here
+    pub internal_link_opportunities: Vec<LinkingOpportunity>,
+    pub eeat_signals: EeatSignalAnalysis,
+    /// Overall score direction across recent analyses
+    pub score_trend: ScoreTrend,
+    /// Top 10 people, places and organizations mentioned
+    pub detected_entities: Vec<NamedEntity>,
     pub issues: Vec<AnalysisIssue>,
     pub suggestions: Vec<AnalysisSuggestion>,
     pub analyzed_at: DateTime<Utc>,
@@ -168,6 +150,9 @@ pub struct ContentAnalysisDetail {
     pub sentence_count: i32,
     pub heading_structure: HeadingStructure,
     pub content_quality: ContentQuality,
+    pub paragraph_distribution: ParagraphDistribution,
+    /// Best passages for featured snippets / passage indexing
+    pub snippet_candidates: Vec<PassageScore>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -213,16 +198,10 @@ pub struct KeywordAnalysisResult {
     pub in_headings: bool,
     pub in_url: bool,
     pub in_image_alt: bool,
+    pub keyword_prominence: Option<f32>,
     pub related_keywords: Vec<RelatedKeyword>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RelatedKeyword {
-    pub keyword: String,
-    pub occurrences: i32,
-    pub density: f32,
-}
-
 /// Readability analysis result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReadabilityResult {
@@ -379,6 +358,157 @@ pub struct BulkAnalysisResult {
     pub duration_ms: i64,
 }
 
+/// Export format for analysis results
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AnalysisExportFormat {
+    Csv,
+    Json,
+    Xlsx,
+}
+
+impl AnalysisExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Json => "json",
+            Self::Xlsx => "xlsx",
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Json => "application/json",
+            Self::Xlsx => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        }
+    }
+}
+
+/// Exported analysis file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedData {
+    pub content_type: String,
+    pub data: Vec<u8>,
+    pub filename: String,
+}
+
+impl ExportedData {
+    /// Content-Disposition header value for downloading the file
+    pub fn content_disposition(&self) -> String {
+        format!("attachment; filename=\"{}\"", self.filename)
+    }
+}
+
+const EXPORT_COLUMNS: [&str; 16] = [
+    "content_id", "content_type", "title", "url", "overall_score", "overall_grade",
+    "focus_keyword", "meta_score", "content_score", "keyword_score", "readability_score",
+    "link_score", "image_score", "schema_score", "issues", "analyzed_at",
+];
+
+/// Score and count columns written as numbers in spreadsheets
+#[cfg(feature = "xlsx")]
+const EXPORT_NUMERIC_COLUMNS: [usize; 9] = [4, 7, 8, 9, 10, 11, 12, 13, 14];
+
+/// Flatten an analysis into export row values
+fn export_row(analysis: &ContentAnalysisResult) -> Vec<String> {
+    vec![
+        analysis.content_id.clone(),
+        analysis.content_type.clone(),
+        analysis.title.clone(),
+        analysis.url.clone(),
+        analysis.overall_score.to_string(),
+        analysis.overall_grade.clone(),
+        analysis.focus_keyword.clone().unwrap_or_default(),
+        analysis.meta_analysis.score.to_string(),
+        analysis.content_analysis.score.to_string(),
+        analysis.keyword_analysis.as_ref().map(|k| k.score.to_string()).unwrap_or_default(),
+        analysis.readability_analysis.score.to_string(),
+        analysis.link_analysis.score.to_string(),
+        analysis.image_analysis.score.to_string(),
+        analysis.schema_analysis.score.to_string(),
+        analysis.issues.len().to_string(),
+        analysis.analyzed_at.to_rfc3339(),
+    ]
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn export_csv(analyses: &[ContentAnalysisResult]) -> Vec<u8> {
+    let mut csv = EXPORT_COLUMNS.join(",");
+    csv.push('\n');
+
+    for analysis in analyses {
+        let row: Vec<String> = export_row(analysis).iter().map(|v| csv_field(v)).collect();
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+    }
+
+    csv.into_bytes()
+}
+
+#[cfg(feature = "xlsx")]
+fn export_xlsx(analyses: &[ContentAnalysisResult]) -> Result<Vec<u8>, rust_xlsxwriter::XlsxError> {
+    let mut workbook = rust_xlsxwriter::Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    for (col, header) in EXPORT_COLUMNS.iter().enumerate() {
+        worksheet.write_string(0, col as u16, *header)?;
+    }
+
+    for (row, analysis) in analyses.iter().enumerate() {
+        for (col, value) in export_row(analysis).iter().enumerate() {
+            match value.parse::<f64>() {
+                Ok(number) if EXPORT_NUMERIC_COLUMNS.contains(&col) => {
+                    worksheet.write_number(row as u32 + 1, col as u16, number)?
+                }
+                _ => worksheet.write_string(row as u32 + 1, col as u16, value)?,
+            };
+        }
+    }
+
+    workbook.save_to_buffer()
+}
+
+/// Export analysis results in the requested format
+///
+/// XLSX requires the `xlsx` feature; without it the export falls back to CSV.
+pub fn export_analyses(analyses: &[ContentAnalysisResult], format: AnalysisExportFormat) -> ExportedData {
+    let (format, data) = match format {
+        AnalysisExportFormat::Csv => (format, export_csv(analyses)),
+        AnalysisExportFormat::Json => (
+            format,
+            serde_json::to_vec_pretty(analyses).unwrap_or_else(|_| b"[]".to_vec()),
+        ),
+        #[cfg(feature = "xlsx")]
+        AnalysisExportFormat::Xlsx => match export_xlsx(analyses) {
+            Ok(data) => (format, data),
+            Err(e) => {
+                tracing::warn!("XLSX export failed, falling back to CSV: {}", e);
+                (AnalysisExportFormat::Csv, export_csv(analyses))
+            }
+        },
+        #[cfg(not(feature = "xlsx"))]
+        AnalysisExportFormat::Xlsx => (AnalysisExportFormat::Csv, export_csv(analyses)),
+    };
+
+    ExportedData {
+        content_type: format.content_type().to_string(),
+        data,
+        filename: format!(
+            "seo-analysis-{}.{}",
+            Utc::now().format("%Y-%m-%d"),
+            format.extension()
+        ),
+    }
+}
+
 /// Analysis settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnalysisSettings {
@@ -392,6 +522,21 @@ pub struct AnalysisSettings {
     pub readability_target_grade: i32,
 }
 
+impl AnalysisSettings {
+    /// Settings for the analysis service
+    pub fn to_analysis_settings(&self) -> crate::models::analysis::AnalysisSettings {
+        crate::models::analysis::AnalysisSettings {
+            min_word_count: self.min_content_length.max(0) as usize,
+            target_keyword_density: self.target_keyword_density_min,
+            max_keyword_density: self.target_keyword_density_max,
+            check_links: self.check_broken_links,
+            check_images: self.check_image_sizes,
+            target_grade_level: self.readability_target_grade.clamp(1, 16) as u8,
+            ..Default::default()
+        }
+    }
+}
+
 impl Default for AnalysisSettings {
     fn default() -> Self {
         Self {
@@ -512,7 +657,7 @@ pub fn get_common_issues() -> Vec<CommonIssue> {
         CommonIssue {
             id: "missing_meta_description".to_string(),
             title: "Missing Meta Description".to_string(),
-            severity: IssueSeverity::Critical,
+            severity: IssueSeverity::Error,
             category: "meta".to_string(),
             description: "Meta descriptions help search engines understand page content and improve click-through rates.".to_string(),
             how_to_fix: "Add a compelling meta description between 120-160 characters that includes your focus keyword.".to_string(),
@@ -31,6 +31,51 @@ pub struct AdminSubmenuItem {
     pub id: String,
     pub title: String,
     pub url: String,
+    /// Extra capability required on top of the parent item's
+    pub capability: Option<String>,
+}
+
+/// Checks whether a user holds a capability
+pub trait CapabilityChecker: Send + Sync {
+    fn has_capability(&self, user_id: &str, capability: &str) -> bool;
+}
+
+/// Grants every capability
+pub struct AllowAllCapabilityChecker;
+
+impl CapabilityChecker for AllowAllCapabilityChecker {
+    fn has_capability(&self, _user_id: &str, _capability: &str) -> bool {
+        true
+    }
+}
+
+/// Denies every capability
+pub struct DenyAllCapabilityChecker;
+
+impl CapabilityChecker for DenyAllCapabilityChecker {
+    fn has_capability(&self, _user_id: &str, _capability: &str) -> bool {
+        false
+    }
+}
+
+/// Capabilities used by the admin menu
+pub struct RequiredCapabilities;
+
+impl RequiredCapabilities {
+    pub const MANAGE_SEO: &'static str = "manage_rustseo";
+    pub const MANAGE_REDIRECTS: &'static str = "manage_rustseo_redirects";
+    pub const VIEW_ANALYSIS: &'static str = "view_rustseo_analysis";
+    pub const MANAGE_TOOLS: &'static str = "manage_rustseo_tools";
+
+    /// All distinct capabilities used by the menu
+    pub fn list() -> Vec<&'static str> {
+        vec![
+            Self::MANAGE_SEO,
+            Self::MANAGE_REDIRECTS,
+            Self::VIEW_ANALYSIS,
+            Self::MANAGE_TOOLS,
+        ]
+    }
 }
 
 impl AdminMenu {
@@ -42,57 +87,67 @@ impl AdminMenu {
                     title: "RustSEO".to_string(),
                     icon: "search".to_string(),
                     url: "/admin/plugins/rustseo".to_string(),
-                    capability: "manage_rustseo".to_string(),
+                    capability: RequiredCapabilities::MANAGE_SEO.to_string(),
                     children: vec![
                         AdminSubmenuItem {
                             id: "seo-dashboard".to_string(),
                             title: "Dashboard".to_string(),
                             url: "/admin/plugins/rustseo".to_string(),
+                            capability: None,
                         },
                         AdminSubmenuItem {
                             id: "seo-settings".to_string(),
                             title: "General Settings".to_string(),
                             url: "/admin/plugins/rustseo/settings".to_string(),
+                            capability: None,
                         },
                         AdminSubmenuItem {
                             id: "seo-appearance".to_string(),
                             title: "Search Appearance".to_string(),
                             url: "/admin/plugins/rustseo/appearance".to_string(),
+                            capability: None,
                         },
                         AdminSubmenuItem {
                             id: "seo-social".to_string(),
                             title: "Social Media".to_string(),
                             url: "/admin/plugins/rustseo/social".to_string(),
+                            capability: None,
                         },
                         AdminSubmenuItem {
                             id: "seo-sitemaps".to_string(),
                             title: "XML Sitemaps".to_string(),
                             url: "/admin/plugins/rustseo/sitemaps".to_string(),
+                            capability: None,
                         },
                         AdminSubmenuItem {
                             id: "seo-schema".to_string(),
                             title: "Schema Markup".to_string(),
                             url: "/admin/plugins/rustseo/schema".to_string(),
+                            capability: None,
                         },
                         AdminSubmenuItem {
                             id: "seo-redirects".to_string(),
                             title: "Redirects".to_string(),
                             url: "/admin/plugins/rustseo/redirects".to_string(),
+                            capability: Some(RequiredCapabilities::MANAGE_REDIRECTS.to_string()),
                         },
                         AdminSubmenuItem {
                             id: "seo-robots".to_string(),
                             title: "Robots.txt".to_string(),
                             url: "/admin/plugins/rustseo/robots".to_string(),
+                            capability: None,
                         },
                         AdminSubmenuItem {
                             id: "seo-analysis".to_string(),
                             title: "SEO Analysis".to_string(),
                             url: "/admin/plugins/rustseo/analysis".to_string(),
+                            capability: Some(RequiredCapabilities::VIEW_ANALYSIS.to_string()),
                         },
                         AdminSubmenuItem {
                             id: "seo-tools".to_string(),
                             title: "Tools".to_string(),
                             url: "/admin/plugins/rustseo/tools".to_string(),
+                            capability: Some(RequiredCapabilities::MANAGE_TOOLS.to_string()),
                         },
                     ],
                 },
@@ -101,14 +156,68 @@ impl AdminMenu {
     }
 }
 
+impl AdminMenu {
+    /// Menu restricted to the items the user is permitted to see
+    pub fn filtered_for_user(&self, checker: &dyn CapabilityChecker, user_id: &str) -> AdminMenu {
+        let items = self.items.iter()
+            .filter(|item| checker.has_capability(user_id, &item.capability))
+            .map(|item| AdminMenuItem {
+                children: item.children.iter()
+                    .filter(|child| {
+                        child.capability.as_deref()
+                            .map(|cap| checker.has_capability(user_id, cap))
+                            .unwrap_or(true)
+                    })
+                    .cloned()
+                    .collect(),
+                ..item.clone()
+            })
+            .collect();
+
+        AdminMenu { items }
+    }
+
+    /// URLs of all menu items and submenu items
+    fn urls(&self) -> Vec<&str> {
+        self.items.iter()
+            .flat_map(|item| {
+                std::iter::once(item.url.as_str())
+                    .chain(item.children.iter().map(|c| c.url.as_str()))
+            })
+            .collect()
+    }
+}
+
 impl Default for AdminMenu {
     fn default() -> Self {
         Self::new()
     }
 }
 
-/// Get admin routes
-pub fn get_admin_routes() -> Vec<(&'static str, &'static str, &'static str)> {
+/// Get admin routes the user is permitted to access
+pub fn get_admin_routes(
+    checker: &dyn CapabilityChecker,
+    user_id: &str,
+) -> Vec<(&'static str, &'static str, &'static str)> {
+    let full_menu = AdminMenu::new();
+    let menu = full_menu.filtered_for_user(checker, user_id);
+    let all_urls = full_menu.urls();
+    let visible_urls = menu.urls();
+
+    // The most specific menu URL covering a route decides access
+    all_admin_routes()
+        .into_iter()
+        .filter(|(_, path, _)| {
+            all_urls.iter()
+                .filter(|url| path == *url || path.starts_with(&format!("{}/", url)))
+                .max_by_key(|url| url.len())
+                .map(|url| visible_urls.contains(url))
+                .unwrap_or(false)
+        })
+        .collect()
+}
+
+fn all_admin_routes() -> Vec<(&'static str, &'static str, &'static str)> {
     vec![
         // (method, path, handler)
         ("GET", "/admin/plugins/rustseo", "dashboard::index"),
@@ -122,6 +231,7 @@ pub fn get_admin_routes() -> Vec<(&'static str, &'static str, &'static str)> {
         ("GET", "/admin/plugins/rustseo/redirects", "redirects::list"),
         ("POST", "/admin/plugins/rustseo/redirects", "redirects::create"),
         ("PUT", "/admin/plugins/rustseo/redirects/:id", "redirects::update"),
+        ("GET", "/admin/plugins/rustseo/redirects/:id/delete", "redirects::confirm_delete"),
         ("DELETE", "/admin/plugins/rustseo/redirects/:id", "redirects::delete"),
         ("GET", "/admin/plugins/rustseo/robots", "settings::robots"),
         ("POST", "/admin/plugins/rustseo/robots", "settings::update_robots"),
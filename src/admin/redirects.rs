@@ -6,6 +6,8 @@ use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
+use crate::services::redirect::RedirectImpactReport;
+
 /// Redirects overview for admin dashboard
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RedirectsOverview {
@@ -258,7 +260,7 @@ impl Default for RedirectSettings {
             query_string_handling: QueryStringHandling::Ignore,
             auto_redirect_post_slug_change: true,
             monitor_redirects: true,
-            max_redirect_chain: 5,
+            max_redirect_chain: crate::models::redirect::DEFAULT_MAX_REDIRECT_CHAIN as i32,
         }
     }
 }
@@ -280,6 +282,29 @@ pub enum QueryStringHandling {
     Exact,
 }
 
+/// Confirmation shown before deleting a redirect
+#[derive(Debug, Clone, Serialize)]
+pub struct DeleteRedirectConfirmation {
+    pub id: Uuid,
+    pub impact: Option<RedirectImpactReport>,
+    pub requires_confirmation: bool,
+}
+
+impl DeleteRedirectConfirmation {
+    pub fn new(id: Uuid, impact: Option<RedirectImpactReport>) -> Self {
+        let requires_confirmation = impact
+            .as_ref()
+            .map(|i| i.estimated_page_rank_loss != "Low" || i.is_in_sitemap)
+            .unwrap_or(false);
+
+        Self {
+            id,
+            impact,
+            requires_confirmation,
+        }
+    }
+}
+
 /// Bulk action for redirects
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BulkAction {
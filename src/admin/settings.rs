@@ -3,6 +3,10 @@
 //! Settings management for RustSEO plugin.
 
 use serde::{Deserialize, Serialize};
+use crate::models::schema::OrganizationSchema as OrganizationJsonLd;
+use crate::models::social::MastodonMetaData;
+use crate::services::schema::{KnowledgePanelSchemas, SchemaService};
+use crate::settings::{get_title_variables, replace_title_variables, TitleContext};
 
 /// General SEO settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -82,6 +86,27 @@ impl Default for KnowledgeGraphSettings {
     }
 }
 
+impl KnowledgeGraphSettings {
+    /// Knowledge panel schemas, if enabled for an organization
+    pub fn knowledge_panel_schemas(
+        &self,
+        service: &SchemaService,
+        search_url: Option<&str>,
+    ) -> Option<KnowledgePanelSchemas> {
+        if !self.enabled || self.entity_type == EntityType::Person || self.name.is_empty() {
+            return None;
+        }
+
+        let mut org = OrganizationJsonLd::new(
+            self.name.clone(),
+            self.url.clone().unwrap_or_else(|| service.site_url().to_string()),
+        );
+        org.logo = self.logo.clone();
+
+        Some(service.knowledge_panel_schema(&org, &self.social_profiles, search_url))
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub enum EntityType {
@@ -101,6 +126,32 @@ pub struct SocialProfiles {
     pub pinterest: Option<String>,
     pub tiktok: Option<String>,
     pub github: Option<String>,
+    pub mastodon: Option<String>,
+}
+
+impl SocialProfiles {
+    /// All configured profile URLs, suitable for `sameAs`
+    pub fn profile_urls(&self) -> Vec<String> {
+        let mastodon = self.mastodon.as_deref()
+            .and_then(|value| MastodonMetaData::parse_account(value)?.account_url);
+
+        [
+            &self.facebook,
+            &self.twitter,
+            &self.instagram,
+            &self.linkedin,
+            &self.youtube,
+            &self.pinterest,
+            &self.tiktok,
+            &self.github,
+            &mastodon,
+        ]
+        .into_iter()
+        .flatten()
+        .map(|url| url.trim().to_string())
+        .filter(|url| !url.is_empty())
+        .collect()
+    }
 }
 
 /// Webmaster tools verification
@@ -172,6 +223,25 @@ impl Default for SearchAppearanceSettings {
     }
 }
 
+impl SearchAppearanceSettings {
+    /// Title template errors, prefixed with the content type they belong to
+    pub fn validate(&self) -> Vec<String> {
+        let types = &self.content_types;
+        let named = [("posts", &types.posts), ("pages", &types.pages), ("products", &types.products)]
+            .into_iter()
+            .chain(types.custom_types.iter().map(|t| ("custom type", t)));
+
+        named
+            .flat_map(|(fallback, content_type)| {
+                let label = if content_type.name.is_empty() { fallback } else { content_type.name.as_str() };
+                ContentTypeSeo::validate_template(&content_type.title_template)
+                    .into_iter()
+                    .map(move |error| format!("{}: {}", label, error))
+            })
+            .collect()
+    }
+}
+
 /// Content type SEO settings
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ContentTypeSettings {
@@ -206,6 +276,54 @@ impl Default for ContentTypeSeo {
     }
 }
 
+impl ContentTypeSeo {
+    /// Problems with a title template: unclosed `%%`, whitespace in names and unknown variables
+    pub fn validate_template(template: &str) -> Vec<String> {
+        let mut errors = Vec::new();
+        let known: Vec<String> = get_title_variables().into_iter().map(|v| v.variable).collect();
+
+        let pieces: Vec<&str> = template.split("%%").collect();
+        if pieces.len().is_multiple_of(2) {
+            errors.push("Unclosed %% marker".to_string());
+        }
+
+        for name in template_variable_names(template) {
+            if name.chars().any(char::is_whitespace) {
+                errors.push(format!("Whitespace inside variable %%{}%%", name));
+            } else if !known.contains(&format!("%%{}%%", name)) {
+                errors.push(format!("Unknown variable %%{}%%", name));
+            }
+        }
+
+        errors
+    }
+
+    /// Render a template and list the variables that were left unreplaced or empty
+    pub fn preview_template(template: &str, context: &TitleContext) -> (String, Vec<String>) {
+        let unreplaced = template_variable_names(template)
+            .into_iter()
+            .map(|name| format!("%%{}%%", name))
+            .filter(|variable| {
+                let value = replace_title_variables(variable, context);
+                value == *variable || value.trim().is_empty()
+            })
+            .collect();
+
+        (replace_title_variables(template, context), unreplaced)
+    }
+}
+
+/// Names between closed `%%` marker pairs
+fn template_variable_names(template: &str) -> Vec<&str> {
+    let pieces: Vec<&str> = template.split("%%").collect();
+    pieces.iter()
+        .skip(1)
+        .step_by(2)
+        .take(pieces.len().saturating_sub(1) / 2)
+        .copied()
+        .collect()
+}
+
 /// Taxonomy SEO settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaxonomySettings {
@@ -39,6 +39,7 @@ pub enum SitemapType {
     Images,
     News,
     Video,
+    Hreflang,
     Custom,
 }
 
@@ -54,6 +55,7 @@ impl SitemapType {
             SitemapType::Images => "Images",
             SitemapType::News => "News",
             SitemapType::Video => "Video",
+            SitemapType::Hreflang => "Hreflang",
             SitemapType::Custom => "Custom",
         }
     }
@@ -276,10 +278,35 @@ pub struct GenerationResult {
     pub sitemaps_generated: i32,
     pub total_urls: i64,
     pub generation_time_ms: i64,
+    /// Sitemaps left as-is because no URL changed
+    pub sitemaps_unchanged: i32,
+    pub urls_added: i64,
+    pub urls_removed: i64,
+    pub urls_modified: i64,
     pub errors: Vec<String>,
     pub warnings: Vec<String>,
 }
 
+impl GenerationResult {
+    /// Add the changes of one regenerated sitemap to the totals
+    pub fn record_diff(&mut self, diff: Option<&crate::models::sitemap::SitemapDiff>) {
+        match diff {
+            None => self.sitemaps_unchanged += 1,
+            Some(diff) => {
+                self.urls_added += diff.added.len() as i64;
+                self.urls_removed += diff.removed.len() as i64;
+                self.urls_modified += diff.modified.len() as i64;
+                tracing::info!(
+                    "Sitemap updated: {} added, {} removed, {} modified",
+                    diff.added.len(),
+                    diff.removed.len(),
+                    diff.modified.len()
+                );
+            }
+        }
+    }
+}
+
 /// Action to regenerate sitemap
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RegenerateSitemapRequest {
@@ -3,12 +3,16 @@
 //! API handlers for SEO content analysis.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use uuid::Uuid;
 use crate::admin::analysis::{
     AnalysisOverview, ContentAnalysisResult, AnalysisSettings,
     BulkAnalysisResult, ContentListItem, BulkEditorUpdate,
+    AnalysisExportFormat, ExportedData,
 };
-use super::{PaginationParams, PaginatedResponse};
+use crate::services::analysis::AnalysisService;
+use crate::services::analysis::sentences::split_sentences;
+use super::{ApiResponse, PaginationParams, PaginatedResponse};
 
 /// Get analysis overview
 pub async fn get_analysis_overview() -> Result<AnalysisOverview, String> {
@@ -40,16 +44,18 @@ pub struct AnalyzeContentRequest {
     pub content_type: String,
     pub content_id: String,
     pub focus_keyword: Option<String>,
+    #[serde(default)]
+    pub secondary_keywords: Vec<String>,
     pub content: Option<String>,
 }
 
-pub async fn analyze_content(_request: AnalyzeContentRequest) -> Result<ContentAnalysisResult, String> {
-    Err("Not implemented".to_string())
+pub async fn analyze_content(_request: AnalyzeContentRequest) -> ApiResponse<ContentAnalysisResult> {
+    ApiResponse::not_implemented()
 }
 
 /// Get analysis result
-pub async fn get_analysis(_content_type: String, _content_id: String) -> Result<Option<ContentAnalysisResult>, String> {
-    Ok(None)
+pub async fn get_analysis(_content_type: String, _content_id: String) -> ApiResponse<ContentAnalysisResult> {
+    ApiResponse::not_found()
 }
 
 /// List analyzed content
@@ -87,6 +93,22 @@ pub async fn bulk_analyze(_request: BulkAnalyzeRequest) -> Result<BulkAnalysisRe
     })
 }
 
+/// Export analysis results
+///
+/// `GET /seo/v1/analysis/export?format=csv`. The response should be sent with
+/// `ExportedData::content_type` and `ExportedData::content_disposition` headers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportAnalysesRequest {
+    pub format: AnalysisExportFormat,
+    pub content_type: Option<String>,
+}
+
+pub async fn export_analyses(request: ExportAnalysesRequest) -> Result<ExportedData, String> {
+    // Would load stored analyses in real implementation
+    let analyses: Vec<ContentAnalysisResult> = vec![];
+    Ok(crate::admin::analysis::export_analyses(&analyses, request.format))
+}
+
 /// Get content for bulk editor
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BulkEditorRequest {
@@ -157,6 +179,10 @@ pub struct KeywordSuggestionsRequest {
     pub content: String,
     pub title: Option<String>,
     pub max_suggestions: Option<i32>,
+    /// Number of documents containing each term, for TF-IDF ranking
+    pub corpus_frequencies: Option<HashMap<String, usize>>,
+    /// Number of documents in the corpus
+    pub corpus_size: Option<usize>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -167,8 +193,30 @@ pub struct KeywordSuggestion {
     pub difficulty: Option<f32>,
 }
 
-pub async fn get_keyword_suggestions(_request: KeywordSuggestionsRequest) -> Result<Vec<KeywordSuggestion>, String> {
-    Ok(vec![])
+pub async fn get_keyword_suggestions(request: KeywordSuggestionsRequest) -> Result<Vec<KeywordSuggestion>, String> {
+    let Some(corpus) = request.corpus_frequencies else {
+        return Ok(vec![]);
+    };
+
+    let corpus_size = request.corpus_size.unwrap_or(0);
+    let max_suggestions = request.max_suggestions.unwrap_or(10).max(0) as usize;
+
+    let suggestions = AnalysisService::suggest_related_keywords_by_tfidf(
+        &request.content,
+        &corpus,
+        corpus_size,
+        max_suggestions,
+    );
+
+    Ok(suggestions
+        .into_iter()
+        .map(|(keyword, relevance)| KeywordSuggestion {
+            keyword,
+            relevance,
+            search_volume: None,
+            difficulty: None,
+        })
+        .collect())
 }
 
 /// Check readability
@@ -185,6 +233,12 @@ pub struct ReadabilityCheckResult {
     pub avg_sentence_length: f32,
     pub issues: Vec<ReadabilityIssue>,
     pub suggestions: Vec<String>,
+    /// Passive sentences for the editor to highlight inline
+    pub passive_sentences: Vec<String>,
+    pub long_sentence_count: usize,
+    pub long_sentence_percentage: f32,
+    /// Example long sentences, at most five
+    pub long_sentences: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -194,17 +248,79 @@ pub struct ReadabilityIssue {
     pub suggestion: String,
 }
 
-pub async fn check_readability(_request: ReadabilityCheckRequest) -> Result<ReadabilityCheckResult, String> {
+pub async fn check_readability(request: ReadabilityCheckRequest) -> Result<ReadabilityCheckResult, String> {
+    let text = strip_html_tags(&request.content);
+    let service = AnalysisService::new()
+        .with_settings(AnalysisSettings::default().to_analysis_settings());
+    let analysis = service.analyze_readability(&text);
+
+    let sentences = split_sentences(&text);
+    let longest = |filter: &dyn Fn(&str) -> bool| {
+        sentences.iter()
+            .filter(|s| filter(s))
+            .max_by_key(|s| s.split_whitespace().count())
+            .map(|s| s.to_string())
+            .unwrap_or_default()
+    };
+
+    let mut suggestions = Vec::new();
+    let issues = analysis.issues.iter()
+        .map(|issue| {
+            let issue_type = issue.title.to_lowercase().replace(' ', "_");
+            let text = match issue.title.as_str() {
+                "High use of passive voice" => {
+                    suggestions.push("Rewrite this sentence in active voice".to_string());
+                    longest(&AnalysisService::is_passive_sentence)
+                }
+                "Complex sentences" => longest(&|s| AnalysisService::analyze_sentence_complexity(s).is_complex()),
+                _ => {
+                    suggestions.push(issue.description.clone());
+                    longest(&|_| true)
+                }
+            };
+
+            ReadabilityIssue {
+                issue_type,
+                text,
+                suggestion: issue.description.clone(),
+            }
+        })
+        .collect();
+
     Ok(ReadabilityCheckResult {
-        score: 0.0,
-        grade_level: "N/A".to_string(),
-        flesch_reading_ease: 0.0,
-        avg_sentence_length: 0.0,
-        issues: vec![],
-        suggestions: vec![],
+        score: analysis.score as f32,
+        grade_level: format!("Grade {:.0}", analysis.flesch_kincaid_grade),
+        flesch_reading_ease: analysis.flesch_reading_ease,
+        avg_sentence_length: analysis.avg_sentence_length,
+        issues,
+        suggestions,
+        passive_sentences: analysis.passive_sentences,
+        long_sentence_count: analysis.long_sentence_count,
+        long_sentence_percentage: analysis.long_sentence_percentage,
+        long_sentences: analysis.long_sentences,
     })
 }
 
+/// Plain text with HTML tags removed
+fn strip_html_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => {
+                in_tag = false;
+                text.push(' ');
+            }
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text
+}
+
 /// Check links in content
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LinkCheckRequest {
@@ -291,5 +407,15 @@ pub struct ScoreHistoryEntry {
 }
 
 pub async fn get_score_history(_request: ScoreHistoryRequest) -> Result<Vec<ScoreHistoryEntry>, String> {
+    // Would load stored analyses and map AnalysisService::score_history in real implementation
     Ok(vec![])
 }
+
+/// Pages that no other page links to
+///
+/// `GET /seo/v1/analysis/orphaned-pages`
+pub async fn get_orphaned_pages() -> Result<Vec<String>, String> {
+    // Would build the internal link graph from published content in real implementation
+    let link_graph: HashMap<String, Vec<String>> = HashMap::new();
+    Ok(AnalysisService::detect_orphaned_pages(&[], &link_graph))
+}
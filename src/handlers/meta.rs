@@ -3,7 +3,24 @@
 //! API handlers for meta tags management.
 
 use serde::{Deserialize, Serialize};
-use crate::models::meta::{SeoMeta, MetaRobots};
+use uuid::Uuid;
+use crate::models::meta::{ContentType, SeoMeta, MetaRobots};
+use crate::services::meta::{CachedMetaService, MetaService, ServiceError};
+use super::{ApiError, ApiResponse};
+
+/// The plugin's meta service, shared with the head output
+fn meta_service() -> CachedMetaService {
+    crate::plugin::get_plugin().meta()
+}
+
+/// Parse the content type and ID from request path parameters
+fn parse_content_key(content_type: &str, content_id: &str) -> Result<(ContentType, Uuid), String> {
+    let parsed_type = serde_json::from_value(serde_json::Value::String(content_type.to_string()))
+        .map_err(|_| format!("Unknown content type: {}", content_type))?;
+    let parsed_id = Uuid::parse_str(content_id)
+        .map_err(|_| format!("Invalid content ID: {}", content_id))?;
+    Ok((parsed_type, parsed_id))
+}
 
 /// Get meta data for content
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -12,8 +29,20 @@ pub struct GetMetaRequest {
     pub content_id: String,
 }
 
-pub async fn get_meta(_request: GetMetaRequest) -> Result<Option<SeoMeta>, String> {
-    Ok(None)
+pub async fn get_meta(request: GetMetaRequest) -> ApiResponse<SeoMeta> {
+    let (content_type, content_id) = match parse_content_key(&request.content_type, &request.content_id) {
+        Ok(key) => key,
+        Err(e) => return ApiResponse::bad_request(&e),
+    };
+
+    match meta_service().get_meta(content_type, content_id) {
+        Ok(Some(meta)) => ApiResponse::success(meta),
+        Ok(None) | Err(ServiceError::NotFound) => ApiResponse::not_found(),
+        Err(ServiceError::ValidationError(errors)) => ApiResponse::validation_error(
+            errors.iter().map(|e| ApiError::new("invalid_meta", e)).collect(),
+        ),
+        Err(e) => ApiResponse::internal_error(&e.to_string()),
+    }
 }
 
 /// Update meta data for content
@@ -48,13 +77,71 @@ pub struct MetaRobotsUpdate {
     pub noimageindex: Option<bool>,
 }
 
-pub async fn update_meta(_request: UpdateMetaRequest) -> Result<SeoMeta, String> {
-    Ok(SeoMeta::default())
+impl SeoMetaUpdate {
+    /// Copy the provided fields onto stored meta
+    pub fn apply_to(&self, meta: &mut SeoMeta) {
+        if let Some(title) = &self.title {
+            meta.title = Some(title.clone()).filter(|t| !t.is_empty());
+            meta.use_custom_title = meta.title.is_some();
+        }
+        if let Some(description) = &self.description {
+            meta.description = Some(description.clone()).filter(|d| !d.is_empty());
+            meta.use_custom_description = meta.description.is_some();
+        }
+        if let Some(keyword) = &self.focus_keyword {
+            meta.focus_keyword = Some(keyword.clone()).filter(|k| !k.is_empty());
+        }
+        if let Some(canonical) = &self.canonical_url {
+            meta.canonical_url = Some(canonical.clone()).filter(|c| !c.is_empty());
+            meta.use_custom_canonical = meta.canonical_url.is_some();
+        }
+        if let Some(robots) = &self.robots {
+            if let Some(index) = robots.index {
+                meta.robots.index = index;
+            }
+            if let Some(follow) = robots.follow {
+                meta.robots.follow = follow;
+            }
+            if let Some(noarchive) = robots.noarchive {
+                meta.no_archive = noarchive;
+            }
+            if let Some(nosnippet) = robots.nosnippet {
+                meta.no_snippet = nosnippet;
+            }
+            if let Some(noimageindex) = robots.noimageindex {
+                meta.no_image_index = noimageindex;
+            }
+        }
+        // Would store the Open Graph and Twitter overrides with the social data in real implementation
+        meta.updated_at = chrono::Utc::now();
+    }
+}
+
+pub async fn update_meta(request: UpdateMetaRequest) -> Result<SeoMeta, String> {
+    let (content_type, content_id) = parse_content_key(&request.content_type, &request.content_id)?;
+    save_update(content_type, content_id, &request.meta)
+}
+
+/// Apply an update to the stored (or new) meta for a content item and save it
+fn save_update(content_type: ContentType, content_id: Uuid, update: &SeoMetaUpdate) -> Result<SeoMeta, String> {
+    let service = meta_service();
+    let mut meta = service
+        .get_meta(content_type, content_id)
+        .map_err(|e| e.to_string())?
+        .unwrap_or_else(|| service.inner().create_default_meta(content_id, content_type));
+
+    update.apply_to(&mut meta);
+    service.save_meta(&meta).map_err(|e| e.to_string())?;
+    Ok(meta)
 }
 
 /// Delete meta data for content
-pub async fn delete_meta(_content_type: String, _content_id: String) -> Result<(), String> {
-    Ok(())
+pub async fn delete_meta(content_type: String, content_id: String) -> Result<(), String> {
+    let (content_type, content_id) = parse_content_key(&content_type, &content_id)?;
+    match meta_service().delete_meta(content_type, content_id) {
+        Ok(()) | Err(ServiceError::NotFound) => Ok(()),
+        Err(e) => Err(e.to_string()),
+    }
 }
 
 /// Bulk get meta data
@@ -75,8 +162,16 @@ pub struct ContentMeta {
     pub meta: Option<SeoMeta>,
 }
 
-pub async fn bulk_get_meta(_request: BulkGetMetaRequest) -> Result<BulkMetaResponse, String> {
-    Ok(BulkMetaResponse { items: vec![] })
+pub async fn bulk_get_meta(request: BulkGetMetaRequest) -> Result<BulkMetaResponse, String> {
+    let mut items = Vec::with_capacity(request.content_ids.len());
+
+    for content_id in request.content_ids {
+        let (content_type, id) = parse_content_key(&request.content_type, &content_id)?;
+        let meta = meta_service().get_meta(content_type, id).map_err(|e| e.to_string())?;
+        items.push(ContentMeta { content_id, meta });
+    }
+
+    Ok(BulkMetaResponse { items })
 }
 
 /// Bulk update meta data
@@ -105,12 +200,26 @@ pub struct BulkUpdateError {
     pub error: String,
 }
 
-pub async fn bulk_update_meta(_request: BulkUpdateMetaRequest) -> Result<BulkUpdateMetaResponse, String> {
-    Ok(BulkUpdateMetaResponse {
+pub async fn bulk_update_meta(request: BulkUpdateMetaRequest) -> Result<BulkUpdateMetaResponse, String> {
+    let mut response = BulkUpdateMetaResponse {
         updated: 0,
         failed: 0,
         errors: vec![],
-    })
+    };
+
+    for item in request.updates {
+        let result = parse_content_key(&request.content_type, &item.content_id)
+            .and_then(|(content_type, id)| save_update(content_type, id, &item.meta));
+        match result {
+            Ok(_) => response.updated += 1,
+            Err(error) => {
+                response.failed += 1;
+                response.errors.push(BulkUpdateError { content_id: item.content_id, error });
+            }
+        }
+    }
+
+    Ok(response)
 }
 
 /// Generate meta preview
@@ -132,6 +241,8 @@ pub struct MetaPreviewResponse {
 pub struct GooglePreview {
     pub title: String,
     pub title_truncated: bool,
+    /// Character index where Google would cut the title
+    pub title_truncation_point: Option<usize>,
     pub description: String,
     pub description_truncated: bool,
     pub url: String,
@@ -154,25 +265,17 @@ pub struct TwitterPreview {
 }
 
 pub async fn generate_preview(request: MetaPreviewRequest) -> Result<MetaPreviewResponse, String> {
-    let title = if request.title.len() > 60 {
-        format!("{}...", &request.title[..57])
-    } else {
-        request.title.clone()
-    };
-
+    let title_cut = MetaService::snippet_truncation_point(&request.title, TITLE_MAX_PX, TITLE_FONT_PX);
     let description = request.description.clone().unwrap_or_default();
-    let desc_truncated = if description.len() > 160 {
-        format!("{}...", &description[..157])
-    } else {
-        description.clone()
-    };
+    let description_cut = MetaService::snippet_truncation_point(&description, DESCRIPTION_MAX_PX, DESCRIPTION_FONT_PX);
 
     Ok(MetaPreviewResponse {
         google: GooglePreview {
-            title: title.clone(),
-            title_truncated: request.title.len() > 60,
-            description: desc_truncated.clone(),
-            description_truncated: description.len() > 160,
+            title: truncate_at(&request.title, title_cut),
+            title_truncated: title_cut.is_some(),
+            title_truncation_point: title_cut,
+            description: truncate_at(&description, description_cut),
+            description_truncated: description_cut.is_some(),
             url: request.url.clone(),
         },
         facebook: FacebookPreview {
@@ -190,6 +293,21 @@ pub async fn generate_preview(request: MetaPreviewRequest) -> Result<MetaPreview
     })
 }
 
+/// Google result title width and font size in pixels
+const TITLE_MAX_PX: f32 = 600.0;
+const TITLE_FONT_PX: f32 = 20.0;
+/// Google result description width and font size in pixels
+const DESCRIPTION_MAX_PX: f32 = 920.0;
+const DESCRIPTION_FONT_PX: f32 = 14.0;
+
+/// Cut `text` at a character index and append an ellipsis
+fn truncate_at(text: &str, cut: Option<usize>) -> String {
+    match cut {
+        Some(cut) => format!("{}...", text.chars().take(cut).collect::<String>().trim_end()),
+        None => text.to_string(),
+    }
+}
+
 /// Suggest meta title
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SuggestTitleRequest {
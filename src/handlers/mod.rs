@@ -17,15 +17,21 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiResponse<T> {
     pub success: bool,
+    /// HTTP status code the response should be sent with
+    #[serde(default = "default_status_code")]
+    pub status_code: u16,
     pub data: Option<T>,
     pub message: Option<String>,
     pub errors: Option<Vec<ApiError>>,
 }
 
+fn default_status_code() -> u16 { 200 }
+
 impl<T> ApiResponse<T> {
     pub fn success(data: T) -> Self {
         Self {
             success: true,
+            status_code: 200,
             data: Some(data),
             message: None,
             errors: None,
@@ -35,6 +41,7 @@ impl<T> ApiResponse<T> {
     pub fn success_with_message(data: T, message: &str) -> Self {
         Self {
             success: true,
+            status_code: 200,
             data: Some(data),
             message: Some(message.to_string()),
             errors: None,
@@ -42,20 +49,67 @@ impl<T> ApiResponse<T> {
     }
 
     pub fn error(message: &str) -> Self {
+        Self::error_with_status(400, message)
+    }
+
+    pub fn validation_error(errors: Vec<ApiError>) -> Self {
         Self {
             success: false,
+            status_code: 422,
             data: None,
-            message: Some(message.to_string()),
-            errors: None,
+            message: Some("Validation failed".to_string()),
+            errors: Some(errors),
         }
     }
 
-    pub fn validation_error(errors: Vec<ApiError>) -> Self {
+    /// 400 Bad Request
+    pub fn bad_request(message: &str) -> Self {
+        Self::error_with_status(400, message)
+    }
+
+    /// 401 Unauthorized
+    pub fn unauthorized() -> Self {
+        Self::error_with_status(401, "Authentication required")
+    }
+
+    /// 403 Forbidden
+    pub fn forbidden() -> Self {
+        Self::error_with_status(403, "You are not allowed to do this")
+    }
+
+    /// 404 Not Found
+    pub fn not_found() -> Self {
+        Self::error_with_status(404, "Not found")
+    }
+
+    /// 500 Internal Server Error
+    pub fn internal_error(message: &str) -> Self {
+        Self::error_with_status(500, message)
+    }
+
+    /// 501 Not Implemented
+    pub fn not_implemented() -> Self {
+        Self::error_with_status(501, "Not implemented")
+    }
+
+    fn error_with_status(status_code: u16, message: &str) -> Self {
         Self {
             success: false,
+            status_code,
             data: None,
-            message: Some("Validation failed".to_string()),
-            errors: Some(errors),
+            message: Some(message.to_string()),
+            errors: None,
+        }
+    }
+
+    /// Transform the data, keeping status and messages
+    pub fn map_data<U, F: FnOnce(T) -> U>(self, f: F) -> ApiResponse<U> {
+        ApiResponse {
+            success: self.success,
+            status_code: self.status_code,
+            data: self.data.map(f),
+            message: self.message,
+            errors: self.errors,
         }
     }
 }
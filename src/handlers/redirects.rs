@@ -7,9 +7,13 @@ use uuid::Uuid;
 use crate::admin::redirects::{
     RedirectsOverview, RedirectEntry, RedirectForm, RedirectSettings,
     NotFoundOverview, NotFoundEntry, ImportResult, RedirectTestResult,
-    RedirectStats, BulkActionResult,
+    RedirectStats, BulkActionResult, DeleteRedirectConfirmation,
+    MatchedRedirect, RedirectChainEntry,
 };
-use super::{PaginationParams, PaginatedResponse};
+use crate::models::redirect::{Redirect, RedirectType};
+use crate::plugin::get_plugin;
+use crate::services::redirect::{format_loop, RedirectError, RedirectService, RedirectSuggestion, SuggestedRedirect};
+use super::{ApiResponse, PaginationParams, PaginatedResponse};
 
 /// Get redirects overview
 pub async fn get_redirects_overview() -> Result<RedirectsOverview, String> {
@@ -43,22 +47,47 @@ pub async fn list_redirects(_request: ListRedirectsRequest) -> Result<PaginatedR
 }
 
 /// Get single redirect
-pub async fn get_redirect(_id: Uuid) -> Result<Option<RedirectEntry>, String> {
-    Ok(None)
+pub async fn get_redirect(_id: Uuid) -> ApiResponse<RedirectEntry> {
+    ApiResponse::not_found()
 }
 
 /// Create redirect
-pub async fn create_redirect(_form: RedirectForm) -> Result<RedirectEntry, String> {
-    Err("Not implemented".to_string())
+pub async fn create_redirect(_form: RedirectForm) -> ApiResponse<RedirectEntry> {
+    ApiResponse::not_implemented()
 }
 
 /// Update redirect
-pub async fn update_redirect(_id: Uuid, _form: RedirectForm) -> Result<RedirectEntry, String> {
-    Err("Not implemented".to_string())
+pub async fn update_redirect(_id: Uuid, _form: RedirectForm) -> ApiResponse<RedirectEntry> {
+    ApiResponse::not_implemented()
+}
+
+/// Get delete confirmation with impact analysis
+pub async fn confirm_delete_redirect(id: Uuid) -> Result<DeleteRedirectConfirmation, String> {
+    let sitemap_urls = get_plugin()
+        .sitemaps()
+        .published_urls(None)
+        .into_iter()
+        .map(|url| url.loc)
+        .collect();
+
+    let mut service = get_plugin().redirect_service().write().await;
+    service.set_sitemap_urls(sitemap_urls);
+    delete_confirmation(id, &service)
+}
+
+/// Impact of deleting a redirect from `service`
+pub fn delete_confirmation(id: Uuid, service: &RedirectService) -> Result<DeleteRedirectConfirmation, String> {
+    if service.get_redirect(id).is_none() {
+        return Err(format!("Redirect not found: {}", id));
+    }
+    Ok(DeleteRedirectConfirmation::new(id, service.analyze_redirect_impact(id)))
 }
 
 /// Delete redirect
-pub async fn delete_redirect(_id: Uuid) -> Result<(), String> {
+pub async fn delete_redirect(id: Uuid) -> Result<(), String> {
+    if !get_plugin().redirect_service().write().await.remove_redirect(id) {
+        return Err(format!("Redirect not found: {}", id));
+    }
     Ok(())
 }
 
@@ -68,8 +97,8 @@ pub struct SetActiveRequest {
     pub active: bool,
 }
 
-pub async fn set_redirect_active(_id: Uuid, _request: SetActiveRequest) -> Result<RedirectEntry, String> {
-    Err("Not implemented".to_string())
+pub async fn set_redirect_active(_id: Uuid, _request: SetActiveRequest) -> ApiResponse<RedirectEntry> {
+    ApiResponse::not_implemented()
 }
 
 /// Bulk actions
@@ -94,14 +123,117 @@ pub struct TestUrlRequest {
     pub follow_chain: bool,
 }
 
-pub async fn test_url(_request: TestUrlRequest) -> Result<RedirectTestResult, String> {
-    Ok(RedirectTestResult {
-        url: String::new(),
-        matched: false,
-        redirect: None,
-        redirect_chain: vec![],
-        final_url: None,
-        warnings: vec![],
+pub async fn test_url(request: TestUrlRequest) -> Result<RedirectTestResult, String> {
+    let service = get_plugin().redirect_service().read().await;
+    let mut result = test_redirect_url(request.url, &service).await;
+    if !request.follow_chain {
+        result.redirect_chain.truncate(1);
+    }
+    Ok(result)
+}
+
+/// Test a URL against the configured redirects, following the full chain
+///
+/// `POST /seo/v1/redirects/test`
+pub async fn test_redirect_url(url: String, service: &RedirectService) -> RedirectTestResult {
+    let test = service.test_url(&url);
+    let chain = service.resolve_chain(&url);
+    let mut warnings = Vec::new();
+
+    let redirect = match (test.redirect_id, test.source, test.target, test.status_code) {
+        (Some(id), Some(source), Some(target), Some(status_code)) => Some(MatchedRedirect {
+            id,
+            source,
+            target,
+            status_code,
+        }),
+        _ => None,
+    };
+
+    let redirect_chain: Vec<RedirectChainEntry> = chain.hops.iter()
+        .enumerate()
+        .map(|(i, hop)| RedirectChainEntry {
+            url: hop.target_url.clone(),
+            status_code: hop.status_code,
+            step: i as i32 + 1,
+        })
+        .collect();
+
+    // The chain ends on a URL it already visited; name the loop hop by hop
+    if chain.is_loop {
+        let chain_urls: Vec<String> = std::iter::once(url.clone())
+            .chain(chain.hops.iter().map(|hop| hop.target_url.clone()))
+            .collect();
+        let (last, visited) = chain_urls.split_last().unwrap_or((&url, &[]));
+        let start = visited.iter().position(|u| u == last).unwrap_or(0);
+        warnings.push(format!("Redirect loop: {}", format_loop(&visited[start..])));
+    }
+
+    let max_chain = service.settings().max_redirect_chain;
+    if chain.hops.len() > max_chain {
+        warnings.push(format!(
+            "Redirect chain has {} hops (maximum {}); point the first redirect at the final URL",
+            chain.hops.len(),
+            max_chain
+        ));
+    } else if chain.hops.len() > 2 {
+        warnings.push(format!(
+            "Redirect chain has {} hops; search engines may stop following long chains, so point the first redirect at the final URL",
+            chain.hops.len()
+        ));
+    }
+
+    // Temporary redirects left in place for a month are likely permanent moves
+    for hop in &chain.hops {
+        if let Some(redirect) = service.get_redirect(hop.redirect_id) {
+            let days_active = (chrono::Utc::now() - redirect.created_at).num_days();
+            if redirect.redirect_type == RedirectType::Temporary && days_active >= 30 {
+                warnings.push(format!(
+                    "{} uses a 302 redirect but has been active for {} days; use a 301 if the content has moved permanently",
+                    redirect.source_url, days_active
+                ));
+            }
+        }
+    }
+
+    RedirectTestResult {
+        matched: redirect.is_some(),
+        redirect,
+        final_url: if chain.is_loop {
+            None
+        } else {
+            chain.final_url().map(str::to_string)
+        },
+        redirect_chain,
+        warnings,
+        url,
+    }
+}
+
+/// Redirect suggestions built from the 404 log
+#[derive(Debug, Clone, Serialize)]
+pub struct RedirectSuggestionsResponse {
+    /// Pattern rules covering groups of related 404s
+    pub patterns: Vec<SuggestedRedirect>,
+    /// Live URLs closely matching individual 404s
+    pub targets: Vec<RedirectSuggestion>,
+}
+
+/// Suggest redirects from recurring 404 patterns and similar live URLs
+///
+/// `GET /seo/v1/redirects/suggestions`
+pub async fn get_redirect_suggestions() -> Result<RedirectSuggestionsResponse, String> {
+    let plugin = get_plugin();
+    let service = plugin.redirect_service().read().await;
+    let known_urls: Vec<String> = plugin.sitemaps()
+        .published_urls(None)
+        .into_iter()
+        .map(|url| url.loc)
+        .collect();
+
+    Ok(RedirectSuggestionsResponse {
+        patterns: service.suggest_redirects_from_404_log(),
+        targets: service.suggest_redirects(&known_urls),
     })
 }
 
@@ -126,8 +258,8 @@ pub struct Create404RedirectRequest {
     pub redirect_type: Option<String>,
 }
 
-pub async fn create_redirect_from_404(_request: Create404RedirectRequest) -> Result<RedirectEntry, String> {
-    Err("Not implemented".to_string())
+pub async fn create_redirect_from_404(_request: Create404RedirectRequest) -> ApiResponse<RedirectEntry> {
+    ApiResponse::not_implemented()
 }
 
 /// Ignore 404
@@ -154,12 +286,21 @@ pub struct ImportRedirectsRequest {
     pub overwrite_existing: bool,
 }
 
-pub async fn import_redirects(_request: ImportRedirectsRequest) -> Result<ImportResult, String> {
+pub async fn import_redirects(request: ImportRedirectsRequest) -> Result<ImportResult, String> {
+    let mut service = get_plugin().redirect_service().write().await;
+
+    let result = match request.format.to_lowercase().as_str() {
+        "csv" => service.import_csv(&request.data),
+        "htaccess" => service.import_htaccess(&request.data),
+        "nginx" | "nginxconf" => service.import_nginx(&request.data),
+        other => return Err(format!("Unsupported import format: {}", other)),
+    };
+
     Ok(ImportResult {
-        success: true,
-        imported: 0,
-        skipped: 0,
-        errors: vec![],
+        success: result.errors.is_empty(),
+        imported: result.imported as i32,
+        skipped: result.skipped as i32,
+        errors: result.errors,
     })
 }
 
@@ -177,11 +318,20 @@ pub struct ExportRedirectsResponse {
     pub content_type: String,
 }
 
-pub async fn export_redirects(_request: ExportRedirectsRequest) -> Result<ExportRedirectsResponse, String> {
+pub async fn export_redirects(request: ExportRedirectsRequest) -> Result<ExportRedirectsResponse, String> {
+    let service = get_plugin().redirect_service().read().await;
+
+    let (data, filename, content_type) = match request.format.to_lowercase().as_str() {
+        "csv" => (service.export_csv(), "redirects.csv", "text/csv"),
+        "htaccess" => (service.export_htaccess(), ".htaccess", "text/plain"),
+        "nginx" | "nginxconf" => (service.export_nginx(), "redirects.conf", "text/plain"),
+        other => return Err(format!("Unsupported export format: {}", other)),
+    };
+
     Ok(ExportRedirectsResponse {
-        data: String::new(),
-        filename: "redirects.csv".to_string(),
-        content_type: "text/csv".to_string(),
+        data,
+        filename: filename.to_string(),
+        content_type: content_type.to_string(),
     })
 }
 
@@ -244,9 +394,44 @@ pub struct CheckLoopsResponse {
     pub chain: Vec<String>,
 }
 
-pub async fn check_redirect_loops(_request: CheckLoopsRequest) -> Result<CheckLoopsResponse, String> {
-    Ok(CheckLoopsResponse {
-        has_loop: false,
-        chain: vec![],
+pub async fn check_redirect_loops(request: CheckLoopsRequest) -> Result<CheckLoopsResponse, String> {
+    let mut service = get_plugin().redirect_service().write().await;
+    let candidate = Redirect::new(request.source, request.target, RedirectType::Permanent);
+    let candidate_id = candidate.id;
+
+    // Only checks the candidate, so it is taken out again when it fits
+    Ok(match service.add_redirect(candidate) {
+        Ok(()) => {
+            service.remove_redirect(candidate_id);
+            CheckLoopsResponse {
+                has_loop: false,
+                chain: vec![],
+            }
+        }
+        Err(RedirectError::RedirectLoop { chain }) => CheckLoopsResponse {
+            has_loop: true,
+            chain,
+        },
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::sitemap::{Sitemap, SitemapType, SitemapUrl};
+
+    #[tokio::test]
+    async fn test_redirect_suggestions_match_404s_to_published_urls() {
+        let plugin = get_plugin();
+        let mut sitemap = Sitemap::new(SitemapType::Pages);
+        sitemap.urls.push(SitemapUrl::new("https://example.com/blog/seo-tips-for-beginners/".to_string()));
+        plugin.sitemaps().publish(vec![sitemap]);
+        plugin.redirect_service().write().await.log_404("/blog/seo-tips-for-begginers", None, None);
+
+        let suggestions = get_redirect_suggestions().await.unwrap();
+        let suggestion = suggestions.targets.iter()
+            .find(|s| s.source_url == "/blog/seo-tips-for-begginers")
+            .unwrap();
+        assert_eq!(suggestion.suggested_target, "https://example.com/blog/seo-tips-for-beginners/");
+    }
+}
@@ -3,7 +3,12 @@
 //! API handlers for robots.txt management.
 
 use serde::{Deserialize, Serialize};
-use crate::models::robots::RobotsTxtSettings;
+use crate::models::robots::{RobotsTxt, RobotsTxtSettings};
+#[cfg(feature = "fetch-robots")]
+use crate::models::robots::{RobotsAuditReport, RobotsDiff};
+use crate::services::robots::RobotsService;
+
+pub use crate::services::robots::{RobotsError, RobotsTestResult, RobotsWarning};
 
 /// Get robots.txt content
 pub async fn get_robots_txt() -> Result<String, String> {
@@ -45,35 +50,17 @@ pub struct RobotsValidationResult {
     pub sitemaps_found: Vec<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RobotsError {
-    pub line: i32,
-    pub message: String,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RobotsWarning {
-    pub line: i32,
-    pub message: String,
-}
-
 pub async fn validate_robots(request: ValidateRobotsRequest) -> Result<RobotsValidationResult, String> {
-    let lines: Vec<&str> = request.content.lines().collect();
-    let rules_count = lines.iter()
-        .filter(|l| l.to_lowercase().starts_with("user-agent:"))
-        .count() as i32;
-
-    let sitemaps_found: Vec<String> = lines.iter()
-        .filter(|l| l.to_lowercase().starts_with("sitemap:"))
-        .map(|l| l.split(':').skip(1).collect::<Vec<_>>().join(":").trim().to_string())
-        .collect();
+    let service = RobotsService::new(String::new());
+    let validation = service.validate(&request.content);
+    let robots = service.parse(&request.content);
 
     Ok(RobotsValidationResult {
-        valid: true,
-        errors: vec![],
-        warnings: vec![],
-        rules_count,
-        sitemaps_found,
+        valid: validation.valid,
+        errors: validation.errors,
+        warnings: validation.warnings,
+        rules_count: robots.rules.len() as i32,
+        sitemaps_found: robots.sitemaps,
     })
 }
 
@@ -85,21 +72,73 @@ pub struct TestRobotsRequest {
     pub robots_content: Option<String>,
 }
 
+/// `POST /seo/v1/robots/test`
+pub async fn test_robots_url(request: TestRobotsRequest) -> Result<RobotsTestResult, String> {
+    let robots = match &request.robots_content {
+        Some(content) => RobotsTxt::parse(content),
+        // Would load the site's robots.txt in real implementation
+        None => RobotsService::new(String::new()).build(),
+    };
+
+    Ok(RobotsService::test_url_against_rules(&robots, &request.user_agent, &request.url))
+}
+
+/// Fetch a live robots.txt and compare it with the local one
+///
+/// `POST /seo/v1/robots/fetch`
+#[cfg(feature = "fetch-robots")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FetchRobotsRequest {
+    pub url: String,
+    pub local_content: Option<String>,
+}
+
+#[cfg(feature = "fetch-robots")]
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RobotsTestResult {
-    pub allowed: bool,
-    pub matched_rule: Option<String>,
-    pub user_agent_matched: String,
+pub struct FetchRobotsResult {
+    pub remote: RobotsTxt,
+    pub diff: RobotsDiff,
 }
 
-pub async fn test_robots_url(_request: TestRobotsRequest) -> Result<RobotsTestResult, String> {
-    Ok(RobotsTestResult {
-        allowed: true,
-        matched_rule: None,
-        user_agent_matched: "*".to_string(),
+#[cfg(feature = "fetch-robots")]
+pub async fn fetch_remote_robots(request: FetchRobotsRequest) -> Result<FetchRobotsResult, String> {
+    let remote = RobotsTxt::from_url(&request.url)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let local = match &request.local_content {
+        Some(content) => RobotsTxt::parse(content),
+        None => {
+            let site_url = url::Url::parse(&request.url)
+                .map(|u| u.origin().ascii_serialization())
+                .unwrap_or_default();
+            RobotsTxt::default_rules(&site_url)
+        }
+    };
+
+    Ok(FetchRobotsResult {
+        diff: RobotsTxt::diff(&local, &remote),
+        remote,
     })
 }
 
+/// Audit the live robots.txt against what the settings generate
+///
+/// `GET /seo/v1/robots/audit`
+#[cfg(feature = "fetch-robots")]
+pub async fn audit_robots() -> Result<RobotsAuditReport, String> {
+    let settings = crate::plugin::get_plugin().settings();
+    let service = RobotsService::new(settings.site_url.clone())
+        .with_settings(settings.robots.to_robots_txt_settings());
+
+    let live_url = format!("{}/robots.txt", settings.site_url.trim_end_matches('/'));
+    let live_content = RobotsTxt::fetch_content(&live_url)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(RobotsService::audit(&service.build(), &live_content))
+}
+
 /// Generate robots.txt from settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GenerateRobotsRequest {
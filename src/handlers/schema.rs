@@ -4,6 +4,13 @@
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
+use crate::models::schema::HowToSchema;
+use crate::services::schema::{schema_testing, SchemaService};
+use super::ApiResponse;
+
+pub use crate::services::schema::{
+    get_available_schema_types, SchemaError, SchemaTypeInfo, SchemaValidationResult, SchemaWarning,
+};
 
 /// Get schema for content
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -19,8 +26,8 @@ pub struct SchemaResponse {
     pub is_custom: bool,
 }
 
-pub async fn get_schema(_request: GetSchemaRequest) -> Result<Option<SchemaResponse>, String> {
-    Ok(None)
+pub async fn get_schema(_request: GetSchemaRequest) -> ApiResponse<SchemaResponse> {
+    ApiResponse::not_found()
 }
 
 /// Update schema for content
@@ -32,8 +39,8 @@ pub struct UpdateSchemaRequest {
     pub custom_schema: Option<JsonValue>,
 }
 
-pub async fn update_schema(_request: UpdateSchemaRequest) -> Result<SchemaResponse, String> {
-    Err("Not implemented".to_string())
+pub async fn update_schema(_request: UpdateSchemaRequest) -> ApiResponse<SchemaResponse> {
+    ApiResponse::not_implemented()
 }
 
 /// Delete custom schema
@@ -76,140 +83,36 @@ pub struct ImageData {
     pub alt: Option<String>,
 }
 
-pub async fn generate_schema(_request: GenerateSchemaRequest) -> Result<SchemaResponse, String> {
-    Err("Not implemented".to_string())
-}
-
-/// Validate schema JSON-LD
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ValidateSchemaRequest {
-    pub json_ld: JsonValue,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SchemaValidationResult {
-    pub valid: bool,
-    pub schema_types: Vec<String>,
-    pub errors: Vec<SchemaError>,
-    pub warnings: Vec<SchemaWarning>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SchemaError {
-    pub path: String,
-    pub message: String,
+pub async fn generate_schema(_request: GenerateSchemaRequest) -> ApiResponse<SchemaResponse> {
+    ApiResponse::not_implemented()
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SchemaWarning {
-    pub path: String,
-    pub message: String,
-    pub recommendation: String,
-}
+/// Generate HowTo JSON-LD from step data
+///
+/// `POST /seo/v1/schema/howto`
+pub async fn generate_howto_schema(howto: HowToSchema) -> ApiResponse<JsonValue> {
+    if howto.name.trim().is_empty() {
+        return ApiResponse::bad_request("HowTo name is required");
+    }
+    if howto.steps.is_empty() {
+        return ApiResponse::bad_request("HowTo needs at least one step");
+    }
 
-pub async fn validate_schema(_request: ValidateSchemaRequest) -> Result<SchemaValidationResult, String> {
-    Ok(SchemaValidationResult {
-        valid: true,
-        schema_types: vec![],
-        errors: vec![],
-        warnings: vec![],
-    })
+    // Would use the configured site name and URL in real implementation
+    let service = SchemaService::new(String::new(), String::new());
+    ApiResponse::success(service.howto_schema(&howto))
 }
 
-/// Get available schema types
+/// Validate schema JSON-LD
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SchemaTypeInfo {
-    pub schema_type: String,
-    pub name: String,
-    pub description: String,
-    pub recommended_for: Vec<String>,
-    pub required_properties: Vec<String>,
-    pub optional_properties: Vec<String>,
+pub struct ValidateSchemaRequest {
+    pub json_ld: JsonValue,
 }
 
-pub fn get_available_schema_types() -> Vec<SchemaTypeInfo> {
-    vec![
-        SchemaTypeInfo {
-            schema_type: "Article".to_string(),
-            name: "Article".to_string(),
-            description: "A general article".to_string(),
-            recommended_for: vec!["posts".to_string(), "news".to_string()],
-            required_properties: vec!["headline".to_string(), "author".to_string(), "datePublished".to_string()],
-            optional_properties: vec!["image".to_string(), "description".to_string()],
-        },
-        SchemaTypeInfo {
-            schema_type: "BlogPosting".to_string(),
-            name: "Blog Post".to_string(),
-            description: "A blog post".to_string(),
-            recommended_for: vec!["posts".to_string(), "blogs".to_string()],
-            required_properties: vec!["headline".to_string(), "author".to_string(), "datePublished".to_string()],
-            optional_properties: vec!["image".to_string(), "description".to_string()],
-        },
-        SchemaTypeInfo {
-            schema_type: "NewsArticle".to_string(),
-            name: "News Article".to_string(),
-            description: "A news article".to_string(),
-            recommended_for: vec!["news".to_string()],
-            required_properties: vec!["headline".to_string(), "author".to_string(), "datePublished".to_string()],
-            optional_properties: vec!["image".to_string(), "description".to_string()],
-        },
-        SchemaTypeInfo {
-            schema_type: "Product".to_string(),
-            name: "Product".to_string(),
-            description: "A product for sale".to_string(),
-            recommended_for: vec!["products".to_string()],
-            required_properties: vec!["name".to_string()],
-            optional_properties: vec!["image".to_string(), "description".to_string(), "offers".to_string()],
-        },
-        SchemaTypeInfo {
-            schema_type: "LocalBusiness".to_string(),
-            name: "Local Business".to_string(),
-            description: "A local business".to_string(),
-            recommended_for: vec!["pages".to_string()],
-            required_properties: vec!["name".to_string(), "address".to_string()],
-            optional_properties: vec!["telephone".to_string(), "openingHours".to_string()],
-        },
-        SchemaTypeInfo {
-            schema_type: "Organization".to_string(),
-            name: "Organization".to_string(),
-            description: "An organization".to_string(),
-            recommended_for: vec!["about".to_string()],
-            required_properties: vec!["name".to_string()],
-            optional_properties: vec!["logo".to_string(), "url".to_string()],
-        },
-        SchemaTypeInfo {
-            schema_type: "FAQPage".to_string(),
-            name: "FAQ Page".to_string(),
-            description: "A page with frequently asked questions".to_string(),
-            recommended_for: vec!["faq".to_string()],
-            required_properties: vec!["mainEntity".to_string()],
-            optional_properties: vec![],
-        },
-        SchemaTypeInfo {
-            schema_type: "HowTo".to_string(),
-            name: "How To".to_string(),
-            description: "Step-by-step instructions".to_string(),
-            recommended_for: vec!["tutorials".to_string(), "guides".to_string()],
-            required_properties: vec!["name".to_string(), "step".to_string()],
-            optional_properties: vec!["image".to_string(), "totalTime".to_string()],
-        },
-        SchemaTypeInfo {
-            schema_type: "Recipe".to_string(),
-            name: "Recipe".to_string(),
-            description: "A cooking recipe".to_string(),
-            recommended_for: vec!["recipes".to_string()],
-            required_properties: vec!["name".to_string(), "recipeIngredient".to_string()],
-            optional_properties: vec!["image".to_string(), "cookTime".to_string()],
-        },
-        SchemaTypeInfo {
-            schema_type: "Event".to_string(),
-            name: "Event".to_string(),
-            description: "An event".to_string(),
-            recommended_for: vec!["events".to_string()],
-            required_properties: vec!["name".to_string(), "startDate".to_string(), "location".to_string()],
-            optional_properties: vec!["image".to_string(), "description".to_string()],
-        },
-    ]
+pub async fn validate_schema(request: ValidateSchemaRequest) -> Result<SchemaValidationResult, String> {
+    let mut result = SchemaService::validate_schema(&request.json_ld);
+    result.rich_results = schema_testing::check_rich_result_eligibility(&request.json_ld);
+    Ok(result)
 }
 
 /// Get schema template
@@ -266,6 +169,18 @@ pub async fn get_schema_template(request: GetTemplateRequest) -> Result<JsonValu
             "@type": "FAQPage",
             "mainEntity": []
         }),
+        "HowTo" => serde_json::json!({
+            "@context": "https://schema.org",
+            "@type": "HowTo",
+            "name": "",
+            "totalTime": "",
+            "step": [{
+                "@type": "HowToStep",
+                "position": 1,
+                "name": "",
+                "text": ""
+            }]
+        }),
         _ => serde_json::json!({
             "@context": "https://schema.org",
             "@type": request.schema_type
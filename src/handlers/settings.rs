@@ -7,6 +7,8 @@ use crate::admin::settings::{
     RustSeoSettings, GeneralSettings, SearchAppearanceSettings,
     SocialSettings, SchemaSettings, ToolsSettings,
 };
+use crate::settings::{PatchError, SeoSettings};
+use super::{ApiError, ApiResponse};
 
 /// Get all settings
 pub async fn get_all_settings() -> Result<RustSeoSettings, String> {
@@ -18,6 +20,34 @@ pub async fn update_all_settings(_settings: RustSeoSettings) -> Result<RustSeoSe
     Ok(RustSeoSettings::default())
 }
 
+/// Partially update settings
+///
+/// `PATCH /seo/v1/settings` with an `application/merge-patch+json` body
+///
+/// The patch is applied to the current settings and the result is saved.
+pub async fn patch_settings(patch: serde_json::Value) -> ApiResponse<SeoSettings> {
+    let plugin = crate::plugin::get_plugin();
+    let mut settings = plugin.settings();
+    match SeoSettings::apply_merge_patch(&mut settings, patch) {
+        Ok(()) => {
+            plugin.update_settings(settings.clone());
+            match plugin.save_settings().await {
+                Ok(()) => ApiResponse::success(settings),
+                Err(e) => ApiResponse::internal_error(&e.message),
+            }
+        }
+        Err(PatchError::InvalidJson(message)) => ApiResponse::bad_request(&message),
+        Err(PatchError::ValidationFailed(result)) => ApiResponse::validation_error(
+            result.errors.iter()
+                .map(|e| match &e.field {
+                    Some(field) => ApiError::field_error(field, "invalid_setting", &e.message),
+                    None => ApiError::new("invalid_setting", &e.message),
+                })
+                .collect(),
+        ),
+    }
+}
+
 /// Get general settings
 pub async fn get_general_settings() -> Result<GeneralSettings, String> {
     Ok(GeneralSettings::default())
@@ -41,8 +71,12 @@ pub async fn get_search_appearance_settings() -> Result<SearchAppearanceSettings
 }
 
 /// Update search appearance settings
-pub async fn update_search_appearance_settings(_settings: SearchAppearanceSettings) -> Result<SearchAppearanceSettings, String> {
-    Ok(SearchAppearanceSettings::default())
+pub async fn update_search_appearance_settings(settings: SearchAppearanceSettings) -> Result<SearchAppearanceSettings, String> {
+    let errors = settings.validate();
+    if !errors.is_empty() {
+        return Err(errors.join("; "));
+    }
+    Ok(settings)
 }
 
 /// Get social settings
@@ -147,6 +181,26 @@ pub async fn validate_settings(_settings: RustSeoSettings) -> Result<ValidationR
     })
 }
 
+/// Get the JSON Schema for plugin settings
+///
+/// `GET /seo/v1/settings/schema`
+pub async fn get_settings_schema() -> Result<serde_json::Value, String> {
+    Ok(SeoSettings::to_json_schema())
+}
+
+/// Validate a single settings field while the admin is typing
+///
+/// `POST /seo/v1/settings/validate-field`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidateFieldRequest {
+    pub field: String,
+    pub value: serde_json::Value,
+}
+
+pub async fn validate_settings_field(request: ValidateFieldRequest) -> Result<crate::settings::ValidationResult, String> {
+    Ok(SeoSettings::validate_partial(&request.field, request.value))
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidationResult {
     pub valid: bool,
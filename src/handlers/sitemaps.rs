@@ -38,14 +38,10 @@ pub struct RegenerateRequest {
 }
 
 pub async fn regenerate_sitemaps(_request: RegenerateRequest) -> Result<GenerationResult, String> {
-    Ok(GenerationResult {
-        success: true,
-        sitemaps_generated: 0,
-        total_urls: 0,
-        generation_time_ms: 0,
-        errors: vec![],
-        warnings: vec![],
-    })
+    crate::plugin::get_plugin()
+        .generate_sitemap()
+        .await
+        .map_err(|e| e.message)
 }
 
 /// Get sitemap list
@@ -159,6 +155,104 @@ pub async fn validate_sitemap(_request: ValidateSitemapRequest) -> Result<Sitema
     })
 }
 
+/// Start a background reachability check of sitemap URLs
+#[cfg(feature = "sitemap-validation")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReachabilityCheckRequest {
+    pub sitemap_type: Option<String>,
+    #[serde(flatten)]
+    pub options: crate::services::sitemap::ReachabilityOptions,
+}
+
+#[cfg(feature = "sitemap-validation")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReachabilityCheckJob {
+    pub job_id: uuid::Uuid,
+    pub status: String,
+    pub started_at: String,
+    pub finished_at: Option<String>,
+    /// URLs the check will request
+    pub total_urls: usize,
+    /// Results so far while running, final once completed
+    pub report: Option<crate::services::sitemap::ReachabilityReport>,
+}
+
+/// Reachability check jobs by ID
+#[cfg(feature = "sitemap-validation")]
+fn reachability_jobs() -> &'static std::sync::Mutex<std::collections::HashMap<uuid::Uuid, ReachabilityCheckJob>> {
+    static JOBS: std::sync::OnceLock<std::sync::Mutex<std::collections::HashMap<uuid::Uuid, ReachabilityCheckJob>>> =
+        std::sync::OnceLock::new();
+    JOBS.get_or_init(Default::default)
+}
+
+/// Apply `update` to a stored job
+#[cfg(feature = "sitemap-validation")]
+fn update_reachability_job(job_id: uuid::Uuid, update: impl FnOnce(&mut ReachabilityCheckJob)) {
+    let mut jobs = reachability_jobs().lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+    if let Some(job) = jobs.get_mut(&job_id) {
+        update(job);
+    }
+}
+
+#[cfg(feature = "sitemap-validation")]
+pub async fn start_reachability_check(request: ReachabilityCheckRequest) -> Result<ReachabilityCheckJob, String> {
+    let sitemap_type = request.sitemap_type
+        .map(|t| {
+            serde_json::from_value::<crate::models::sitemap::SitemapType>(serde_json::Value::String(t.clone()))
+                .map_err(|_| format!("Unknown sitemap type: {}", t))
+        })
+        .transpose()?;
+
+    let service = crate::plugin::get_plugin().sitemaps();
+    let urls = service.published_urls(sitemap_type);
+    let total_urls = request.options.sample_size.map_or(urls.len(), |n| n.min(urls.len()));
+
+    let job = ReachabilityCheckJob {
+        job_id: uuid::Uuid::now_v7(),
+        status: "running".to_string(),
+        started_at: chrono::Utc::now().to_rfc3339(),
+        finished_at: None,
+        total_urls,
+        report: None,
+    };
+    let job_id = job.job_id;
+    reachability_jobs()
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .insert(job_id, job.clone());
+
+    tokio::spawn(async move {
+        let report = service
+            .validate_urls_reachability_with_progress(&urls, request.options, |progress| {
+                update_reachability_job(job_id, |job| job.report = Some(progress.clone()));
+            })
+            .await;
+        tracing::info!(
+            "Sitemap reachability check {} finished: {} checked, {} unreachable",
+            job_id,
+            report.checked,
+            report.unreachable.len()
+        );
+        update_reachability_job(job_id, |job| {
+            job.status = "completed".to_string();
+            job.finished_at = Some(chrono::Utc::now().to_rfc3339());
+            job.report = Some(report);
+        });
+    });
+
+    Ok(job)
+}
+
+/// Get status of a reachability check
+#[cfg(feature = "sitemap-validation")]
+pub async fn get_reachability_check(job_id: uuid::Uuid) -> super::ApiResponse<ReachabilityCheckJob> {
+    let jobs = reachability_jobs().lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+    match jobs.get(&job_id) {
+        Some(job) => super::ApiResponse::success(job.clone()),
+        None => super::ApiResponse::not_found(),
+    }
+}
+
 /// Get sitemap XML content
 pub async fn get_sitemap_xml(sitemap_type: String) -> Result<String, String> {
     // Would return actual sitemap XML in real implementation
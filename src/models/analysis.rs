@@ -2,10 +2,12 @@
 //!
 //! Models for analyzing content for SEO optimization.
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::meta::ContentType;
+
 /// Complete SEO analysis for a content item
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SeoAnalysis {
@@ -16,14 +18,148 @@ pub struct SeoAnalysis {
     pub meta_analysis: MetaAnalysis,
     pub content_analysis: ContentAnalysis,
     pub keyword_analysis: KeywordAnalysis,
+    /// One analysis per secondary focus keyword
+    #[serde(default)]
+    pub secondary_keyword_analyses: Vec<KeywordAnalysis>,
     pub readability_analysis: ReadabilityAnalysis,
     pub link_analysis: LinkAnalysis,
     pub image_analysis: ImageAnalysis,
     pub technical_analysis: TechnicalAnalysis,
+    /// Top 10 pages that mention the keywords but don't link here
+    #[serde(default)]
+    pub internal_link_opportunities: Vec<LinkingOpportunity>,
+    /// Top 10 people, places and organizations mentioned
+    #[serde(default)]
+    pub detected_entities: Vec<NamedEntity>,
+    /// Current-year mentions, update phrases and recent sources
+    #[serde(default)]
+    pub freshness_signals: FreshnessSignalReport,
+    /// Experience, expertise, authority and trust signals
+    #[serde(default)]
+    pub eeat_signals: EeatSignalAnalysis,
+    /// Paragraphs scored as standalone passages for passage indexing
+    #[serde(default)]
+    pub passage_analysis: PassageAnalysis,
     pub suggestions: Vec<SeoSuggestion>,
     pub analyzed_at: DateTime<Utc>,
 }
 
+/// Section scores of one analysis run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoreHistoryPoint {
+    pub date: DateTime<Utc>,
+    pub overall: i32,
+    pub title: i32,
+    pub meta: i32,
+    pub content: i32,
+    pub keyword: i32,
+}
+
+/// Direction of the overall score, with the slope in points per analysis
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScoreTrend {
+    Improving(f32),
+    Declining(f32),
+    #[default]
+    Stable,
+}
+
+/// Which inputs changed since the previous analysis
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct ChangedFields {
+    pub title_changed: bool,
+    pub description_changed: bool,
+    pub content_changed: bool,
+    pub keyword_changed: bool,
+}
+
+impl SeoAnalysis {
+    /// Overall score averaged over the component scores
+    pub fn calculate_overall_score(&self) -> SeoScore {
+        self.weighted_overall_score(1.0)
+    }
+
+    /// Weight of each secondary keyword relative to the focus keyword
+    pub const SECONDARY_KEYWORD_WEIGHT: f32 = 0.5;
+
+    /// Overall score with the link score weighted relative to the other components
+    fn weighted_overall_score(&self, link_weight: f32) -> SeoScore {
+        let mut scores = vec![
+            (self.title_analysis.score, 1.0),
+            (self.meta_analysis.score, 1.0),
+            (self.content_analysis.score, 1.0),
+            (self.keyword_analysis.score, 1.0),
+            (self.readability_analysis.score, 1.0),
+            (self.link_analysis.score, link_weight),
+            (self.image_analysis.score, 1.0),
+            (self.technical_analysis.score, 1.0),
+        ];
+        scores.extend(
+            self.secondary_keyword_analyses.iter()
+                .map(|analysis| (analysis.score, Self::SECONDARY_KEYWORD_WEIGHT)),
+        );
+
+        let total: f32 = scores.iter().map(|(score, weight)| *score as f32 * weight).sum();
+        let weights: f32 = scores.iter().map(|(_, weight)| weight).sum();
+
+        SeoScore::new((total / weights) as i32)
+    }
+
+    /// Grace period for new content, which can't have earned links yet
+    ///
+    /// Under 30 days old, link warnings are dropped and links count half in the
+    /// overall score. Under 7 days old, missing alt text warnings are dropped too.
+    pub fn apply_content_age_adjustment(analysis: &mut SeoAnalysis, days_since_publish: i64) {
+        const LINK_ISSUES: &[&str] = &["No internal links", "No outbound links"];
+        const IMAGE_ISSUES: &[&str] = &["Images missing alt text"];
+
+        if days_since_publish >= 30 {
+            return;
+        }
+
+        analysis.link_analysis.issues.retain(|issue| !LINK_ISSUES.contains(&issue.title.as_str()));
+
+        if days_since_publish < 7 {
+            analysis.image_analysis.issues.retain(|issue| !IMAGE_ISSUES.contains(&issue.title.as_str()));
+        }
+
+        analysis.overall_score = analysis.weighted_overall_score(0.5);
+    }
+
+    /// Keep components from `old` whose inputs didn't change
+    pub fn merge_with_previous(new: SeoAnalysis, old: SeoAnalysis, changed_fields: ChangedFields) -> SeoAnalysis {
+        let mut merged = new;
+
+        if !changed_fields.content_changed {
+            merged.content_analysis = old.content_analysis;
+            merged.readability_analysis = old.readability_analysis;
+            merged.link_analysis = old.link_analysis;
+            merged.image_analysis = old.image_analysis;
+            merged.technical_analysis = old.technical_analysis;
+            merged.freshness_signals = old.freshness_signals;
+            merged.eeat_signals = old.eeat_signals;
+
+            if !changed_fields.keyword_changed {
+                merged.keyword_analysis = old.keyword_analysis;
+                merged.secondary_keyword_analyses = old.secondary_keyword_analyses;
+                merged.passage_analysis = old.passage_analysis;
+            }
+        }
+
+        // Title and description checks also look for the focus keyword
+        if !changed_fields.title_changed && !changed_fields.keyword_changed {
+            merged.title_analysis = old.title_analysis;
+        }
+        if !changed_fields.description_changed && !changed_fields.keyword_changed {
+            merged.meta_analysis = old.meta_analysis;
+        }
+
+        merged.overall_score = merged.calculate_overall_score();
+        merged
+    }
+}
+
 /// SEO score (0-100)
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct SeoScore {
@@ -85,6 +221,7 @@ pub struct TitleAnalysis {
     pub length: usize,
     pub has_focus_keyword: bool,
     pub keyword_position: Option<usize>,
+    pub power_words: TitlePowerAnalysis,
     pub issues: Vec<AnalysisIssue>,
 }
 
@@ -143,17 +280,124 @@ impl TitleAnalysis {
             }
         }
 
+        // Power words lift CTR, capped so stuffing them doesn't pay off
+        let power_words = TitlePowerAnalysis::analyze(title);
+        score += (power_words.power_words_found.len() as i32 * 5).min(10);
+
         Self {
-            score: score.max(0),
+            score: score.clamp(0, 100),
             title: title.to_string(),
             length,
             has_focus_keyword: has_keyword,
             keyword_position: keyword_pos,
+            power_words,
             issues,
         }
     }
 }
 
+/// Power words with the strongest effect on click-through rate
+pub const HIGH_IMPACT_POWER_WORDS: &[&str] = &[
+    "ultimate", "complete", "proven", "free", "essential", "definitive", "exclusive",
+    "guaranteed", "secret", "instant", "powerful", "amazing", "incredible", "shocking",
+    "breakthrough", "revolutionary", "best", "easy", "fast", "new", "unbelievable",
+    "insane", "jaw-dropping", "remarkable", "epic", "must-have", "surprising", "hidden",
+    "little-known", "unusual", "mistakes", "warning", "avoid", "never", "stop",
+    "effortless", "massive", "bonus", "limited", "now", "urgent", "critical",
+    "vital", "killer", "genius", "hack", "hacks", "master", "legendary", "brilliant",
+];
+
+/// Power words with a moderate effect on click-through rate
+pub const MEDIUM_IMPACT_POWER_WORDS: &[&str] = &[
+    "simple", "quick", "step-by-step", "comprehensive", "practical", "expert",
+    "professional", "effective", "tips", "tricks", "strategies", "secrets", "guide",
+    "checklist", "blueprint", "formula", "framework", "lessons", "ideas", "ways",
+    "reasons", "facts", "success", "boost", "improve", "grow", "save", "discover",
+    "learn", "unlock", "transform", "simplify", "smart", "clever", "inspiring",
+    "helpful", "valuable", "useful", "reliable", "trusted", "official", "updated",
+    "latest", "modern", "beginner", "advanced", "affordable", "cheap", "today",
+    "everything",
+];
+
+const POSITIVE_SENTIMENT_WORDS: &[&str] = &[
+    "best", "amazing", "easy", "free", "success", "love", "great", "brilliant",
+    "incredible", "proven", "save", "boost", "improve", "win", "happy", "perfect",
+];
+
+const NEGATIVE_SENTIMENT_WORDS: &[&str] = &[
+    "mistakes", "warning", "avoid", "never", "stop", "worst", "fail", "failure",
+    "bad", "shocking", "dangerous", "wrong", "lose", "killer", "problem", "hate",
+];
+
+/// Click-through signals in a title
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TitlePowerAnalysis {
+    pub score: i32,
+    pub power_words_found: Vec<String>,
+    pub has_number: bool,
+    pub has_current_year: bool,
+    pub has_question: bool,
+    /// -1.0 (negative) to 1.0 (positive)
+    pub character_sentiment: f32,
+}
+
+impl TitlePowerAnalysis {
+    pub fn analyze(title: &str) -> Self {
+        let lower = title.to_lowercase();
+        let words: Vec<&str> = lower
+            .split(|c: char| !c.is_alphanumeric() && c != '-')
+            .filter(|w| !w.is_empty())
+            .collect();
+
+        let mut power_words_found = Vec::new();
+        let mut score = 0;
+        for word in &words {
+            if power_words_found.iter().any(|found| found == word) {
+                continue;
+            }
+            if HIGH_IMPACT_POWER_WORDS.contains(word) {
+                power_words_found.push(word.to_string());
+                score += 20;
+            } else if MEDIUM_IMPACT_POWER_WORDS.contains(word) {
+                power_words_found.push(word.to_string());
+                score += 10;
+            }
+        }
+
+        let has_number = words.iter().any(|w| w.chars().all(|c| c.is_ascii_digit()));
+        let current_year = Utc::now().year().to_string();
+        let has_current_year = words.contains(&current_year.as_str());
+        let has_question = title.trim_end().ends_with('?');
+
+        if has_number {
+            score += 15;
+        }
+        if has_current_year {
+            score += 10;
+        }
+        if has_question {
+            score += 10;
+        }
+
+        let positive = words.iter().filter(|w| POSITIVE_SENTIMENT_WORDS.contains(w)).count();
+        let negative = words.iter().filter(|w| NEGATIVE_SENTIMENT_WORDS.contains(w)).count();
+        let character_sentiment = if positive + negative == 0 {
+            0.0
+        } else {
+            (positive as f32 - negative as f32) / (positive + negative) as f32
+        };
+
+        Self {
+            score: score.min(100),
+            power_words_found,
+            has_number,
+            has_current_year,
+            has_question,
+            character_sentiment,
+        }
+    }
+}
+
 /// Meta description analysis
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MetaAnalysis {
@@ -239,9 +483,21 @@ pub struct ContentAnalysis {
     pub sentence_count: usize,
     pub heading_count: HeadingCount,
     pub has_h1: bool,
+    pub paragraph_distribution: ParagraphDistribution,
     pub issues: Vec<AnalysisIssue>,
 }
 
+/// Paragraph length distribution
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ParagraphDistribution {
+    pub count: usize,
+    pub avg_words: f32,
+    pub max_words: usize,
+    pub min_words: usize,
+    pub over_150_words: usize,
+    pub under_20_words: usize,
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct HeadingCount {
     pub h1: usize,
@@ -315,6 +571,28 @@ impl ContentAnalysis {
             score -= 5;
         }
 
+        // Paragraph lengths
+        let paragraph_distribution = Self::paragraph_length_distribution(content);
+        if paragraph_distribution.count > 0 {
+            let count = paragraph_distribution.count as f32;
+            if paragraph_distribution.over_150_words as f32 / count > 0.25 {
+                issues.push(AnalysisIssue::new(
+                    IssueSeverity::Warning,
+                    "Too many long paragraphs",
+                    "More than 25% of paragraphs exceed 150 words. Split them up.",
+                ));
+                score -= 10;
+            }
+            if paragraph_distribution.under_20_words as f32 / count > 0.5 {
+                issues.push(AnalysisIssue::new(
+                    IssueSeverity::Info,
+                    "Too many short paragraphs",
+                    "More than half of paragraphs are under 20 words. Consider combining related ones.",
+                ));
+                score -= 5;
+            }
+        }
+
         Self {
             score: score.max(0),
             word_count,
@@ -322,9 +600,32 @@ impl ContentAnalysis {
             sentence_count,
             heading_count,
             has_h1,
+            paragraph_distribution,
             issues,
         }
     }
+
+    /// Word count distribution across paragraphs, ignoring headings
+    pub fn paragraph_length_distribution(content: &str) -> ParagraphDistribution {
+        let lengths: Vec<usize> = content.split("\n\n")
+            .map(|p| p.trim())
+            .filter(|p| !p.is_empty() && !p.starts_with('#'))
+            .map(|p| p.split_whitespace().count())
+            .collect();
+
+        if lengths.is_empty() {
+            return ParagraphDistribution::default();
+        }
+
+        ParagraphDistribution {
+            count: lengths.len(),
+            avg_words: lengths.iter().sum::<usize>() as f32 / lengths.len() as f32,
+            max_words: lengths.iter().copied().max().unwrap_or(0),
+            min_words: lengths.iter().copied().min().unwrap_or(0),
+            over_150_words: lengths.iter().filter(|l| **l > 150).count(),
+            under_20_words: lengths.iter().filter(|l| **l < 20).count(),
+        }
+    }
 }
 
 /// Keyword analysis
@@ -336,23 +637,78 @@ pub struct KeywordAnalysis {
     pub keyword_density: f32,
     pub in_first_paragraph: bool,
     pub in_headings: bool,
+    pub heading_placement: Option<HeadingKeywordPlacement>,
     pub in_url: bool,
+    /// First keyword match as a fraction of content length, 0.0 = very start
+    pub keyword_prominence: Option<f32>,
+    /// Frequent content terms related to the focus keyword
+    #[serde(default)]
+    pub related_keywords: Vec<RelatedKeyword>,
     pub issues: Vec<AnalysisIssue>,
 }
 
+/// Content term that co-occurs with the focus keyword
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelatedKeyword {
+    pub keyword: String,
+    pub occurrences: i32,
+    pub density: f32,
+}
+
+/// Focus keyword placement within headings
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HeadingKeywordPlacement {
+    pub in_h1: bool,
+    pub in_h2: bool,
+    /// Word offset of the keyword within the H1
+    pub h1_keyword_position: Option<usize>,
+    pub best_heading_match: Option<String>,
+    /// Headings containing only some of the keyword terms
+    pub partial_matches: usize,
+}
+
 /// Readability analysis
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReadabilityAnalysis {
     pub score: i32,
     pub flesch_reading_ease: f32,
     pub flesch_kincaid_grade: f32,
+    pub coleman_liau_index: f32,
+    pub smog_grade: f32,
     pub avg_sentence_length: f32,
     pub avg_word_length: f32,
     pub passive_voice_percentage: f32,
+    /// Sentences written in passive voice, for inline highlighting
+    pub passive_sentences: Vec<String>,
     pub transition_word_percentage: f32,
+    /// Sentences with too many dependent clauses
+    pub complex_sentence_count: usize,
+    /// Sentences longer than `AnalysisSettings::max_sentence_word_count`
+    pub long_sentence_count: usize,
+    pub long_sentence_percentage: f32,
+    /// Up to five long sentences, for display
+    pub long_sentences: Vec<String>,
     pub issues: Vec<AnalysisIssue>,
 }
 
+/// Dependent clause complexity of a sentence
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct SentenceComplexity {
+    pub clause_count: usize,
+    /// Two or more clause indicators without punctuation between them
+    pub has_nested_clauses: bool,
+    pub complexity_score: f32,
+}
+
+impl SentenceComplexity {
+    /// Score above which a sentence counts as complex
+    pub const COMPLEX_THRESHOLD: f32 = 2.5;
+
+    pub fn is_complex(&self) -> bool {
+        self.complexity_score > Self::COMPLEX_THRESHOLD
+    }
+}
+
 /// Link analysis
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LinkAnalysis {
@@ -372,9 +728,35 @@ pub struct ImageAnalysis {
     pub images_with_alt: usize,
     pub images_with_keyword: usize,
     pub large_images: Vec<String>,
+    /// Alt text quality of each image that has alt text
+    pub alt_quality: Vec<ImageAltQuality>,
     pub issues: Vec<AnalysisIssue>,
 }
 
+/// How descriptive an alt text is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AltQuality {
+    Poor,
+    Acceptable,
+    Good,
+}
+
+/// Alt text quality with the reasons behind it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AltQualityReport {
+    pub quality: AltQuality,
+    pub issues: Vec<String>,
+}
+
+/// Alt text quality of a single image
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageAltQuality {
+    pub src: String,
+    pub quality: AltQuality,
+    pub issues: Vec<String>,
+}
+
 /// Technical SEO analysis
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TechnicalAnalysis {
@@ -386,6 +768,115 @@ pub struct TechnicalAnalysis {
     pub has_schema: bool,
     pub page_load_time: Option<f32>,
     pub mobile_friendly: bool,
+    pub mobile_issues: Option<MobileIssueReport>,
+    /// How well the content answers spoken questions
+    pub voice_search: Option<VoiceSearchAnalysis>,
+    pub issues: Vec<AnalysisIssue>,
+}
+
+/// Readiness of content for voice assistant answers
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VoiceSearchAnalysis {
+    /// 0-100
+    pub score: i32,
+    pub avg_sentence_length: f32,
+    /// Content opens with an answer to the question implied by the title
+    pub has_direct_answer: bool,
+    pub question_answer_pairs: usize,
+    pub recommendations: Vec<String>,
+}
+
+/// Signals that content is kept up to date
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FreshnessSignalReport {
+    pub current_year_mentioned: bool,
+    /// Update phrases and dated headings found in the content
+    pub freshness_phrases: Vec<String>,
+    pub score_adjustment: i32,
+}
+
+/// Mobile usability problems found in page HTML
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MobileIssueReport {
+    pub has_viewport_meta: bool,
+    /// Inline font sizes below 12px
+    pub has_small_font: bool,
+    /// Pixel widths above 600px without a max-width
+    pub has_fixed_width_elements: bool,
+    /// `overflow: hidden` on the body, which hides content wider than the screen
+    pub has_horizontal_scroll_risk: bool,
+    pub issues: Vec<AnalysisIssue>,
+}
+
+impl MobileIssueReport {
+    pub fn is_mobile_friendly(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Passage indexing analysis
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PassageAnalysis {
+    pub top_passages: Vec<PassageScore>,
+    pub avg_passage_score: f32,
+    pub total_passages: usize,
+}
+
+/// Score for a single passage
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PassageScore {
+    pub text: String,
+    pub score: f32,
+    pub word_count: usize,
+    pub has_keyword: bool,
+}
+
+/// Person, place or organization mentioned in content
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NamedEntity {
+    pub text: String,
+    pub entity_type: EntityType,
+    pub count: usize,
+    /// Byte offsets of each mention
+    pub start_positions: Vec<usize>,
+}
+
+/// Kind of named entity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EntityType {
+    Person,
+    Location,
+    Organization,
+    Product,
+    Unknown,
+}
+
+/// Page that could link to a target page
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinkingOpportunity {
+    pub source_url: String,
+    pub suggested_anchor: String,
+    pub context_sentence: String,
+    pub relevance_score: f32,
+}
+
+/// Author details used for E-E-A-T signals
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthorProfile {
+    pub name: String,
+    pub bio: Option<String>,
+    pub url: Option<String>,
+    pub credentials: Vec<String>,
+}
+
+/// Experience, expertise, authority and trust signals found in content
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EeatSignalAnalysis {
+    /// 0-100
+    pub expertise_score: i32,
+    pub authority_signals: Vec<String>,
+    pub trust_signals: Vec<String>,
     pub issues: Vec<AnalysisIssue>,
 }
 
@@ -411,8 +902,10 @@ impl AnalysisIssue {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum IssueSeverity {
+    #[serde(alias = "critical")]
     Error,
     Warning,
+    Suggestion,
     Info,
     Success,
 }
@@ -422,6 +915,7 @@ impl IssueSeverity {
         match self {
             Self::Error => "error",
             Self::Warning => "warning",
+            Self::Suggestion => "suggestion",
             Self::Info => "info",
             Self::Success => "success",
         }
@@ -431,10 +925,23 @@ impl IssueSeverity {
         match self {
             Self::Error => "#dc3232",
             Self::Warning => "#ffb900",
+            Self::Suggestion => "#17a2b8",
             Self::Info => "#0073aa",
             Self::Success => "#00a32a",
         }
     }
+
+    /// Parse a severity name, accepting the admin's old `critical` spelling
+    pub fn from_admin_severity(s: &str) -> Option<IssueSeverity> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "critical" | "error" => Some(Self::Error),
+            "warning" => Some(Self::Warning),
+            "suggestion" => Some(Self::Suggestion),
+            "info" => Some(Self::Info),
+            "success" => Some(Self::Success),
+            _ => None,
+        }
+    }
 }
 
 /// SEO suggestion
@@ -465,6 +972,57 @@ pub struct AnalysisSettings {
     pub check_readability: bool,
     pub check_links: bool,
     pub check_images: bool,
+    #[serde(default = "default_target_grade_level")]
+    pub target_grade_level: u8,
+    /// Relax link and image checks for recently published content.
+    /// Off by default so existing score histories stay comparable.
+    #[serde(default)]
+    pub apply_age_adjustments: bool,
+    /// Word count threshold for cornerstone content; 0 uses twice `min_word_count`
+    #[serde(default)]
+    pub cornerstone_min_word_count: usize,
+    /// Sentences with more words than this count as long
+    #[serde(default = "default_max_sentence_word_count")]
+    pub max_sentence_word_count: usize,
+}
+
+fn default_max_sentence_word_count() -> usize { 20 }
+
+fn default_target_grade_level() -> u8 { 8 }
+
+impl AnalysisSettings {
+    pub fn builder() -> AnalysisSettingsBuilder {
+        AnalysisSettingsBuilder::new()
+    }
+
+    /// Default settings with a word count threshold suited to the content type
+    pub fn default_for_content_type(content_type: &ContentType) -> Self {
+        Self {
+            min_word_count: Self::adaptive_min_word_count(content_type),
+            ..Self::default()
+        }
+    }
+
+    /// Minimum word count expected for a content type
+    pub fn adaptive_min_word_count(content_type: &ContentType) -> usize {
+        match content_type {
+            ContentType::Post => 300,
+            ContentType::Page | ContentType::Product => 150,
+            ContentType::Custom => 100,
+            ContentType::Category | ContentType::Tag | ContentType::Author | ContentType::Archive => 50,
+        }
+    }
+
+    /// Word count threshold, raised for cornerstone content
+    pub fn min_word_count_for(&self, is_cornerstone: bool) -> usize {
+        if !is_cornerstone {
+            self.min_word_count
+        } else if self.cornerstone_min_word_count > 0 {
+            self.cornerstone_min_word_count
+        } else {
+            self.min_word_count * 2
+        }
+    }
 }
 
 impl Default for AnalysisSettings {
@@ -477,6 +1035,176 @@ impl Default for AnalysisSettings {
             check_readability: true,
             check_links: true,
             check_images: true,
+            target_grade_level: 8,
+            apply_age_adjustments: false,
+            cornerstone_min_word_count: 0,
+            max_sentence_word_count: default_max_sentence_word_count(),
+        }
+    }
+}
+
+/// Fluent builder for analysis settings
+#[derive(Debug, Clone)]
+pub struct AnalysisSettingsBuilder {
+    settings: AnalysisSettings,
+}
+
+impl AnalysisSettingsBuilder {
+    pub fn new() -> Self {
+        Self {
+            settings: AnalysisSettings::default(),
+        }
+    }
+
+    pub fn min_word_count(mut self, count: usize) -> Self {
+        self.settings.min_word_count = count;
+        self
+    }
+
+    pub fn keyword_density_range(mut self, target: f32, max: f32) -> Self {
+        self.settings.target_keyword_density = target;
+        self.settings.max_keyword_density = max;
+        self
+    }
+
+    pub fn check_readability(mut self, enabled: bool) -> Self {
+        self.settings.check_readability = enabled;
+        self
+    }
+
+    pub fn check_links(mut self, enabled: bool) -> Self {
+        self.settings.check_links = enabled;
+        self
+    }
+
+    pub fn check_images(mut self, enabled: bool) -> Self {
+        self.settings.check_images = enabled;
+        self
+    }
+
+    pub fn target_grade_level(mut self, grade: u8) -> Self {
+        self.settings.target_grade_level = grade;
+        self
+    }
+
+    pub fn apply_age_adjustments(mut self, enabled: bool) -> Self {
+        self.settings.apply_age_adjustments = enabled;
+        self
+    }
+
+    pub fn cornerstone_min_word_count(mut self, count: usize) -> Self {
+        self.settings.cornerstone_min_word_count = count;
+        self
+    }
+
+    pub fn max_sentence_word_count(mut self, count: usize) -> Self {
+        self.settings.max_sentence_word_count = count;
+        self
+    }
+
+    /// Validate and build the settings
+    pub fn build(self) -> Result<AnalysisSettings, AnalysisSettingsError> {
+        let mut violations = Vec::new();
+
+        if self.settings.target_keyword_density >= self.settings.max_keyword_density {
+            violations.push(format!(
+                "Target keyword density ({}) must be lower than max keyword density ({})",
+                self.settings.target_keyword_density, self.settings.max_keyword_density
+            ));
+        }
+
+        if self.settings.min_word_count == 0 {
+            violations.push("Minimum word count must be greater than 0".to_string());
         }
+
+        if self.settings.max_sentence_word_count == 0 {
+            violations.push("Maximum sentence word count must be greater than 0".to_string());
+        }
+
+        if self.settings.target_grade_level == 0 || self.settings.target_grade_level > 16 {
+            violations.push(format!(
+                "Target grade level must be between 1 and 16, got {}",
+                self.settings.target_grade_level
+            ));
+        }
+
+        if violations.is_empty() {
+            Ok(self.settings)
+        } else {
+            Err(AnalysisSettingsError(violations))
+        }
+    }
+}
+
+impl Default for AnalysisSettingsBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Invalid analysis settings
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("invalid analysis settings: {}", .0.join("; "))]
+pub struct AnalysisSettingsError(pub Vec<String>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_admin_severity() {
+        assert_eq!(IssueSeverity::from_admin_severity("critical"), Some(IssueSeverity::Error));
+        assert_eq!(IssueSeverity::from_admin_severity("Warning"), Some(IssueSeverity::Warning));
+        assert_eq!(IssueSeverity::from_admin_severity("suggestion"), Some(IssueSeverity::Suggestion));
+        assert_eq!(IssueSeverity::from_admin_severity("info"), Some(IssueSeverity::Info));
+        assert_eq!(IssueSeverity::from_admin_severity("blocker"), None);
+    }
+
+    #[test]
+    fn test_from_admin_severity_round_trips_as_str() {
+        for severity in [
+            IssueSeverity::Error,
+            IssueSeverity::Warning,
+            IssueSeverity::Suggestion,
+            IssueSeverity::Info,
+            IssueSeverity::Success,
+        ] {
+            assert_eq!(IssueSeverity::from_admin_severity(severity.as_str()), Some(severity));
+        }
+    }
+
+    #[test]
+    fn test_cornerstone_min_word_count() {
+        let settings = AnalysisSettings::default();
+        assert_eq!(settings.min_word_count_for(false), 300);
+        assert_eq!(settings.min_word_count_for(true), 600);
+
+        let settings = AnalysisSettings::builder()
+            .cornerstone_min_word_count(1500)
+            .build()
+            .unwrap();
+        assert_eq!(settings.min_word_count_for(true), 1500);
+    }
+
+    #[test]
+    fn test_critical_deserializes_as_error() {
+        let severity: IssueSeverity = serde_json::from_str("\"critical\"").unwrap();
+        assert_eq!(severity, IssueSeverity::Error);
+        assert_eq!(serde_json::to_string(&severity).unwrap(), "\"error\"");
+    }
+
+    #[test]
+    fn test_settings_without_newer_fields_deserialize() {
+        let settings: AnalysisSettings = serde_json::from_str(r#"{
+            "enabled": true,
+            "min_word_count": 300,
+            "target_keyword_density": 1.5,
+            "max_keyword_density": 3.0,
+            "check_readability": true,
+            "check_links": true,
+            "check_images": true
+        }"#).unwrap();
+        assert_eq!(settings.target_grade_level, 8);
+        assert!(!settings.apply_age_adjustments);
     }
 }
@@ -20,6 +20,8 @@ pub struct BreadcrumbItem {
     pub name: String,
     pub url: Option<String>,
     pub position: i32,
+    /// Item for the page being viewed
+    pub is_current: bool,
 }
 
 impl Breadcrumb {
@@ -34,16 +36,59 @@ impl Breadcrumb {
 
     pub fn add(&mut self, name: String, url: Option<String>) -> &mut Self {
         let position = (self.items.len() + 1) as i32;
-        self.items.push(BreadcrumbItem { name, url, position });
+        self.items.push(BreadcrumbItem { name, url, position, is_current: false });
         self
     }
 
+    pub fn add_item(&mut self, label: &str, url: Option<&str>) {
+        self.add(label.to_string(), url.map(str::to_string));
+    }
+
+    /// Mark the last item as the current page
+    pub fn mark_current(&mut self) {
+        let last = self.items.len().saturating_sub(1);
+        for (i, item) in self.items.iter_mut().enumerate() {
+            item.is_current = i == last;
+        }
+    }
+
+    /// Build a trail from a URL path, one item per segment
+    ///
+    /// Segments the resolver has no label for are title-cased from the slug.
+    pub fn from_path(
+        path: &str,
+        site_url: &str,
+        label_resolver: impl Fn(&str) -> Option<String>,
+    ) -> Breadcrumb {
+        let site_url = site_url.trim_end_matches('/');
+        let mut breadcrumb = Breadcrumb::new();
+
+        if breadcrumb.show_home {
+            let home_text = breadcrumb.home_text.clone();
+            breadcrumb.add_item(&home_text, Some(&format!("{}/", site_url)));
+        }
+
+        let path = path.split(['?', '#']).next().unwrap_or_default();
+        let mut url = site_url.to_string();
+        for segment in path.split('/').filter(|s| !s.is_empty()) {
+            url.push('/');
+            url.push_str(segment);
+
+            let label = label_resolver(segment).unwrap_or_else(|| segment_label(segment));
+            breadcrumb.add_item(&label, Some(&format!("{}/", url)));
+        }
+
+        breadcrumb.mark_current();
+        breadcrumb
+    }
+
     pub fn with_home(mut self, home_url: &str) -> Self {
         if self.show_home {
             let mut items = vec![BreadcrumbItem {
                 name: self.home_text.clone(),
                 url: Some(home_url.to_string()),
                 position: 1,
+                is_current: false,
             }];
 
             // Adjust positions
@@ -57,10 +102,16 @@ impl Breadcrumb {
         self
     }
 
-    /// Generate HTML for breadcrumbs
-    pub fn to_html(&self) -> String {
+    /// Generate HTML for breadcrumbs using the configured separator and prefix
+    pub fn to_html(&self, settings: &BreadcrumbSettings) -> String {
         let mut html = String::new();
         html.push_str("<nav class=\"breadcrumb\" aria-label=\"Breadcrumb\">\n");
+        if settings.show_prefix && !settings.prefix_text.is_empty() {
+            html.push_str(&format!(
+                "  <span class=\"breadcrumb-prefix\">{}</span>\n",
+                html_escape(&settings.prefix_text)
+            ));
+        }
         html.push_str("  <ol class=\"breadcrumb-list\">\n");
 
         for (i, item) in self.items.iter().enumerate() {
@@ -68,30 +119,35 @@ impl Breadcrumb {
 
             html.push_str("    <li class=\"breadcrumb-item\">\n");
 
-            if let Some(url) = &item.url {
-                if !is_last {
+            let name = html_escape(&item.name);
+            match &item.url {
+                Some(url) if !is_last || settings.show_last_as_link => {
                     html.push_str(&format!(
                         "      <a href=\"{}\">{}</a>\n",
                         html_escape(url),
-                        html_escape(&item.name)
+                        name
                     ));
-                } else {
+                }
+                _ if is_last => {
+                    let name = if settings.bold_current {
+                        format!("<strong>{}</strong>", name)
+                    } else {
+                        name
+                    };
                     html.push_str(&format!(
                         "      <span aria-current=\"page\">{}</span>\n",
-                        html_escape(&item.name)
+                        name
                     ));
                 }
-            } else {
-                html.push_str(&format!(
-                    "      <span>{}</span>\n",
-                    html_escape(&item.name)
-                ));
+                _ => {
+                    html.push_str(&format!("      <span>{}</span>\n", name));
+                }
             }
 
             if !is_last {
                 html.push_str(&format!(
                     "      <span class=\"breadcrumb-separator\">{}</span>\n",
-                    html_escape(&self.separator)
+                    html_escape(&settings.separator)
                 ));
             }
 
@@ -126,9 +182,12 @@ impl Breadcrumb {
         })
     }
 
-    /// Generate complete HTML with schema markup
-    pub fn to_html_with_schema(&self) -> String {
-        let mut html = self.to_html();
+    /// Generate complete HTML with schema markup, when enabled
+    pub fn to_html_with_schema(&self, settings: &BreadcrumbSettings) -> String {
+        let mut html = self.to_html(settings);
+        if !settings.schema_enabled {
+            return html;
+        }
         html.push_str(&format!(
             "<script type=\"application/ld+json\">\n{}\n</script>\n",
             serde_json::to_string_pretty(&self.to_json_ld()).unwrap_or_default()
@@ -154,6 +213,9 @@ pub struct BreadcrumbSettings {
     pub show_prefix: bool,
     pub prefix_text: String,
     pub show_last_as_link: bool,
+    /// Wrap the current page's item in `<strong>`
+    #[serde(default)]
+    pub bold_current: bool,
     pub show_post_type: bool,
     pub show_category: bool,
     pub show_parent_pages: bool,
@@ -171,6 +233,7 @@ impl Default for BreadcrumbSettings {
             show_prefix: false,
             prefix_text: "You are here: ".to_string(),
             show_last_as_link: false,
+            bold_current: false,
             show_post_type: false,
             show_category: true,
             show_parent_pages: true,
@@ -179,6 +242,22 @@ impl Default for BreadcrumbSettings {
     }
 }
 
+/// Readable label from a URL slug, e.g. `seo-tips` -> `Seo Tips`
+fn segment_label(segment: &str) -> String {
+    segment
+        .split(['-', '_'])
+        .filter(|w| !w.is_empty())
+        .map(|w| {
+            let mut chars = w.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
 fn html_escape(s: &str) -> String {
     s.replace('&', "&amp;")
         .replace('<', "&lt;")
@@ -91,7 +91,7 @@ pub enum KeywordTrend {
 
 /// Keyword research result
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct KeywordResearch {
+pub struct KeywordResearchResult {
     pub seed_keyword: String,
     pub suggestions: Vec<KeywordSuggestion>,
     pub questions: Vec<String>,
@@ -100,6 +100,84 @@ pub struct KeywordResearch {
     pub generated_at: DateTime<Utc>,
 }
 
+/// Planned keyword being researched and tracked
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeywordResearch {
+    pub id: Uuid,
+    pub keyword: String,
+    pub search_volume: Option<i64>,
+    pub difficulty: Option<f32>,
+    pub cpc: Option<f32>,
+    pub intent: SearchIntent,
+    pub assigned_content_id: Option<Uuid>,
+    pub target_url: Option<String>,
+    pub current_rank: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl KeywordResearch {
+    pub fn new(keyword: String, intent: SearchIntent) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::now_v7(),
+            keyword,
+            search_volume: None,
+            difficulty: None,
+            cpc: None,
+            intent,
+            assigned_content_id: None,
+            target_url: None,
+            current_rank: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Suggested URL slug for this keyword
+    pub fn suggested_slug(&self) -> String {
+        keyword_to_url_slug(&self.keyword)
+    }
+}
+
+/// Search intent behind a keyword
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchIntent {
+    Informational,
+    Navigational,
+    Transactional,
+    Commercial,
+}
+
+impl SearchIntent {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Informational => "Informational",
+            Self::Navigational => "Navigational",
+            Self::Transactional => "Transactional",
+            Self::Commercial => "Commercial",
+        }
+    }
+}
+
+/// Convert a keyword into a URL slug
+pub fn keyword_to_url_slug(keyword: &str) -> String {
+    let slug: String = keyword
+        .to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-")
+        .chars()
+        .filter(|c| c.is_alphanumeric() || *c == '-')
+        .collect();
+
+    slug.split('-')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
 /// Keyword density analysis
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KeywordDensity {
@@ -41,13 +41,96 @@ pub struct SeoMeta {
     pub max_image_preview: Option<ImagePreviewSize>,
     pub max_video_preview: Option<i32>,
 
+    /// Cornerstone (pillar) content is analyzed with stricter rules
+    #[serde(default)]
+    pub is_cornerstone: bool,
+
+    /// Translations of this content for hreflang links
+    #[serde(default)]
+    pub hreflang_alternates: Vec<HreflangEntry>,
+
     // Timestamps
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+/// Language alternate of a page
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HreflangEntry {
+    /// Language or language-region code, e.g. `en` or `en-GB`
+    pub locale: String,
+    pub url: String,
+}
+
+impl HreflangEntry {
+    pub fn new(locale: &str, url: &str) -> Self {
+        Self {
+            locale: locale.to_string(),
+            url: url.to_string(),
+        }
+    }
+}
+
+/// Generate hreflang alternate link tags, plus `x-default` pointing at `x_default`
+pub fn hreflang_html(alternates: &[HreflangEntry], x_default: Option<&str>) -> String {
+    let mut html = String::new();
+
+    for entry in alternates.iter().filter(|e| !e.locale.eq_ignore_ascii_case("x-default")) {
+        html.push_str(&format!(
+            "<link rel=\"alternate\" hreflang=\"{}\" href=\"{}\" />\n",
+            html_escape(&entry.locale.replace('_', "-")),
+            html_escape(&entry.url)
+        ));
+    }
+
+    // An explicit x-default entry wins over the canonical URL
+    let x_default = alternates.iter()
+        .find(|e| e.locale.eq_ignore_ascii_case("x-default"))
+        .map(|e| e.url.as_str())
+        .or(x_default);
+    if let Some(url) = x_default.filter(|_| !alternates.is_empty()) {
+        html.push_str(&format!(
+            "<link rel=\"alternate\" hreflang=\"x-default\" href=\"{}\" />\n",
+            html_escape(url)
+        ));
+    }
+
+    html
+}
+
+/// Pagination link tags for multi-page content
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaginationLinks {
+    pub prev: Option<String>,
+    pub next: Option<String>,
+    pub canonical: String,
+}
+
+impl PaginationLinks {
+    /// Generate rel prev/next link tags HTML
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+
+        if let Some(prev) = &self.prev {
+            html.push_str(&format!(
+                "<link rel=\"prev\" href=\"{}\">\n",
+                html_escape(prev)
+            ));
+        }
+
+        if let Some(next) = &self.next {
+            html.push_str(&format!(
+                "<link rel=\"next\" href=\"{}\">\n",
+                html_escape(next)
+            ));
+        }
+
+        html
+    }
+}
+
 /// Content type for SEO meta
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ContentType {
     Post,
@@ -60,6 +143,13 @@ pub enum ContentType {
     Custom,
 }
 
+impl ContentType {
+    /// Taxonomy and author pages where thin content is expected
+    pub fn is_short_form(&self) -> bool {
+        matches!(self, Self::Category | Self::Tag | Self::Author)
+    }
+}
+
 /// Robots meta directives
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MetaRobots {
@@ -107,6 +197,14 @@ impl MetaRobots {
         }
     }
 
+    /// Parse the `content` attribute of a robots meta tag
+    ///
+    /// Snippet and preview limits are validated but not kept; use
+    /// [`SeoMeta::apply_robots_content`] to read them as well.
+    pub fn from_html_meta_content(content: &str) -> Result<Self, MetaParseError> {
+        parse_robots_content(content).map(|(robots, _)| robots)
+    }
+
     /// Generate robots meta content string
     pub fn to_content_string(&self) -> String {
         let mut directives = Vec::new();
@@ -143,6 +241,83 @@ impl MetaRobots {
     }
 }
 
+/// Error parsing a robots meta `content` attribute
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum MetaParseError {
+    #[error("unknown robots directive '{0}'")]
+    UnknownDirective(String),
+    #[error("invalid value '{value}' for {directive}")]
+    InvalidValue { directive: String, value: String },
+}
+
+/// Snippet and preview limits from a robots meta tag
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct RobotsLimits {
+    max_snippet: Option<i32>,
+    max_image_preview: Option<ImagePreviewSize>,
+    max_video_preview: Option<i32>,
+}
+
+/// Parse comma-separated robots directives, case-insensitively
+fn parse_robots_content(content: &str) -> Result<(MetaRobots, RobotsLimits), MetaParseError> {
+    let mut robots = MetaRobots::new();
+    let mut limits = RobotsLimits::default();
+
+    let parse_limit = |directive: &str, value: &str| {
+        value.parse::<i32>()
+            .ok()
+            .filter(|v| *v >= -1)
+            .ok_or_else(|| MetaParseError::InvalidValue {
+                directive: directive.to_string(),
+                value: value.to_string(),
+            })
+    };
+
+    for directive in content.split(',').map(|d| d.trim().to_lowercase()).filter(|d| !d.is_empty()) {
+        if let Some((name, value)) = directive.split_once(':') {
+            let (name, value) = (name.trim(), value.trim());
+            match name {
+                "max-snippet" => limits.max_snippet = Some(parse_limit(name, value)?),
+                "max-video-preview" => limits.max_video_preview = Some(parse_limit(name, value)?),
+                "max-image-preview" => {
+                    limits.max_image_preview = Some(ImagePreviewSize::parse(value).ok_or_else(|| {
+                        MetaParseError::InvalidValue {
+                            directive: name.to_string(),
+                            value: value.to_string(),
+                        }
+                    })?);
+                }
+                // Only controls when the page drops out of results
+                "unavailable_after" => {}
+                _ => return Err(MetaParseError::UnknownDirective(directive.clone())),
+            }
+            continue;
+        }
+
+        match directive.as_str() {
+            "index" => robots.index = true,
+            "noindex" => robots.index = false,
+            "follow" => robots.follow = true,
+            "nofollow" => robots.follow = false,
+            "all" => {
+                robots.index = true;
+                robots.follow = true;
+            }
+            "none" => {
+                robots.index = false;
+                robots.follow = false;
+            }
+            "noarchive" | "nocache" => robots.no_archive = true,
+            "nosnippet" => robots.no_snippet = true,
+            "noimageindex" => robots.no_image_index = true,
+            "notranslate" => robots.no_translate = true,
+            _ => return Err(MetaParseError::UnknownDirective(directive.clone())),
+        }
+    }
+
+    Ok((robots, limits))
+}
+
 /// Image preview size for Google
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -152,6 +327,48 @@ pub enum ImagePreviewSize {
     Large,
 }
 
+impl ImagePreviewSize {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Standard => "standard",
+            Self::Large => "large",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "none" => Some(Self::None),
+            "standard" => Some(Self::Standard),
+            "large" => Some(Self::Large),
+            _ => None,
+        }
+    }
+}
+
+/// Generate the complete robots content string including snippet and preview limits
+pub fn to_full_content_string(meta: &SeoMeta) -> String {
+    let robots = MetaRobots {
+        no_archive: meta.robots.no_archive || meta.no_archive,
+        no_snippet: meta.robots.no_snippet || meta.no_snippet,
+        no_image_index: meta.robots.no_image_index || meta.no_image_index,
+        ..meta.robots.clone()
+    };
+
+    let mut directives = vec![robots.to_content_string()];
+
+    if !robots.no_snippet {
+        directives.push(format!("max-snippet:{}", meta.max_snippet.unwrap_or(-1)));
+    }
+
+    let image_preview = meta.max_image_preview.unwrap_or(ImagePreviewSize::Large);
+    directives.push(format!("max-image-preview:{}", image_preview.as_str()));
+
+    directives.push(format!("max-video-preview:{}", meta.max_video_preview.unwrap_or(-1)));
+
+    directives.join(", ")
+}
+
 impl SeoMeta {
     pub fn new(content_id: Uuid, content_type: ContentType) -> Self {
         let now = Utc::now();
@@ -175,11 +392,30 @@ impl SeoMeta {
             max_snippet: None,
             max_image_preview: None,
             max_video_preview: None,
+            is_cornerstone: false,
+            hreflang_alternates: vec![],
             created_at: now,
             updated_at: now,
         }
     }
 
+    /// Read a robots meta `content` attribute into the robots directives and
+    /// snippet/preview limits
+    pub fn apply_robots_content(&mut self, content: &str) -> Result<(), MetaParseError> {
+        let (robots, limits) = parse_robots_content(content)?;
+
+        self.no_archive = robots.no_archive;
+        self.no_snippet = robots.no_snippet;
+        self.no_image_index = robots.no_image_index;
+        self.robots = robots;
+        self.max_snippet = limits.max_snippet;
+        self.max_image_preview = limits.max_image_preview;
+        self.max_video_preview = limits.max_video_preview;
+        self.updated_at = Utc::now();
+
+        Ok(())
+    }
+
     /// Generate the final title based on template
     pub fn get_title(&self, post_title: &str, site_name: &str, separator: &str) -> String {
         if self.use_custom_title && self.title.is_some() {
@@ -196,6 +432,45 @@ impl SeoMeta {
             .replace(" - ", separator)
     }
 
+    /// Add a keyword unless it is already present (case-insensitive)
+    pub fn add_keyword(&mut self, keyword: &str) {
+        let keyword = keyword.trim();
+        if keyword.is_empty() {
+            return;
+        }
+
+        let lower = keyword.to_lowercase();
+        if !self.keywords.iter().any(|k| k.trim().to_lowercase() == lower) {
+            self.keywords.push(keyword.to_string());
+        }
+    }
+
+    /// Generate the keywords meta tag
+    ///
+    /// Search engines give this tag little to no weight, but some internal
+    /// site search systems still read it.
+    pub fn keywords_html(&self, max_keywords: usize) -> Option<String> {
+        let focus = self.focus_keyword.as_deref().map(str::trim);
+        let mut seen = std::collections::HashSet::new();
+
+        let keywords: Vec<&str> = self.keywords.iter()
+            .map(|k| k.trim())
+            .filter(|k| !k.is_empty())
+            .filter(|k| Some(*k) != focus)
+            .filter(|k| seen.insert(k.to_lowercase()))
+            .take(max_keywords)
+            .collect();
+
+        if keywords.is_empty() {
+            return None;
+        }
+
+        Some(format!(
+            "<meta name=\"keywords\" content=\"{}\">\n",
+            html_escape(&keywords.join(", "))
+        ))
+    }
+
     /// Generate meta tags HTML
     pub fn to_html(&self, post_title: &str, site_name: &str, separator: &str) -> String {
         let mut html = String::new();
@@ -213,17 +488,14 @@ impl SeoMeta {
         }
 
         // Keywords
-        if !self.keywords.is_empty() {
-            html.push_str(&format!(
-                "<meta name=\"keywords\" content=\"{}\">\n",
-                html_escape(&self.keywords.join(", "))
-            ));
+        if let Some(keywords) = self.keywords_html(10) {
+            html.push_str(&keywords);
         }
 
         // Robots
         html.push_str(&format!(
             "<meta name=\"robots\" content=\"{}\">\n",
-            self.robots.to_content_string()
+            to_full_content_string(self)
         ));
 
         // Canonical
@@ -239,7 +511,7 @@ impl SeoMeta {
 }
 
 /// Simple HTML escape
-fn html_escape(s: &str) -> String {
+pub(crate) fn html_escape(s: &str) -> String {
     s.replace('&', "&amp;")
         .replace('<', "&lt;")
         .replace('>', "&gt;")
@@ -290,3 +562,30 @@ impl Default for HomepageMeta {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_robots_meta_content() {
+        let robots = MetaRobots::from_html_meta_content("  NoIndex ,nofollow,  noarchive ").unwrap();
+        assert!(!robots.index && !robots.follow && robots.no_archive);
+
+        let mut meta = SeoMeta::new(Uuid::nil(), ContentType::Post);
+        meta.apply_robots_content("index, follow, max-snippet:-1, MAX-IMAGE-PREVIEW:large, max-video-preview:0").unwrap();
+        assert!(meta.robots.index);
+        assert_eq!(meta.max_snippet, Some(-1));
+        assert_eq!(meta.max_image_preview, Some(ImagePreviewSize::Large));
+        assert_eq!(meta.max_video_preview, Some(0));
+
+        assert_eq!(
+            MetaRobots::from_html_meta_content("noindex, nofolow").unwrap_err(),
+            MetaParseError::UnknownDirective("nofolow".to_string())
+        );
+        assert!(matches!(
+            MetaRobots::from_html_meta_content("max-image-preview:huge"),
+            Err(MetaParseError::InvalidValue { .. })
+        ));
+    }
+}
@@ -6,6 +6,9 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+/// Hops a redirect chain may have before it is reported
+pub const DEFAULT_MAX_REDIRECT_CHAIN: usize = 5;
+
 /// URL redirect rule
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Redirect {
@@ -17,10 +20,14 @@ pub struct Redirect {
     pub is_regex: bool,
     pub is_active: bool,
     pub hit_count: i64,
-    pub last_accessed: Option<DateTime<Utc>>,
+    #[serde(alias = "last_accessed")]
+    pub last_hit: Option<DateTime<Utc>>,
     pub notes: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// User who created the rule, if known
+    #[serde(default)]
+    pub created_by: Option<String>,
 }
 
 /// Redirect type (HTTP status code)
@@ -52,6 +59,19 @@ impl RedirectType {
         }
     }
 
+    /// Redirect type for an HTTP status code, if one exists
+    pub fn from_status_code(code: u16) -> Option<Self> {
+        match code {
+            301 => Some(Self::Permanent),
+            302 => Some(Self::Temporary),
+            307 => Some(Self::TemporaryPreserve),
+            308 => Some(Self::PermanentPreserve),
+            410 => Some(Self::Gone),
+            451 => Some(Self::LegalRestriction),
+            _ => None,
+        }
+    }
+
     pub fn description(&self) -> &'static str {
         match self {
             Self::Permanent => "301 Moved Permanently",
@@ -62,6 +82,11 @@ impl RedirectType {
             Self::LegalRestriction => "451 Unavailable for Legal Reasons",
         }
     }
+
+    /// Whether the response carries a `Location` header
+    pub fn is_redirect(&self) -> bool {
+        !matches!(self, Self::Gone | Self::LegalRestriction)
+    }
 }
 
 /// Match type for source URL
@@ -90,10 +115,11 @@ impl Redirect {
             is_regex: false,
             is_active: true,
             hit_count: 0,
-            last_accessed: None,
+            last_hit: None,
             notes: None,
             created_at: now,
             updated_at: now,
+            created_by: None,
         }
     }
 
@@ -117,21 +143,78 @@ impl Redirect {
         }
     }
 
-    /// Get the target URL, applying regex replacements if needed
+    /// Get the target URL, expanding `$N` capture group references for regex rules
+    ///
+    /// Captures are copied as-is, so percent-encoded input is not encoded again.
+    /// Falls back to the literal target when the pattern does not match.
     pub fn get_target(&self, url: &str) -> String {
-        if self.is_regex && self.match_type == MatchType::Regex {
-            if let Ok(re) = regex::Regex::new(&self.source_url) {
-                return re.replace(url, &self.target_url).to_string();
-            }
+        if self.match_type != MatchType::Regex {
+            return self.target_url.clone();
+        }
+
+        match regex::Regex::new(&self.source_url) {
+            Ok(re) => self.get_target_with(&re, url),
+            Err(_) => self.target_url.clone(),
+        }
+    }
+
+    /// Like `get_target`, with the source pattern already compiled
+    pub fn get_target_with(&self, re: &regex::Regex, url: &str) -> String {
+        if self.match_type != MatchType::Regex {
+            return self.target_url.clone();
         }
-        self.target_url.clone()
+
+        let Some(captures) = re.captures(url) else {
+            return self.target_url.clone();
+        };
+
+        let mut target = String::new();
+        captures.expand(&brace_group_references(&self.target_url), &mut target);
+        target
     }
 
     /// Increment hit counter
     pub fn record_hit(&mut self) {
         self.hit_count += 1;
-        self.last_accessed = Some(Utc::now());
+        self.last_hit = Some(Utc::now());
+    }
+
+    /// Whether this rule answers with 410 Gone instead of redirecting
+    pub fn is_gone(&self) -> bool {
+        self.redirect_type == RedirectType::Gone
+    }
+}
+
+/// Rewrite `$N` as `${N}` so a reference followed by text, like `$1.html`,
+/// is not read as a named group
+fn brace_group_references(template: &str) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '$' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.peek() {
+            Some('$') => {
+                chars.next();
+                result.push_str("$$");
+            }
+            Some(d) if d.is_ascii_digit() => {
+                let mut group = String::new();
+                while let Some(d) = chars.peek().filter(|d| d.is_ascii_digit()) {
+                    group.push(*d);
+                    chars.next();
+                }
+                result.push_str(&format!("${{{}}}", group));
+            }
+            _ => result.push('$'),
+        }
     }
+
+    result
 }
 
 /// 404 error log entry
@@ -245,8 +328,13 @@ pub struct RedirectSettings {
     pub pass_query_string: bool,
     pub monitor_changes: bool,
     pub case_insensitive: bool,
+    /// Hops after which a redirect chain is reported
+    #[serde(default = "default_max_redirect_chain")]
+    pub max_redirect_chain: usize,
 }
 
+fn default_max_redirect_chain() -> usize { DEFAULT_MAX_REDIRECT_CHAIN }
+
 impl Default for RedirectSettings {
     fn default() -> Self {
         Self {
@@ -259,6 +347,64 @@ impl Default for RedirectSettings {
             pass_query_string: true,
             monitor_changes: true,
             case_insensitive: true,
+            max_redirect_chain: DEFAULT_MAX_REDIRECT_CHAIN,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn regex_redirect(source: &str, target: &str) -> Redirect {
+        let mut redirect = Redirect::new(source.to_string(), target.to_string(), RedirectType::Permanent);
+        redirect.match_type = MatchType::Regex;
+        redirect
+    }
+
+    #[test]
+    fn test_get_target_simple_capture() {
+        let redirect = regex_redirect(r"^/old/(.+)$", "/new/$1.html");
+        assert_eq!(redirect.get_target("/old/page"), "/new/page.html");
+    }
+
+    #[test]
+    fn test_get_target_nested_groups() {
+        let redirect = regex_redirect(r"^/blog/((\d{4})/(\d{2}))/(.+)$", "/archive/$2/$3/$4?period=$1");
+        assert_eq!(
+            redirect.get_target("/blog/2024/05/hello-world"),
+            "/archive/2024/05/hello-world?period=2024/05"
+        );
+    }
+
+    #[test]
+    fn test_get_target_no_match_falls_back_to_target() {
+        let redirect = regex_redirect(r"^/old/(.+)$", "/new/$1");
+        assert_eq!(redirect.get_target("/other/page"), "/new/$1");
+
+        let exact = Redirect::new("/a".to_string(), "/b".to_string(), RedirectType::Permanent);
+        assert_eq!(exact.get_target("/a"), "/b");
+    }
+
+    #[test]
+    fn test_get_target_keeps_encoded_capture() {
+        let redirect = regex_redirect(r"^/search/(.+)$", "/find?q=$1");
+        assert_eq!(redirect.get_target("/search/rust%20seo"), "/find?q=rust%20seo");
+    }
+
+    #[test]
+    fn test_settings_without_max_redirect_chain_deserialize() {
+        let settings: RedirectSettings = serde_json::from_str(r#"{
+            "enabled": true,
+            "log_404s": true,
+            "auto_redirect_404_to_homepage": false,
+            "redirect_attachment_pages": true,
+            "redirect_category_base": false,
+            "redirect_tag_base": false,
+            "pass_query_string": true,
+            "monitor_changes": true,
+            "case_insensitive": true
+        }"#).unwrap();
+        assert_eq!(settings.max_redirect_chain, DEFAULT_MAX_REDIRECT_CHAIN);
+    }
+}
@@ -13,6 +13,70 @@ pub struct RobotsTxt {
     pub custom_content: Option<String>,
 }
 
+/// Differences between two robots.txt files
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RobotsDiff {
+    /// User agents only present locally
+    pub only_in_local: Vec<String>,
+    /// User agents only present remotely
+    pub only_in_remote: Vec<String>,
+    pub changed: Vec<RobotsRuleDiff>,
+    pub sitemaps_only_in_local: Vec<String>,
+    pub sitemaps_only_in_remote: Vec<String>,
+}
+
+impl RobotsDiff {
+    pub fn is_identical(&self) -> bool {
+        self.only_in_local.is_empty()
+            && self.only_in_remote.is_empty()
+            && self.changed.is_empty()
+            && self.sitemaps_only_in_local.is_empty()
+            && self.sitemaps_only_in_remote.is_empty()
+    }
+}
+
+/// Differences between the generated robots.txt and the live file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RobotsAuditReport {
+    pub in_sync: bool,
+    /// Generated rules the live file lacks
+    pub missing_rules: Vec<String>,
+    /// Live rules the settings would not generate
+    pub extra_rules: Vec<String>,
+    pub missing_sitemaps: Vec<String>,
+    pub extra_sitemaps: Vec<String>,
+}
+
+/// Rule differences for a user agent present in both files
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RobotsRuleDiff {
+    pub user_agent: String,
+    pub allow_only_in_local: Vec<String>,
+    pub allow_only_in_remote: Vec<String>,
+    pub disallow_only_in_local: Vec<String>,
+    pub disallow_only_in_remote: Vec<String>,
+}
+
+impl RobotsRuleDiff {
+    pub fn is_empty(&self) -> bool {
+        self.allow_only_in_local.is_empty()
+            && self.allow_only_in_remote.is_empty()
+            && self.disallow_only_in_local.is_empty()
+            && self.disallow_only_in_remote.is_empty()
+    }
+}
+
+/// Error fetching a remote robots.txt
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum RobotsError {
+    #[error("robots.txt not found")]
+    NotFound,
+    #[error("access to robots.txt is forbidden")]
+    Forbidden,
+    #[error("network error: {0}")]
+    NetworkError(String),
+}
+
 /// Robot rule for a specific user agent
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RobotsRule {
@@ -112,9 +176,13 @@ impl RobotsTxt {
     }
 
     /// Parse robots.txt content
+    ///
+    /// Consecutive `User-agent` lines share the rules that follow them, so each
+    /// agent in a group gets its own copy of those rules.
     pub fn parse(content: &str) -> Self {
         let mut robots = Self::new();
-        let mut current_rule: Option<RobotsRule> = None;
+        let mut current_group: Vec<RobotsRule> = Vec::new();
+        let mut in_agent_lines = false;
 
         for line in content.lines() {
             let line = line.trim();
@@ -129,13 +197,18 @@ impl RobotsTxt {
                 let directive = directive.trim().to_lowercase();
                 let value = value.trim().to_string();
 
+                if directive != "user-agent" {
+                    in_agent_lines = false;
+                }
+
                 match directive.as_str() {
                     "user-agent" => {
-                        // Save previous rule if exists
-                        if let Some(rule) = current_rule.take() {
-                            robots.rules.push(rule);
+                        // A User-agent line after rules starts a new group
+                        if !in_agent_lines {
+                            robots.rules.append(&mut current_group);
                         }
-                        current_rule = Some(RobotsRule {
+                        in_agent_lines = true;
+                        current_group.push(RobotsRule {
                             user_agent: value,
                             allow: vec![],
                             disallow: vec![],
@@ -143,22 +216,23 @@ impl RobotsTxt {
                         });
                     }
                     "allow" => {
-                        if let Some(ref mut rule) = current_rule {
-                            rule.allow.push(value);
+                        for rule in &mut current_group {
+                            rule.allow.push(value.clone());
                         }
                     }
                     "disallow" => {
-                        if let Some(ref mut rule) = current_rule {
-                            rule.disallow.push(value);
+                        for rule in &mut current_group {
+                            rule.disallow.push(value.clone());
                         }
                     }
                     "crawl-delay" => {
                         if let Ok(delay) = value.parse() {
-                            if let Some(ref mut rule) = current_rule {
-                                rule.crawl_delay = Some(delay);
-                            } else {
+                            if current_group.is_empty() {
                                 robots.crawl_delay = Some(delay);
                             }
+                            for rule in &mut current_group {
+                                rule.crawl_delay = Some(delay);
+                            }
                         }
                     }
                     "sitemap" => {
@@ -169,13 +243,133 @@ impl RobotsTxt {
             }
         }
 
-        // Save last rule
-        if let Some(rule) = current_rule {
-            robots.rules.push(rule);
-        }
+        // Save last group
+        robots.rules.append(&mut current_group);
 
         robots
     }
+
+    /// Fetch and parse a remote robots.txt
+    #[cfg(feature = "fetch-robots")]
+    pub async fn from_url(url: &str) -> Result<RobotsTxt, RobotsError> {
+        Self::fetch_content(url).await.map(|content| Self::parse(&content))
+    }
+
+    /// Fetch the raw content of a remote robots.txt
+    #[cfg(feature = "fetch-robots")]
+    pub async fn fetch_content(url: &str) -> Result<String, RobotsError> {
+        let client = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::limited(2))
+            .timeout(std::time::Duration::from_secs(10))
+            .build()
+            .map_err(|e| RobotsError::NetworkError(e.to_string()))?;
+
+        let response = client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| RobotsError::NetworkError(e.to_string()))?;
+
+        match response.status().as_u16() {
+            404 => return Err(RobotsError::NotFound),
+            401 | 403 => return Err(RobotsError::Forbidden),
+            status if !(200..300).contains(&status) => {
+                return Err(RobotsError::NetworkError(format!("Unexpected status {}", status)));
+            }
+            _ => {}
+        }
+
+        response
+            .text()
+            .await
+            .map_err(|e| RobotsError::NetworkError(e.to_string()))
+    }
+
+    /// Compare rule sets of a local and a remote robots.txt
+    pub fn diff(local: &RobotsTxt, remote: &RobotsTxt) -> RobotsDiff {
+        let mut diff = RobotsDiff::default();
+
+        for rule in &local.rules {
+            match remote.find_rule(&rule.user_agent) {
+                None => diff.only_in_local.push(rule.user_agent.clone()),
+                Some(remote_rule) => {
+                    let rule_diff = RobotsRuleDiff {
+                        user_agent: rule.user_agent.clone(),
+                        allow_only_in_local: missing_from(&rule.allow, &remote_rule.allow),
+                        allow_only_in_remote: missing_from(&remote_rule.allow, &rule.allow),
+                        disallow_only_in_local: missing_from(&rule.disallow, &remote_rule.disallow),
+                        disallow_only_in_remote: missing_from(&remote_rule.disallow, &rule.disallow),
+                    };
+                    if !rule_diff.is_empty() {
+                        diff.changed.push(rule_diff);
+                    }
+                }
+            }
+        }
+
+        for rule in &remote.rules {
+            if local.find_rule(&rule.user_agent).is_none() {
+                diff.only_in_remote.push(rule.user_agent.clone());
+            }
+        }
+
+        diff.sitemaps_only_in_local = missing_from(&local.sitemaps, &remote.sitemaps);
+        diff.sitemaps_only_in_remote = missing_from(&remote.sitemaps, &local.sitemaps);
+
+        diff
+    }
+
+    /// Combine with another robots.txt
+    ///
+    /// Rules for the same user agent are unioned, new user agents appended and
+    /// sitemaps deduplicated. Conflicting crawl delays resolve to the smaller one.
+    pub fn merge(&self, other: &RobotsTxt) -> RobotsTxt {
+        let mut merged = self.clone();
+
+        for rule in &other.rules {
+            match merged.rules.iter_mut().find(|r| r.user_agent.eq_ignore_ascii_case(&rule.user_agent)) {
+                Some(existing) => {
+                    union_into(&mut existing.allow, &rule.allow);
+                    union_into(&mut existing.disallow, &rule.disallow);
+                    existing.crawl_delay = min_delay(existing.crawl_delay, rule.crawl_delay);
+                }
+                None => merged.rules.push(rule.clone()),
+            }
+        }
+
+        union_into(&mut merged.sitemaps, &other.sitemaps);
+        merged.crawl_delay = min_delay(merged.crawl_delay, other.crawl_delay);
+
+        merged.custom_content = match (&merged.custom_content, &other.custom_content) {
+            (Some(base), Some(extra)) if base != extra => Some(format!("{}\n{}", base, extra)),
+            (None, extra) => extra.clone(),
+            (base, _) => base.clone(),
+        };
+
+        merged
+    }
+
+    /// Find the rule group for a user agent (case-insensitive)
+    fn find_rule(&self, user_agent: &str) -> Option<&RobotsRule> {
+        self.rules.iter().find(|r| r.user_agent.eq_ignore_ascii_case(user_agent))
+    }
+}
+
+/// Append the values of `extra` that `list` lacks
+fn union_into(list: &mut Vec<String>, extra: &[String]) {
+    for value in extra {
+        if !list.contains(value) {
+            list.push(value.clone());
+        }
+    }
+}
+
+/// The more permissive of two crawl delays
+fn min_delay(a: Option<u32>, b: Option<u32>) -> Option<u32> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    }
 }
 
 impl Default for RobotsTxt {
@@ -331,3 +525,8 @@ pub fn ai_crawlers() -> Vec<&'static str> {
         "FacebookBot",
     ]
 }
+
+/// Entries of `a` that are not in `b`
+fn missing_from(a: &[String], b: &[String]) -> Vec<String> {
+    a.iter().filter(|x| !b.contains(x)).cloned().collect()
+}
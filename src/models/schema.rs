@@ -80,6 +80,99 @@ impl SchemaMarkup {
             json_str
         )
     }
+
+    /// Wrap content in Microdata `itemscope`/`itemprop` markup
+    pub fn to_microdata_html(&self, content_html: &str) -> String {
+        microdata_html(&self.data, self.schema_type.as_str(), content_html)
+    }
+}
+
+/// How structured data is written into the page
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SchemaOutputFormat {
+    #[default]
+    JsonLd,
+    Microdata,
+}
+
+/// Microdata block for a JSON-LD object, wrapping `content_html`
+///
+/// Article headlines are attached to the first `<h1>`/`<h2>` in the content;
+/// all other properties become `<meta>`/`<link>` tags.
+pub fn microdata_html(data: &Value, default_type: &str, content_html: &str) -> String {
+    let schema_type = data.get("@type").and_then(Value::as_str).unwrap_or(default_type);
+    let mut content = content_html.to_string();
+    let mut skip = vec![];
+
+    if matches!(schema_type, "Article" | "NewsArticle" | "BlogPosting") {
+        if let Some(m) = regex::Regex::new(r"(?i)<h[12]\b").ok().and_then(|re| re.find(content_html)) {
+            content.insert_str(m.end(), " itemprop=\"headline\"");
+            skip.push("headline");
+        }
+    }
+
+    let mut html = format!("<div itemscope itemtype=\"https://schema.org/{}\">\n", html_escape(schema_type));
+    html.push_str(&microdata_properties(data, &skip));
+    if !content.is_empty() {
+        html.push_str(&content);
+        html.push('\n');
+    }
+    html.push_str("</div>\n");
+    html
+}
+
+/// `<meta>`, `<link>` and nested `itemscope` elements for an object's properties
+fn microdata_properties(data: &Value, skip: &[&str]) -> String {
+    const URL_PROPERTIES: &[&str] = &["url", "image", "logo", "sameAs", "availability", "contentUrl", "thumbnailUrl"];
+
+    let Some(object) = data.as_object() else {
+        return String::new();
+    };
+
+    let mut html = String::new();
+    for (key, value) in object {
+        if key.starts_with('@') || skip.contains(&key.as_str()) {
+            continue;
+        }
+
+        let values = match value {
+            Value::Array(items) => items.iter().collect(),
+            value => vec![value],
+        };
+        for value in values {
+            match value {
+                Value::Object(_) => {
+                    let nested_type = value.get("@type").and_then(Value::as_str).unwrap_or("Thing");
+                    html.push_str(&format!(
+                        "<div itemprop=\"{}\" itemscope itemtype=\"https://schema.org/{}\">\n{}</div>\n",
+                        html_escape(key),
+                        html_escape(nested_type),
+                        microdata_properties(value, &[])
+                    ));
+                }
+                Value::String(s) if URL_PROPERTIES.contains(&key.as_str()) => {
+                    html.push_str(&format!("<link itemprop=\"{}\" href=\"{}\">\n", html_escape(key), html_escape(s)));
+                }
+                Value::String(s) => {
+                    html.push_str(&format!("<meta itemprop=\"{}\" content=\"{}\">\n", html_escape(key), html_escape(s)));
+                }
+                Value::Number(_) | Value::Bool(_) => {
+                    html.push_str(&format!("<meta itemprop=\"{}\" content=\"{}\">\n", html_escape(key), value));
+                }
+                _ => {}
+            }
+        }
+    }
+    html
+}
+
+fn html_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
 }
 
 /// Website schema builder
@@ -404,6 +497,22 @@ pub struct AggregateRating {
 }
 
 impl AggregateRating {
+    /// Average the individual review ratings on a 1-5 scale
+    pub fn from_reviews(reviews: &[ReviewSchema]) -> Option<Self> {
+        if reviews.is_empty() {
+            return None;
+        }
+
+        let total: f32 = reviews.iter().map(|r| r.rating).sum();
+        let average = total / reviews.len() as f32;
+        Some(Self {
+            rating_value: (average * 10.0).round() / 10.0,
+            review_count: reviews.len() as i32,
+            best_rating: 5.0,
+            worst_rating: 1.0,
+        })
+    }
+
     pub fn to_json(&self) -> Value {
         json!({
             "@type": "AggregateRating",
@@ -423,6 +532,24 @@ pub struct ReviewSchema {
     pub date_published: DateTime<Utc>,
 }
 
+impl ReviewSchema {
+    pub fn to_json(&self) -> Value {
+        json!({
+            "@type": "Review",
+            "author": {
+                "@type": "Person",
+                "name": self.author
+            },
+            "reviewRating": {
+                "@type": "Rating",
+                "ratingValue": self.rating
+            },
+            "reviewBody": self.review_body,
+            "datePublished": self.date_published.to_rfc3339()
+        })
+    }
+}
+
 impl ProductSchema {
     pub fn to_json_ld(&self) -> Value {
         let mut schema = json!({
@@ -455,10 +582,18 @@ impl ProductSchema {
             });
         }
 
-        if let Some(rating) = &self.rating {
+        // Fall back to an aggregate of the individual reviews
+        let rating = self.rating.clone().or_else(|| AggregateRating::from_reviews(&self.reviews));
+        if let Some(rating) = rating {
             schema["aggregateRating"] = rating.to_json();
         }
 
+        if !self.reviews.is_empty() {
+            schema["review"] = json!(self.reviews.iter()
+                .map(ReviewSchema::to_json)
+                .collect::<Vec<_>>());
+        }
+
         schema
     }
 }
@@ -588,3 +723,650 @@ impl LocalBusinessSchema {
         schema
     }
 }
+
+/// Job posting schema builder
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobPostingSchema {
+    pub title: String,
+    pub description: String,
+    pub date_posted: DateTime<Utc>,
+    pub valid_through: Option<DateTime<Utc>>,
+    /// FULL_TIME, PART_TIME, CONTRACTOR, TEMPORARY, INTERN, ...
+    pub employment_type: Vec<String>,
+    pub hiring_organization: OrganizationSchema,
+    pub job_location: Option<AddressSchema>,
+    pub remote_work: JobRemoteWork,
+    pub base_salary: Option<SalarySchema>,
+}
+
+/// Remote work details for a job posting
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobRemoteWork {
+    pub is_remote: bool,
+    /// Value for `jobLocationType`, usually TELECOMMUTE
+    pub work_location_type: String,
+}
+
+impl Default for JobRemoteWork {
+    fn default() -> Self {
+        Self {
+            is_remote: false,
+            work_location_type: "TELECOMMUTE".to_string(),
+        }
+    }
+}
+
+/// Salary for a job posting
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SalarySchema {
+    pub value: f32,
+    pub currency: String,
+    /// HOUR, DAY, WEEK, MONTH or YEAR
+    pub unit_text: String,
+}
+
+impl JobPostingSchema {
+    pub fn to_json_ld(&self) -> Value {
+        let mut hiring_organization = json!({
+            "@type": "Organization",
+            "name": self.hiring_organization.name,
+            "sameAs": self.hiring_organization.url
+        });
+
+        if let Some(logo) = &self.hiring_organization.logo {
+            hiring_organization["logo"] = json!(logo);
+        }
+
+        let mut schema = json!({
+            "@context": "https://schema.org",
+            "@type": "JobPosting",
+            "title": self.title,
+            "description": self.description,
+            "datePosted": self.date_posted.to_rfc3339(),
+            "hiringOrganization": hiring_organization
+        });
+
+        if let Some(valid_through) = &self.valid_through {
+            schema["validThrough"] = json!(valid_through.to_rfc3339());
+        }
+
+        if !self.employment_type.is_empty() {
+            schema["employmentType"] = json!(self.employment_type);
+        }
+
+        if let Some(location) = &self.job_location {
+            schema["jobLocation"] = json!({
+                "@type": "Place",
+                "address": location.to_json()
+            });
+        }
+
+        if self.remote_work.is_remote {
+            schema["jobLocationType"] = json!(self.remote_work.work_location_type);
+        }
+
+        if let Some(salary) = &self.base_salary {
+            schema["baseSalary"] = json!({
+                "@type": "MonetaryAmount",
+                "currency": salary.currency,
+                "value": {
+                    "@type": "QuantitativeValue",
+                    "value": salary.value,
+                    "unitText": salary.unit_text
+                }
+            });
+        }
+
+        schema
+    }
+}
+
+/// Course schema builder
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CourseSchema {
+    pub name: String,
+    pub description: String,
+    pub url: String,
+    pub provider: OrganizationSchema,
+    pub instructor: Option<PersonSchema>,
+    pub course_code: Option<String>,
+    pub course_prerequisites: Vec<String>,
+    pub educational_level: Option<String>,
+    pub offers: Option<CourseOffer>,
+    /// ISO 8601 duration of the course workload, e.g. `PT10H`
+    pub duration: Option<String>,
+}
+
+/// Pricing for a course
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CourseOffer {
+    pub price: String,
+    pub currency: String,
+    pub availability: String,
+    /// Free, Paid, Subscription or Partially Free
+    pub category: String,
+}
+
+impl CourseSchema {
+    pub fn to_json_ld(&self) -> Value {
+        let mut schema = json!({
+            "@context": "https://schema.org",
+            "@type": "Course",
+            "name": self.name,
+            "description": self.description,
+            "url": self.url,
+            "provider": {
+                "@type": "Organization",
+                "name": self.provider.name,
+                "sameAs": self.provider.url
+            }
+        });
+
+        if let Some(instructor) = &self.instructor {
+            schema["instructor"] = instructor.to_json();
+        }
+
+        if let Some(code) = &self.course_code {
+            schema["courseCode"] = json!(code);
+        }
+
+        if !self.course_prerequisites.is_empty() {
+            schema["coursePrerequisites"] = json!(self.course_prerequisites);
+        }
+
+        if let Some(level) = &self.educational_level {
+            schema["educationalLevel"] = json!(level);
+        }
+
+        if let Some(offer) = &self.offers {
+            schema["offers"] = json!({
+                "@type": "Offer",
+                "category": offer.category,
+                "price": offer.price,
+                "priceCurrency": offer.currency,
+                "availability": offer.availability
+            });
+        }
+
+        if let Some(duration) = &self.duration {
+            let mut instance = json!({
+                "@type": "CourseInstance",
+                "courseWorkload": duration
+            });
+            if let Some(instructor) = &self.instructor {
+                instance["instructor"] = instructor.to_json();
+            }
+            schema["hasCourseInstance"] = instance;
+        }
+
+        schema
+    }
+}
+
+/// HowTo schema builder
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HowToSchema {
+    pub name: String,
+    pub description: Option<String>,
+    /// ISO 8601 duration, e.g. `PT30M`
+    pub total_time: Option<String>,
+    pub estimated_cost: Option<String>,
+    pub image: Vec<String>,
+    pub steps: Vec<HowToStep>,
+}
+
+/// A single step of a HowTo
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HowToStep {
+    pub name: String,
+    pub text: String,
+    pub image: Option<String>,
+    pub url: Option<String>,
+}
+
+impl HowToSchema {
+    pub fn to_json_ld(&self) -> Value {
+        let mut schema = json!({
+            "@context": "https://schema.org",
+            "@type": "HowTo",
+            "name": self.name,
+            "step": self.steps.iter()
+                .enumerate()
+                .map(|(i, step)| step.to_json(i + 1))
+                .collect::<Vec<_>>()
+        });
+
+        if let Some(description) = &self.description {
+            schema["description"] = json!(description);
+        }
+
+        if let Some(total_time) = &self.total_time {
+            schema["totalTime"] = json!(total_time);
+        }
+
+        if let Some(cost) = &self.estimated_cost {
+            schema["estimatedCost"] = json!(cost);
+        }
+
+        if !self.image.is_empty() {
+            schema["image"] = json!(self.image);
+        }
+
+        schema
+    }
+}
+
+impl HowToStep {
+    pub fn to_json(&self, position: usize) -> Value {
+        let mut step = json!({
+            "@type": "HowToStep",
+            "position": position,
+            "name": self.name,
+            "text": self.text
+        });
+
+        if let Some(image) = &self.image {
+            step["image"] = json!(image);
+        }
+
+        if let Some(url) = &self.url {
+            step["url"] = json!(url);
+        }
+
+        step
+    }
+}
+
+/// Recipe schema builder
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecipeSchema {
+    pub name: String,
+    pub description: String,
+    pub image: Vec<String>,
+    pub author: PersonSchema,
+    pub date_published: DateTime<Utc>,
+    /// ISO 8601 durations, e.g. `PT15M`
+    pub prep_time: Option<String>,
+    pub cook_time: Option<String>,
+    pub total_time: Option<String>,
+    pub keywords: Vec<String>,
+    pub recipe_yield: Option<String>,
+    pub recipe_category: Option<String>,
+    pub recipe_cuisine: Option<String>,
+    pub nutrition: Option<NutritionInfo>,
+    pub recipe_ingredients: Vec<String>,
+    pub recipe_instructions: Vec<String>,
+}
+
+/// Nutrition facts per serving, as display text such as `240 calories` or `12 g`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NutritionInfo {
+    pub calories: Option<String>,
+    pub carbohydrates: Option<String>,
+    pub fat: Option<String>,
+    pub protein: Option<String>,
+}
+
+impl RecipeSchema {
+    pub fn to_json_ld(&self) -> Value {
+        let mut schema = json!({
+            "@context": "https://schema.org",
+            "@type": "Recipe",
+            "name": self.name,
+            "description": self.description,
+            "image": self.image,
+            "author": self.author.to_json(),
+            "datePublished": self.date_published.to_rfc3339(),
+            "recipeIngredient": self.recipe_ingredients,
+            "recipeInstructions": self.recipe_instructions.iter()
+                .map(|text| json!({
+                    "@type": "HowToStep",
+                    "text": text
+                }))
+                .collect::<Vec<_>>()
+        });
+
+        for (key, value) in [
+            ("prepTime", &self.prep_time),
+            ("cookTime", &self.cook_time),
+            ("totalTime", &self.total_time),
+            ("recipeYield", &self.recipe_yield),
+            ("recipeCategory", &self.recipe_category),
+            ("recipeCuisine", &self.recipe_cuisine),
+        ] {
+            if let Some(value) = value {
+                schema[key] = json!(value);
+            }
+        }
+
+        if !self.keywords.is_empty() {
+            schema["keywords"] = json!(self.keywords.join(", "));
+        }
+
+        if let Some(nutrition) = &self.nutrition {
+            schema["nutrition"] = nutrition.to_json();
+        }
+
+        schema
+    }
+}
+
+impl NutritionInfo {
+    pub fn to_json(&self) -> Value {
+        let mut nutrition = json!({
+            "@type": "NutritionInformation"
+        });
+
+        for (key, value) in [
+            ("calories", &self.calories),
+            ("carbohydrateContent", &self.carbohydrates),
+            ("fatContent", &self.fat),
+            ("proteinContent", &self.protein),
+        ] {
+            if let Some(value) = value {
+                nutrition[key] = json!(value);
+            }
+        }
+
+        nutrition
+    }
+}
+
+/// Event schema builder
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventSchema {
+    pub name: String,
+    pub start_date: DateTime<Utc>,
+    pub end_date: Option<DateTime<Utc>>,
+    pub location: EventLocation,
+    pub description: Option<String>,
+    pub image: Vec<String>,
+    pub organizer: Option<OrganizationSchema>,
+    pub performer: Vec<PersonSchema>,
+    pub offers: Vec<EventOffer>,
+    pub status: EventStatus,
+}
+
+/// Where an event takes place
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EventLocation {
+    Place { name: String, address: AddressSchema },
+    Virtual { url: String },
+}
+
+impl EventLocation {
+    pub fn to_json(&self) -> Value {
+        match self {
+            Self::Place { name, address } => json!({
+                "@type": "Place",
+                "name": name,
+                "address": address.to_json()
+            }),
+            Self::Virtual { url } => json!({
+                "@type": "VirtualLocation",
+                "url": url
+            }),
+        }
+    }
+
+    /// schema.org `eventAttendanceMode` for this location
+    pub fn attendance_mode_url(&self) -> &'static str {
+        match self {
+            Self::Place { .. } => "https://schema.org/OfflineEventAttendanceMode",
+            Self::Virtual { .. } => "https://schema.org/OnlineEventAttendanceMode",
+        }
+    }
+}
+
+/// Ticket offer for an event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventOffer {
+    pub name: Option<String>,
+    pub price: String,
+    pub currency: String,
+    pub availability: ProductAvailability,
+    pub url: Option<String>,
+    pub valid_from: Option<DateTime<Utc>>,
+}
+
+impl EventOffer {
+    pub fn to_json(&self) -> Value {
+        let mut offer = json!({
+            "@type": "Offer",
+            "price": self.price,
+            "priceCurrency": self.currency,
+            "availability": self.availability.schema_url()
+        });
+
+        if let Some(name) = &self.name {
+            offer["name"] = json!(name);
+        }
+
+        if let Some(url) = &self.url {
+            offer["url"] = json!(url);
+        }
+
+        if let Some(valid_from) = &self.valid_from {
+            offer["validFrom"] = json!(valid_from.to_rfc3339());
+        }
+
+        offer
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub enum EventStatus {
+    #[default]
+    Scheduled,
+    Cancelled,
+    Postponed,
+    Rescheduled,
+    MovedOnline,
+}
+
+impl EventStatus {
+    pub fn schema_url(&self) -> &'static str {
+        match self {
+            Self::Scheduled => "https://schema.org/EventScheduled",
+            Self::Cancelled => "https://schema.org/EventCancelled",
+            Self::Postponed => "https://schema.org/EventPostponed",
+            Self::Rescheduled => "https://schema.org/EventRescheduled",
+            Self::MovedOnline => "https://schema.org/EventMovedOnline",
+        }
+    }
+}
+
+impl EventSchema {
+    pub fn to_json_ld(&self) -> Value {
+        // An event moved online is attended online whatever its original venue
+        let attendance_mode = if self.status == EventStatus::MovedOnline {
+            "https://schema.org/OnlineEventAttendanceMode"
+        } else {
+            self.location.attendance_mode_url()
+        };
+
+        let mut schema = json!({
+            "@context": "https://schema.org",
+            "@type": "Event",
+            "name": self.name,
+            "startDate": self.start_date.to_rfc3339(),
+            "eventStatus": self.status.schema_url(),
+            "eventAttendanceMode": attendance_mode,
+            "location": self.location.to_json()
+        });
+
+        if let Some(end_date) = &self.end_date {
+            schema["endDate"] = json!(end_date.to_rfc3339());
+        }
+
+        if let Some(description) = &self.description {
+            schema["description"] = json!(description);
+        }
+
+        if !self.image.is_empty() {
+            schema["image"] = json!(self.image);
+        }
+
+        if let Some(organizer) = &self.organizer {
+            schema["organizer"] = json!({
+                "@type": "Organization",
+                "name": organizer.name,
+                "url": organizer.url
+            });
+        }
+
+        if !self.performer.is_empty() {
+            schema["performer"] = json!(self.performer.iter()
+                .map(PersonSchema::to_json)
+                .collect::<Vec<_>>());
+        }
+
+        if !self.offers.is_empty() {
+            schema["offers"] = json!(self.offers.iter()
+                .map(EventOffer::to_json)
+                .collect::<Vec<_>>());
+        }
+
+        schema
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job_posting(employment_type: Vec<String>) -> JobPostingSchema {
+        JobPostingSchema {
+            title: "Rust Developer".to_string(),
+            description: "Build fast things.".to_string(),
+            date_posted: Utc::now(),
+            valid_through: None,
+            employment_type,
+            hiring_organization: OrganizationSchema::new(
+                "Example Inc".to_string(),
+                "https://example.com".to_string(),
+            ),
+            job_location: None,
+            remote_work: JobRemoteWork {
+                is_remote: true,
+                ..Default::default()
+            },
+            base_salary: Some(SalarySchema {
+                value: 90000.0,
+                currency: "USD".to_string(),
+                unit_text: "YEAR".to_string(),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_job_posting_employment_type_is_array() {
+        let schema = job_posting(vec!["FULL_TIME".to_string()]).to_json_ld();
+        assert_eq!(schema["employmentType"], json!(["FULL_TIME"]));
+
+        let schema = job_posting(vec!["FULL_TIME".to_string(), "CONTRACTOR".to_string()]).to_json_ld();
+        assert_eq!(schema["employmentType"], json!(["FULL_TIME", "CONTRACTOR"]));
+        assert_eq!(schema["jobLocationType"], "TELECOMMUTE");
+        assert_eq!(schema["baseSalary"]["value"]["unitText"], "YEAR");
+    }
+
+    #[test]
+    fn test_course_optional_properties() {
+        let mut course = CourseSchema {
+            name: "Intro to Rust".to_string(),
+            description: "Learn the basics of Rust.".to_string(),
+            url: "https://example.com/courses/rust".to_string(),
+            provider: OrganizationSchema::new(
+                "Example Academy".to_string(),
+                "https://example.com".to_string(),
+            ),
+            instructor: None,
+            course_code: None,
+            course_prerequisites: vec![],
+            educational_level: None,
+            offers: None,
+            duration: None,
+        };
+
+        let schema = course.to_json_ld();
+        assert_eq!(schema["provider"]["@type"], "Organization");
+        assert!(schema.get("courseCode").is_none());
+        assert!(schema.get("hasCourseInstance").is_none());
+
+        course.course_code = Some("RS101".to_string());
+        course.educational_level = Some("Beginner".to_string());
+        course.duration = Some("PT10H".to_string());
+        let schema = course.to_json_ld();
+        assert_eq!(schema["courseCode"], "RS101");
+        assert_eq!(schema["educationalLevel"], "Beginner");
+        assert_eq!(schema["hasCourseInstance"]["courseWorkload"], "PT10H");
+    }
+
+    #[test]
+    fn test_product_reviews_fill_in_aggregate_rating() {
+        let review = |rating: f32| ReviewSchema {
+            author: "Sam".to_string(),
+            rating,
+            review_body: "Works well.".to_string(),
+            date_published: Utc::now(),
+        };
+        let mut product = ProductSchema {
+            name: "Widget".to_string(),
+            description: "A widget.".to_string(),
+            url: "https://example.com/widget".to_string(),
+            image: vec![],
+            sku: None,
+            brand: None,
+            price: "9.99".to_string(),
+            currency: "USD".to_string(),
+            availability: ProductAvailability::InStock,
+            condition: ProductCondition::New,
+            rating: None,
+            reviews: vec![review(5.0), review(4.0), review(4.0)],
+        };
+
+        let schema = product.to_json_ld();
+        assert_eq!(schema["review"][0]["@type"], "Review");
+        assert_eq!(schema["review"][0]["reviewRating"]["@type"], "Rating");
+        assert_eq!(schema["aggregateRating"]["reviewCount"], 3);
+        assert!((schema["aggregateRating"]["ratingValue"].as_f64().unwrap() - 4.3).abs() < 0.01);
+
+        product.reviews.clear();
+        assert!(product.to_json_ld().get("aggregateRating").is_none());
+    }
+
+    #[test]
+    fn test_howto_steps_are_numbered() {
+        let howto = HowToSchema {
+            name: "Change a tire".to_string(),
+            description: None,
+            total_time: Some("PT30M".to_string()),
+            estimated_cost: None,
+            image: vec![],
+            steps: vec![
+                HowToStep {
+                    name: "Loosen the nuts".to_string(),
+                    text: "Turn each lug nut a half turn.".to_string(),
+                    image: None,
+                    url: Some("https://example.com/tire#step1".to_string()),
+                },
+                HowToStep {
+                    name: "Jack up the car".to_string(),
+                    text: "Raise the car until the tire is off the ground.".to_string(),
+                    image: None,
+                    url: None,
+                },
+            ],
+        };
+
+        let schema = howto.to_json_ld();
+        assert_eq!(schema["@type"], "HowTo");
+        assert_eq!(schema["totalTime"], "PT30M");
+        assert_eq!(schema["step"][1]["@type"], "HowToStep");
+        assert_eq!(schema["step"][1]["position"], 2);
+        assert_eq!(schema["step"][0]["url"], "https://example.com/tire#step1");
+        assert!(schema.get("image").is_none());
+    }
+}
@@ -25,10 +25,29 @@ pub struct Sitemap {
     pub sitemap_type: SitemapType,
     pub urls: Vec<SitemapUrl>,
     pub generated_at: DateTime<Utc>,
+    /// 1-based chunk number when a large sitemap is split across files
+    #[serde(default)]
+    pub part: Option<usize>,
+}
+
+/// URL-level changes between two generations of a sitemap
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SitemapDiff {
+    pub added: Vec<SitemapUrl>,
+    /// Locations no longer in the sitemap
+    pub removed: Vec<String>,
+    /// URLs whose lastmod, priority or changefreq changed
+    pub modified: Vec<SitemapUrl>,
+}
+
+impl SitemapDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
 }
 
 /// Type of sitemap
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum SitemapType {
     Posts,
@@ -40,10 +59,29 @@ pub enum SitemapType {
     Images,
     Videos,
     News,
+    /// Language alternates for multilingual sites
+    Hreflang,
     Custom,
 }
 
 impl SitemapType {
+    /// All sitemap types
+    pub fn all() -> [SitemapType; 11] {
+        [
+            Self::Posts,
+            Self::Pages,
+            Self::Products,
+            Self::Categories,
+            Self::Tags,
+            Self::Authors,
+            Self::Images,
+            Self::Videos,
+            Self::News,
+            Self::Hreflang,
+            Self::Custom,
+        ]
+    }
+
     pub fn filename(&self) -> &'static str {
         match self {
             Self::Posts => "post-sitemap.xml",
@@ -55,6 +93,7 @@ impl SitemapType {
             Self::Images => "image-sitemap.xml",
             Self::Videos => "video-sitemap.xml",
             Self::News => "news-sitemap.xml",
+            Self::Hreflang => "hreflang-sitemap.xml",
             Self::Custom => "custom-sitemap.xml",
         }
     }
@@ -116,6 +155,61 @@ impl SitemapUrl {
         self.priority = Some(priority.clamp(0.0, 1.0));
         self
     }
+
+    /// Rough relative crawl budget cost of this URL
+    ///
+    /// A plain page counts as 1.0, a video page as 2.0 and a news entry as 1.8.
+    /// Images add 0.5 for the first and 0.2 for each additional one, so a page
+    /// with a single image costs 1.5. The figures are proportional estimates for
+    /// comparing sitemaps, not values reported by search engines.
+    pub fn estimated_crawl_budget_cost(&self) -> f32 {
+        let base = if !self.videos.is_empty() {
+            2.0
+        } else if self.news.is_some() {
+            1.8
+        } else {
+            1.0
+        };
+
+        let images = match self.images.len() {
+            0 => 0.0,
+            n => 0.5 + 0.2 * (n - 1) as f32,
+        };
+
+        base + images
+    }
+
+    /// Approximate size in bytes of this URL's `<url>` entry, ignoring XML escaping
+    ///
+    /// Exact for location, dates, frequency, priority, images and alternates;
+    /// video and news entries are sized from their text plus a fixed tag overhead.
+    pub fn estimated_xml_size(&self) -> usize {
+        let mut size = 17 + 16 + self.loc.len();
+        if self.lastmod.is_some() {
+            size += 49;
+        }
+        if let Some(freq) = &self.changefreq {
+            size += 30 + freq.as_str().len();
+        }
+        if self.priority.is_some() {
+            size += 29;
+        }
+        for image in &self.images {
+            size += 67 + image.loc.len();
+            size += image.title.as_ref().map_or(0, |title| 34 + title.len());
+            size += image.caption.as_ref().map_or(0, |caption| 38 + caption.len());
+        }
+        for video in &self.videos {
+            size += 400 + video.thumbnail_loc.len() + video.title.len() + video.description.len();
+        }
+        if let Some(news) = &self.news {
+            size += 300 + news.publication_name.len() + news.title.len();
+        }
+        for alt in &self.alternates {
+            size += 54 + alt.hreflang.len() + alt.href.len();
+        }
+        size
+    }
 }
 
 /// Change frequency values
@@ -199,9 +293,27 @@ impl Sitemap {
             sitemap_type,
             urls: vec![],
             generated_at: Utc::now(),
+            part: None,
         }
     }
 
+    /// File name, numbered when this is one chunk of a split sitemap
+    pub fn filename(&self) -> String {
+        let filename = self.sitemap_type.filename();
+        match self.part {
+            Some(part) => format!("{}{}.xml", filename.trim_end_matches(".xml"), part),
+            None => filename.to_string(),
+        }
+    }
+
+    /// Most recent URL modification, falling back to the generation time
+    pub fn lastmod(&self) -> DateTime<Utc> {
+        self.urls.iter()
+            .filter_map(|url| url.lastmod)
+            .max()
+            .unwrap_or(self.generated_at)
+    }
+
     /// Generate XML string for this sitemap
     pub fn to_xml(&self) -> String {
         let mut xml = String::new();
@@ -255,6 +367,83 @@ impl Sitemap {
                 xml.push_str("    </image:image>\n");
             }
 
+            // Videos
+            for video in &url.videos {
+                xml.push_str("    <video:video>\n");
+                xml.push_str(&format!("      <video:thumbnail_loc>{}</video:thumbnail_loc>\n", xml_escape(&video.thumbnail_loc)));
+                xml.push_str(&format!("      <video:title>{}</video:title>\n", xml_escape(&video.title)));
+                xml.push_str(&format!("      <video:description>{}</video:description>\n", xml_escape(&video.description)));
+                if let Some(content_loc) = &video.content_loc {
+                    xml.push_str(&format!("      <video:content_loc>{}</video:content_loc>\n", xml_escape(content_loc)));
+                }
+                if let Some(player_loc) = &video.player_loc {
+                    xml.push_str(&format!("      <video:player_loc>{}</video:player_loc>\n", xml_escape(player_loc)));
+                }
+                if let Some(duration) = video.duration {
+                    xml.push_str(&format!("      <video:duration>{}</video:duration>\n", duration));
+                }
+                if let Some(expiration) = &video.expiration_date {
+                    xml.push_str(&format!(
+                        "      <video:expiration_date>{}</video:expiration_date>\n",
+                        expiration.format("%Y-%m-%dT%H:%M:%S%:z")
+                    ));
+                }
+                if let Some(rating) = video.rating {
+                    xml.push_str(&format!("      <video:rating>{:.1}</video:rating>\n", rating));
+                }
+                if let Some(view_count) = video.view_count {
+                    xml.push_str(&format!("      <video:view_count>{}</video:view_count>\n", view_count));
+                }
+                if let Some(published) = &video.publication_date {
+                    xml.push_str(&format!(
+                        "      <video:publication_date>{}</video:publication_date>\n",
+                        published.format("%Y-%m-%dT%H:%M:%S%:z")
+                    ));
+                }
+                xml.push_str(&format!(
+                    "      <video:family_friendly>{}</video:family_friendly>\n",
+                    if video.family_friendly { "yes" } else { "no" }
+                ));
+                // Google reads at most 32 tags per video
+                for tag in video.tags.iter().take(32) {
+                    xml.push_str(&format!("      <video:tag>{}</video:tag>\n", xml_escape(tag)));
+                }
+                if let Some(category) = &video.category {
+                    xml.push_str(&format!("      <video:category>{}</video:category>\n", xml_escape(category)));
+                }
+                if video.requires_subscription {
+                    xml.push_str("      <video:requires_subscription>yes</video:requires_subscription>\n");
+                }
+                if video.live {
+                    xml.push_str("      <video:live>yes</video:live>\n");
+                }
+                xml.push_str("    </video:video>\n");
+            }
+
+            // News
+            if let Some(news) = &url.news {
+                xml.push_str("    <news:news>\n");
+                xml.push_str("      <news:publication>\n");
+                xml.push_str(&format!("        <news:name>{}</news:name>\n", xml_escape(&news.publication_name)));
+                xml.push_str(&format!("        <news:language>{}</news:language>\n", xml_escape(&news.publication_language)));
+                xml.push_str("      </news:publication>\n");
+                xml.push_str(&format!(
+                    "      <news:publication_date>{}</news:publication_date>\n",
+                    news.publication_date.format("%Y-%m-%dT%H:%M:%S%:z")
+                ));
+                xml.push_str(&format!("      <news:title>{}</news:title>\n", xml_escape(&news.title)));
+                if !news.keywords.is_empty() {
+                    xml.push_str(&format!("      <news:keywords>{}</news:keywords>\n", xml_escape(&news.keywords.join(", "))));
+                }
+                if !news.stock_tickers.is_empty() {
+                    xml.push_str(&format!(
+                        "      <news:stock_tickers>{}</news:stock_tickers>\n",
+                        xml_escape(&news.stock_tickers.join(", "))
+                    ));
+                }
+                xml.push_str("    </news:news>\n");
+            }
+
             // Alternates (hreflang)
             for alt in &url.alternates {
                 xml.push_str(&format!(
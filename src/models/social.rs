@@ -4,6 +4,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::admin::settings::SocialProfiles;
+
 /// OpenGraph data for social sharing
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpenGraphData {
@@ -83,7 +85,7 @@ impl OpenGraphType {
 }
 
 /// OpenGraph article data
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct OpenGraphArticle {
     pub published_time: Option<String>,
     pub modified_time: Option<String>,
@@ -135,6 +137,43 @@ impl OpenGraphData {
         }
     }
 
+    /// Attach article data, switching `og:type` to `article`
+    pub fn with_article(mut self, article: OpenGraphArticle) -> Self {
+        self.og_type = OpenGraphType::Article;
+        self.article = Some(article);
+        self
+    }
+
+    /// Add an `article:author` profile URL
+    pub fn with_article_author(mut self, url: &str) -> Self {
+        let authors = &mut self.article_mut().author;
+        if !authors.iter().any(|a| a == url) {
+            authors.push(url.to_string());
+        }
+        self
+    }
+
+    /// Add an `article:tag`
+    pub fn with_article_tag(mut self, tag: &str) -> Self {
+        let tags = &mut self.article_mut().tag;
+        if !tags.iter().any(|t| t == tag) {
+            tags.push(tag.to_string());
+        }
+        self
+    }
+
+    /// Set the `article:section`
+    pub fn with_article_section(mut self, section: &str) -> Self {
+        self.article_mut().section = Some(section.to_string());
+        self
+    }
+
+    /// Article data, created (as an article) when missing
+    fn article_mut(&mut self) -> &mut OpenGraphArticle {
+        self.og_type = OpenGraphType::Article;
+        self.article.get_or_insert_with(OpenGraphArticle::default)
+    }
+
     /// Generate OpenGraph meta tags HTML
     pub fn to_html(&self) -> String {
         let mut html = String::new();
@@ -422,6 +461,82 @@ impl TwitterCardData {
     }
 }
 
+/// Mastodon / fediverse meta data
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MastodonMetaData {
+    /// Profile URL (https://instance.social/@user)
+    pub account_url: Option<String>,
+
+    /// fediverse:creator (@user@instance.social)
+    pub creator_account: Option<String>,
+}
+
+impl MastodonMetaData {
+    /// Mastodon data for the configured Mastodon profile
+    ///
+    /// Other networks also use `/@handle` URLs, so only the Mastodon entry is read.
+    pub fn from_social_profiles(profiles: &SocialProfiles) -> Option<Self> {
+        profiles.mastodon.as_deref().and_then(Self::parse_account)
+    }
+
+    /// Parse `@user@domain` or `https://domain/@user` into Mastodon data
+    pub fn parse_account(value: &str) -> Option<Self> {
+        let value = value.trim();
+
+        // @user@domain
+        if let Some(handle) = value.strip_prefix('@') {
+            let (user, domain) = handle.split_once('@')?;
+            if !is_valid_handle_part(user) || !domain.contains('.') || !is_valid_handle_part(domain) {
+                return None;
+            }
+            return Some(Self {
+                account_url: Some(format!("https://{}/@{}", domain, user)),
+                creator_account: Some(format!("@{}@{}", user, domain)),
+            });
+        }
+
+        // https://domain/@user
+        let parsed = url::Url::parse(value).ok()?;
+        let domain = parsed.host_str()?;
+        let user = parsed.path().trim_matches('/').strip_prefix('@')?;
+        if !is_valid_handle_part(user) || user.contains('@') {
+            return None;
+        }
+
+        Some(Self {
+            account_url: Some(value.to_string()),
+            creator_account: Some(format!("@{}@{}", user, domain)),
+        })
+    }
+
+    /// Generate fediverse meta tags HTML
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+
+        if let Some(creator) = &self.creator_account {
+            html.push_str(&format!(
+                "<meta property=\"fediverse:creator\" content=\"{}\">\n",
+                html_escape(creator)
+            ));
+        }
+
+        if let Some(url) = &self.account_url {
+            html.push_str(&format!(
+                "<link rel=\"me\" href=\"{}\">\n",
+                html_escape(url)
+            ));
+        }
+
+        html
+    }
+}
+
+fn is_valid_handle_part(s: &str) -> bool {
+    !s.is_empty()
+        && s.chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.' || c == '-')
+}
+
 /// Social media settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SocialSettings {
@@ -433,6 +548,7 @@ pub struct SocialSettings {
     pub twitter_site: Option<String>,
     pub facebook_app_id: Option<String>,
     pub facebook_admin_ids: Vec<String>,
+    pub mastodon: Option<MastodonMetaData>,
 }
 
 impl Default for SocialSettings {
@@ -446,6 +562,7 @@ impl Default for SocialSettings {
             twitter_site: None,
             facebook_app_id: None,
             facebook_admin_ids: vec![],
+            mastodon: None,
         }
     }
 }
@@ -457,3 +574,42 @@ fn html_escape(s: &str) -> String {
         .replace('"', "&quot;")
         .replace('\'', "&#39;")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mastodon_only_from_mastodon_profile() {
+        let mut profiles = SocialProfiles {
+            youtube: Some("https://www.youtube.com/@rustpress".to_string()),
+            tiktok: Some("https://www.tiktok.com/@rustpress".to_string()),
+            ..SocialProfiles::default()
+        };
+        assert!(MastodonMetaData::from_social_profiles(&profiles).is_none());
+
+        profiles.mastodon = Some("@rustpress@fosstodon.org".to_string());
+        let mastodon = MastodonMetaData::from_social_profiles(&profiles).unwrap();
+        assert_eq!(mastodon.creator_account.as_deref(), Some("@rustpress@fosstodon.org"));
+        assert_eq!(mastodon.account_url.as_deref(), Some("https://fosstodon.org/@rustpress"));
+    }
+
+    #[test]
+    fn test_article_builders() {
+        let og = OpenGraphData::new(OpenGraphType::Website, "Post".to_string(), "https://example.com/post".to_string())
+            .with_article_author("https://example.com/author/jane")
+            .with_article_author("https://example.com/author/jane")
+            .with_article_tag("rust")
+            .with_article_section("News");
+
+        assert_eq!(og.og_type, OpenGraphType::Article);
+        let article = og.article.as_ref().unwrap();
+        assert_eq!(article.author, vec!["https://example.com/author/jane"]);
+        assert_eq!(article.tag, vec!["rust"]);
+
+        let html = og.to_html();
+        assert!(html.contains("<meta property=\"og:type\" content=\"article\">"));
+        assert!(html.contains("<meta property=\"article:author\" content=\"https://example.com/author/jane\">"));
+        assert!(html.contains("<meta property=\"article:section\" content=\"News\">"));
+    }
+}
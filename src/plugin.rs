@@ -4,6 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::{Arc, PoisonError, RwLock};
 
 /// Plugin metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -46,28 +47,127 @@ impl Default for PluginInfo {
 #[derive(Debug, Clone)]
 pub struct RustSeoPlugin {
     info: PluginInfo,
-    settings: crate::settings::SeoSettings,
+    settings: Arc<RwLock<crate::settings::SeoSettings>>,
+    meta: Arc<RwLock<crate::services::CachedMetaService>>,
+    sitemaps: Arc<RwLock<crate::services::SitemapService>>,
+    redirects: Arc<tokio::sync::RwLock<crate::services::RedirectService>>,
+    analysis: Arc<RwLock<crate::services::analysis::AnalysisService>>,
     initialized: bool,
 }
 
 impl RustSeoPlugin {
     /// Create new plugin instance
     pub fn new() -> Self {
+        let settings = crate::settings::SeoSettings::default();
+        let store = Arc::new(crate::services::meta::InMemoryMetaStore::new());
+        let meta = Self::meta_service(&settings, store);
+        let sitemaps = Self::sitemap_service(&settings);
+        // Would load the configured redirects and 404 log from the database in real implementation
+        let redirects = crate::services::RedirectService::new()
+            .with_settings(settings.redirects.to_redirect_settings());
         Self {
             info: PluginInfo::default(),
-            settings: crate::settings::SeoSettings::default(),
+            meta: Arc::new(RwLock::new(meta)),
+            sitemaps: Arc::new(RwLock::new(sitemaps)),
+            redirects: Arc::new(tokio::sync::RwLock::new(redirects)),
+            analysis: Arc::new(RwLock::new(crate::services::analysis::AnalysisService::from_settings(&settings))),
+            settings: Arc::new(RwLock::new(settings)),
             initialized: false,
         }
     }
 
+    /// Cached meta service configured from the plugin settings
+    fn meta_service(
+        settings: &crate::settings::SeoSettings,
+        store: Arc<dyn crate::services::meta::MetaStore>,
+    ) -> crate::services::CachedMetaService {
+        let mut service = crate::services::MetaService::new(settings.site_name.clone(), settings.site_url.clone())
+            .with_settings(settings.meta.clone())
+            .with_store(store);
+        if let Some(mastodon) = settings.social.mastodon.as_deref()
+            .and_then(crate::models::social::MastodonMetaData::parse_account)
+        {
+            service = service.with_mastodon(mastodon);
+        }
+        crate::services::CachedMetaService::from_settings(service, &settings.advanced)
+    }
+
+    /// Sitemap service configured from the plugin settings
+    fn sitemap_service(settings: &crate::settings::SeoSettings) -> crate::services::SitemapService {
+        // Would publish to the site's web root through a FileSitemapStore in real implementation
+        crate::services::SitemapService::new(settings.site_url.clone())
+            .with_config(settings.sitemap.to_sitemap_config())
+    }
+
+    /// Sitemap service holding the published files and change tracking
+    pub fn sitemaps(&self) -> crate::services::SitemapService {
+        self.sitemaps.read().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    /// Redirect rules and 404 log shared by the redirect handlers
+    pub fn redirect_service(&self) -> &tokio::sync::RwLock<crate::services::RedirectService> {
+        &self.redirects
+    }
+
+    /// Meta service shared by the head output and the meta handlers
+    pub fn meta(&self) -> crate::services::CachedMetaService {
+        self.meta.read().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    /// Drop cached meta for a content item, or all of it when the key is not recognised
+    pub fn invalidate_meta_cache(&self, content_type: &str, content_id: &str) {
+        let content_type = serde_json::from_value(serde_json::Value::String(content_type.to_string()));
+        match (content_type, uuid::Uuid::parse_str(content_id)) {
+            (Ok(content_type), Ok(content_id)) => self.meta().invalidate(content_type, content_id),
+            _ => self.meta().invalidate_all(),
+        }
+    }
+
+    /// Drop the cached analysis for a content item, or all of them when the ID is not recognised
+    pub fn invalidate_analysis_cache(&self, content_id: &str) {
+        let analysis = self.analysis.read().unwrap_or_else(PoisonError::into_inner);
+        match uuid::Uuid::parse_str(content_id) {
+            Ok(content_id) => analysis.invalidate_cache(content_id),
+            Err(_) => analysis.clear_cache(),
+        }
+    }
+
+    /// Register a resolver for custom `%%variable%%` title placeholders
+    pub fn register_resolver(&self, resolver: Box<dyn crate::settings::TitleVariableResolver>) {
+        crate::settings::register_title_resolver(resolver);
+    }
+
     /// Get plugin info
     pub fn info(&self) -> &PluginInfo {
         &self.info
     }
 
-    /// Get plugin settings
-    pub fn settings(&self) -> &crate::settings::SeoSettings {
-        &self.settings
+    /// Get a snapshot of the plugin settings
+    pub fn settings(&self) -> crate::settings::SeoSettings {
+        self.settings.read().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    /// Replace the plugin settings; call `save_settings` to persist them
+    ///
+    /// The meta and sitemap services are rebuilt for the new settings,
+    /// keeping their stored data. Cached analyses are dropped.
+    pub fn update_settings(&self, settings: crate::settings::SeoSettings) {
+        let mut meta = self.meta.write().unwrap_or_else(PoisonError::into_inner);
+        *meta = Self::meta_service(&settings, meta.inner().store());
+        let mut sitemaps = self.sitemaps.write().unwrap_or_else(PoisonError::into_inner);
+        *sitemaps = Self::sitemap_service(&settings).share_state_with(&sitemaps);
+        let redirect_settings = settings.redirects.to_redirect_settings();
+        match self.redirects.try_write() {
+            Ok(mut redirects) => redirects.set_settings(redirect_settings),
+            Err(_) => {
+                // A handler holds the redirects; apply once it lets go
+                let redirects = self.redirects.clone();
+                tokio::spawn(async move { redirects.write().await.set_settings(redirect_settings) });
+            }
+        }
+        *self.analysis.write().unwrap_or_else(PoisonError::into_inner) =
+            crate::services::analysis::AnalysisService::from_settings(&settings);
+        *self.settings.write().unwrap_or_else(PoisonError::into_inner) = settings;
     }
 
     /// Initialize the plugin
@@ -95,7 +195,7 @@ impl RustSeoPlugin {
     /// Load settings from database
     async fn load_settings(&mut self) -> Result<(), PluginError> {
         // In real implementation, this would load from database
-        self.settings = crate::settings::SeoSettings::default();
+        self.update_settings(crate::settings::SeoSettings::default());
         Ok(())
     }
 
@@ -125,7 +225,8 @@ impl RustSeoPlugin {
     /// Register REST API routes
     fn register_api_routes(&self) {
         // This would integrate with RustPress routing system
-        let _routes = crate::admin::get_admin_routes();
+        // Capabilities are checked per request, so register every route
+        let _routes = crate::admin::get_admin_routes(&crate::admin::AllowAllCapabilityChecker, "");
     }
 
     /// Activate the plugin
@@ -182,26 +283,58 @@ impl RustSeoPlugin {
 
     /// Get meta tags for a page
     pub fn get_meta_tags(&self, content_type: &str, content_id: &str) -> String {
-        // This would fetch and return meta tags for the content
-        let _ = (content_type, content_id);
-        String::new()
+        use crate::models::meta::{ContentType, SeoMeta};
+        use crate::settings::ContentContext;
+
+        let Ok(content_id) = uuid::Uuid::parse_str(content_id) else {
+            return String::new();
+        };
+        let content_type = serde_json::from_value(serde_json::Value::String(content_type.to_string()))
+            .unwrap_or(ContentType::Custom);
+
+        let service = self.meta();
+        let meta = service.get_meta(content_type, content_id)
+            .ok()
+            .flatten()
+            .unwrap_or_else(|| SeoMeta::new(content_id, content_type));
+        let context = ContentContext {
+            is_archive: content_type == ContentType::Archive,
+            ..ContentContext::new(content_type)
+        };
+        let meta = service.inner().restrict_robots(&meta, &context);
+
+        // Would load the content title, permalink, featured image and author in real implementation
+        let settings = self.settings();
+        let title = meta.title.clone().unwrap_or_else(|| settings.site_name.clone());
+        service.inner().generate_head(&meta, &title, &settings.site_url, None, None, None)
+    }
+
+    /// Build the sitemaps and publish the ones that changed since the last run
+    pub async fn generate_sitemap(&self) -> Result<crate::admin::sitemaps::GenerationResult, PluginError> {
+        // Would build the sitemaps from published content in real implementation
+        let sitemaps = Vec::new();
+
+        let result = self.sitemaps().publish(sitemaps);
+        if !result.success {
+            return Err(PluginError::new("SITEMAP_GENERATION_FAILED", &result.errors.join("; ")));
+        }
+        Ok(result)
     }
 
-    /// Generate sitemap XML
-    pub async fn generate_sitemap(&self) -> Result<String, PluginError> {
-        let service = crate::services::sitemap::SitemapService::new(
-            self.settings.site_url.clone()
-        );
-
-        // Generate sitemap index
-        let sitemap = service.generate_index(vec![]);
-        Ok(sitemap.to_xml())
-    }
+    /// Analyze content through the analysis cache, skipping content that would not be indexed anyway
+    pub fn analyze_content(
+        &self,
+        meta: &crate::models::meta::SeoMeta,
+        context: &crate::settings::ContentContext,
+        data: crate::services::analysis::AnalysisInput,
+    ) -> Option<crate::models::analysis::SeoAnalysis> {
+        use crate::services::analysis::AnalysisService;
 
-    /// Analyze content
-    pub fn analyze_content(&self, content: &str, focus_keyword: Option<&str>) -> crate::models::analysis::SeoAnalysisResult {
-        let service = crate::services::analysis::AnalysisService::new();
-        service.analyze(content, focus_keyword)
+        if !AnalysisService::is_analysis_worthwhile(meta, context, &self.settings()) {
+            return None;
+        }
+        let service = self.analysis.read().unwrap_or_else(PoisonError::into_inner);
+        Some(service.analyze_cached(meta.content_id, data))
     }
 
     /// Process redirect
@@ -214,7 +347,7 @@ impl RustSeoPlugin {
     /// Generate robots.txt
     pub fn generate_robots_txt(&self) -> String {
         let service = crate::services::robots::RobotsService::new(
-            self.settings.site_url.clone()
+            self.settings().site_url
         );
         service.generate()
     }
@@ -232,18 +365,19 @@ impl RustSeoPlugin {
 
     /// Get enabled features
     fn get_enabled_features(&self) -> Vec<String> {
+        let settings = self.settings();
         let mut features = vec!["meta_tags".to_string()];
 
-        if self.settings.sitemap.enabled {
+        if settings.sitemap.enabled {
             features.push("sitemap".to_string());
         }
-        if self.settings.schema.enabled {
+        if settings.schema.enabled {
             features.push("schema".to_string());
         }
-        if self.settings.redirects.enabled {
+        if settings.redirects.enabled {
             features.push("redirects".to_string());
         }
-        if self.settings.social.enabled {
+        if settings.social.enabled {
             features.push("social".to_string());
         }
 
@@ -310,6 +444,8 @@ pub struct PluginHooks;
 
 impl PluginHooks {
     /// Hook: Output meta tags in head
+    ///
+    /// Emits the full block from [`MetaService::generate_head`](crate::services::MetaService::generate_head).
     pub fn head_output(plugin: &RustSeoPlugin, content_type: &str, content_id: &str) -> String {
         plugin.get_meta_tags(content_type, content_id)
     }
@@ -321,12 +457,20 @@ impl PluginHooks {
     }
 
     /// Hook: Analyze content on save
-    pub fn content_save(plugin: &RustSeoPlugin, content: &str, focus_keyword: Option<&str>) -> crate::models::analysis::SeoAnalysisResult {
-        plugin.analyze_content(content, focus_keyword)
-    }
-
-    /// Hook: Update sitemap on content change
-    pub async fn content_change(_plugin: &RustSeoPlugin) {
+    pub fn content_save(
+        plugin: &RustSeoPlugin,
+        meta: &crate::models::meta::SeoMeta,
+        context: &crate::settings::ContentContext,
+        data: crate::services::analysis::AnalysisInput,
+    ) -> Option<crate::models::analysis::SeoAnalysis> {
+        plugin.invalidate_analysis_cache(&meta.content_id.to_string());
+        plugin.analyze_content(meta, context, data)
+    }
+
+    /// Hook: Update sitemap and cached meta on content change
+    pub async fn content_change(plugin: &RustSeoPlugin, content_type: &str, content_id: &str) {
+        plugin.invalidate_meta_cache(content_type, content_id);
+        plugin.invalidate_analysis_cache(content_id);
         // Regenerate sitemap
     }
 
@@ -357,10 +501,77 @@ pub fn get_plugin() -> &'static RustSeoPlugin {
 pub mod actions {
     use super::*;
 
-    pub async fn regenerate_sitemap() -> Result<(), PluginError> {
+    /// Sitemap files older than this are reported as stale during recovery
+    const STALE_SITEMAP_DAYS: i64 = 7;
+
+    pub async fn regenerate_sitemap() -> Result<crate::admin::sitemaps::GenerationResult, PluginError> {
         let plugin = get_plugin();
-        let _ = plugin.generate_sitemap().await?;
-        Ok(())
+        match plugin.generate_sitemap().await {
+            Ok(result) => {
+                tracing::info!(
+                    "Regenerated {} sitemaps with {} URLs in {}ms",
+                    result.sitemaps_generated,
+                    result.total_urls,
+                    result.generation_time_ms
+                );
+                Ok(result)
+            }
+            Err(e) => {
+                // Database records are missing, fall back to the published sitemap files
+                tracing::warn!("Sitemap regeneration failed, rebuilding index from existing files: {}", e);
+                recover_sitemap_index(&plugin.sitemaps())
+            }
+        }
+    }
+
+    /// Rebuild and write the sitemap index from sitemap files that are still in the store
+    pub fn recover_sitemap_index(
+        service: &crate::services::sitemap::SitemapService,
+    ) -> Result<crate::admin::sitemaps::GenerationResult, PluginError> {
+        use crate::services::sitemap::{SitemapService, INDEX_FILENAME};
+
+        let started = std::time::Instant::now();
+        let stale_before = chrono::Utc::now() - chrono::Duration::days(STALE_SITEMAP_DAYS);
+        let files: Vec<(String, chrono::DateTime<chrono::Utc>)> = service.store()
+            .list()
+            .into_iter()
+            .filter(|(filename, _)| SitemapService::infer_sitemap_type_from_filename(filename).is_some())
+            .collect();
+        if files.is_empty() {
+            return Err(PluginError::new(
+                "SITEMAP_FILES_MISSING",
+                "No published sitemap files found to rebuild the index from",
+            ));
+        }
+
+        let warnings: Vec<String> = files.iter()
+            .filter(|(_, modified)| *modified < stale_before)
+            .map(|(filename, modified)| {
+                format!("{} is stale, last written {}", filename, modified.format("%Y-%m-%d"))
+            })
+            .collect();
+        for warning in &warnings {
+            tracing::warn!("{}", warning);
+        }
+
+        let sitemap_urls = files.iter().map(|(filename, _)| service.get_file_url(filename)).collect();
+        let index = SitemapService::rebuild_index_from_sitemaps(sitemap_urls);
+        service.store()
+            .write(INDEX_FILENAME, &index.to_xml())
+            .map_err(|e| PluginError::new("SITEMAP_WRITE_FAILED", &e.to_string()))?;
+
+        Ok(crate::admin::sitemaps::GenerationResult {
+            success: true,
+            sitemaps_generated: 0,
+            total_urls: 0,
+            generation_time_ms: started.elapsed().as_millis() as i64,
+            sitemaps_unchanged: files.len() as i32,
+            urls_added: 0,
+            urls_removed: 0,
+            urls_modified: 0,
+            errors: vec![],
+            warnings,
+        })
     }
 
     pub async fn analyze_all_content() -> Result<AnalysisStats, PluginError> {
@@ -398,15 +609,98 @@ pub mod actions {
 
     pub async fn export_settings() -> Result<String, PluginError> {
         let plugin = get_plugin();
-        let settings = serde_json::to_string_pretty(&plugin.settings)
+        let settings = serde_json::to_string_pretty(&plugin.settings())
             .map_err(|e| PluginError::new("EXPORT_FAILED", &e.to_string()))?;
         Ok(settings)
     }
 
     pub async fn import_settings(data: &str) -> Result<(), PluginError> {
-        let _settings: crate::settings::SeoSettings = serde_json::from_str(data)
+        let value: serde_json::Value = serde_json::from_str(data)
+            .map_err(|e| PluginError::new("IMPORT_FAILED", &e.to_string()))?;
+
+        if is_wp_seo_export(&value) {
+            return import_wp_seo(&value).await;
+        }
+
+        let _settings: crate::settings::SeoSettings = serde_json::from_value(value)
             .map_err(|e| PluginError::new("IMPORT_FAILED", &e.to_string()))?;
         // Save settings
         Ok(())
     }
+
+    /// Yoast post meta export, either a single object or a list of them
+    fn is_wp_seo_export(value: &serde_json::Value) -> bool {
+        let has_yoast_title = |v: &serde_json::Value| v.get("_yoast_wpseo_title").is_some();
+        match value {
+            serde_json::Value::Array(items) => items.iter().any(has_yoast_title),
+            other => has_yoast_title(other),
+        }
+    }
+
+    /// Import content meta from a Yoast post meta export
+    async fn import_wp_seo(value: &serde_json::Value) -> Result<(), PluginError> {
+        let items = match value {
+            serde_json::Value::Array(items) => items.iter().collect::<Vec<_>>(),
+            other => vec![other],
+        };
+
+        let _metas: Vec<crate::models::meta::SeoMeta> = items
+            .into_iter()
+            .map(crate::settings::SeoSettings::from_wp_seo_json)
+            .collect();
+        // Save content meta
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::meta::{ContentType, SeoMeta};
+
+    #[test]
+    fn test_head_output_sees_saved_meta_after_settings_change() {
+        let plugin = RustSeoPlugin::new();
+        let content_id = uuid::Uuid::now_v7();
+        let mut meta = SeoMeta::new(content_id, ContentType::Post);
+        meta.title = Some("Stored title".to_string());
+        meta.use_custom_title = true;
+        plugin.meta().save_meta(&meta).unwrap();
+
+        plugin.update_settings(crate::settings::SeoSettings::default());
+        assert!(plugin.get_meta_tags("post", &content_id.to_string()).contains("Stored title"));
+    }
+
+    #[test]
+    fn test_head_output_includes_configured_mastodon_account() {
+        let plugin = RustSeoPlugin::new();
+        let mut settings = crate::settings::SeoSettings::default();
+        settings.social.mastodon = Some("https://fosstodon.org/@rustpress".to_string());
+        plugin.update_settings(settings);
+
+        let meta = SeoMeta::new(uuid::Uuid::now_v7(), ContentType::Post);
+        let head = plugin.meta().inner().generate_head(&meta, "Post", "https://example.com/post", None, None, None);
+        assert!(head.contains("<meta property=\"fediverse:creator\" content=\"@rustpress@fosstodon.org\">"));
+    }
+
+    #[test]
+    fn test_recover_sitemap_index_from_stored_files() {
+        use crate::services::sitemap::{SitemapService, INDEX_FILENAME};
+
+        let service = SitemapService::new("https://example.com".to_string());
+        assert_eq!(actions::recover_sitemap_index(&service).unwrap_err().code, "SITEMAP_FILES_MISSING");
+
+        service.store().write("post-sitemap.xml", "<urlset/>").unwrap();
+        service.store().write("post-sitemap2.xml", "<urlset/>").unwrap();
+        service.store().write("notes.xml", "<urlset/>").unwrap();
+
+        let result = actions::recover_sitemap_index(&service).unwrap();
+        assert_eq!(result.sitemaps_unchanged, 2);
+        assert!(result.warnings.is_empty());
+
+        let index = service.store().read(INDEX_FILENAME).unwrap();
+        assert!(index.contains("https://example.com/post-sitemap.xml"));
+        assert!(index.contains("https://example.com/post-sitemap2.xml"));
+        assert!(!index.contains("notes.xml"));
+    }
 }
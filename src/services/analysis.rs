@@ -2,19 +2,34 @@
 //!
 //! Service for analyzing content for SEO optimization.
 
+pub mod lsi;
+pub mod sentences;
+pub mod syllable;
+
 use crate::models::analysis::*;
-use chrono::Utc;
+use crate::models::meta::{ContentType, SeoMeta};
+use self::sentences::split_sentences;
+use crate::settings::{normalize_canonical_url, CanonicalStrategy, ContentContext, SeoSettings};
+use crate::services::schema::{SchemaError, SchemaService, SchemaWarning};
+use chrono::{DateTime, Datelike, Utc};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
 use uuid::Uuid;
 
 /// Service for SEO content analysis
+#[derive(Debug)]
 pub struct AnalysisService {
     settings: AnalysisSettings,
+    cache: Mutex<AnalysisCache>,
 }
 
 impl AnalysisService {
     pub fn new() -> Self {
         Self {
             settings: AnalysisSettings::default(),
+            cache: Mutex::new(AnalysisCache::default()),
         }
     }
 
@@ -23,9 +38,628 @@ impl AnalysisService {
         self
     }
 
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache = Mutex::new(AnalysisCache::new(ttl));
+        self
+    }
+
+    /// Service caching results as configured in the advanced settings
+    pub fn from_settings(settings: &SeoSettings) -> Self {
+        Self {
+            settings: AnalysisSettings::default(),
+            cache: Mutex::new(AnalysisCache::from_settings(settings)),
+        }
+    }
+
+    /// Service with thresholds adapted to the content type
+    pub fn for_content_type(content_type: &ContentType) -> Self {
+        Self::new().with_settings(AnalysisSettings::default_for_content_type(content_type))
+    }
+
+    /// Apply the new content grace period, if enabled in the settings
+    pub fn adjust_for_content_age(&self, analysis: &mut SeoAnalysis, days_since_publish: i64) {
+        if self.settings.apply_age_adjustments {
+            SeoAnalysis::apply_content_age_adjustment(analysis, days_since_publish);
+        }
+    }
+
+    /// Analyze content, reusing a cached result while it is fresh
+    pub fn analyze_cached(&self, content_id: Uuid, data: AnalysisInput) -> SeoAnalysis {
+        if let Some(analysis) = self.cached_analysis(content_id) {
+            return analysis;
+        }
+
+        let analysis = self.analyze(content_id, data);
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner).put(analysis.clone());
+        analysis
+    }
+
+    /// Get a fresh cached analysis
+    pub fn cached_analysis(&self, content_id: Uuid) -> Option<SeoAnalysis> {
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner).get(content_id).cloned()
+    }
+
+    /// Drop the cached analysis for a content item
+    pub fn invalidate_cache(&self, content_id: Uuid) {
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner).invalidate(content_id);
+    }
+
+    /// Drop all cached analyses
+    pub fn clear_cache(&self) {
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner).clear();
+    }
+
+    /// Power words and other click-through signals in a title
+    pub fn analyze_title_power_words(title: &str) -> TitlePowerAnalysis {
+        TitlePowerAnalysis::analyze(title)
+    }
+
+    /// Detect mobile usability problems in page HTML
+    pub fn detect_mobile_issues(html: &str) -> MobileIssueReport {
+        let mut report = MobileIssueReport {
+            has_viewport_meta: regex::Regex::new(r#"(?i)<meta[^>]+name\s*=\s*["']viewport["']"#)
+                .map(|re| re.is_match(html))
+                .unwrap_or(false),
+            ..Default::default()
+        };
+
+        let styles: Vec<String> = regex::Regex::new(r#"(?i)style\s*=\s*"([^"]*)""#)
+            .map(|re| {
+                re.captures_iter(html)
+                    .map(|c| c[1].to_lowercase())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let font_size = regex::Regex::new(r"font-size\s*:\s*(\d+(?:\.\d+)?)px").ok();
+        let width = regex::Regex::new(r"(?:^|[;\s])width\s*:\s*(\d+(?:\.\d+)?)px").ok();
+
+        for style in &styles {
+            if let Some(re) = &font_size {
+                report.has_small_font |= re.captures_iter(style)
+                    .filter_map(|c| c[1].parse::<f32>().ok())
+                    .any(|size| size < 12.0);
+            }
+            if let Some(re) = &width {
+                report.has_fixed_width_elements |= !style.contains("max-width")
+                    && re.captures_iter(style)
+                        .filter_map(|c| c[1].parse::<f32>().ok())
+                        .any(|px| px > 600.0);
+            }
+        }
+
+        report.has_horizontal_scroll_risk = regex::Regex::new(
+            r#"(?is)(<body[^>]*style\s*=\s*"[^"]*overflow(?:-x)?\s*:\s*hidden)|(\bbody\s*\{[^}]*overflow(?:-x)?\s*:\s*hidden)"#,
+        )
+        .map(|re| re.is_match(html))
+        .unwrap_or(false);
+
+        if !report.has_viewport_meta {
+            report.issues.push(AnalysisIssue::new(
+                IssueSeverity::Error,
+                "No viewport meta tag",
+                "Add <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\"> so the page scales on mobile devices.",
+            ));
+        }
+        if report.has_small_font {
+            report.issues.push(AnalysisIssue::new(
+                IssueSeverity::Warning,
+                "Font size too small",
+                "Text smaller than 12px is hard to read on mobile devices.",
+            ));
+        }
+        if report.has_fixed_width_elements {
+            report.issues.push(AnalysisIssue::new(
+                IssueSeverity::Warning,
+                "Fixed-width elements",
+                "Elements wider than 600px without a max-width overflow small screens.",
+            ));
+        }
+        if report.has_horizontal_scroll_risk {
+            report.issues.push(AnalysisIssue::new(
+                IssueSeverity::Warning,
+                "Body overflow hidden",
+                "overflow: hidden on the body hides content that doesn't fit the screen instead of reflowing it.",
+            ));
+        }
+
+        report
+    }
+
+    /// TF-IDF weight of a term in a document, or plain TF without a corpus
+    pub fn compute_tfidf(
+        term: &str,
+        document: &str,
+        corpus_frequencies: &HashMap<String, usize>,
+        corpus_size: usize,
+    ) -> f32 {
+        let words = tokenize(document);
+        let term_words = tokenize(term);
+        if words.is_empty() || term_words.is_empty() || term_words.len() > words.len() {
+            return 0.0;
+        }
+
+        let occurrences = words.windows(term_words.len())
+            .filter(|window| *window == term_words.as_slice())
+            .count();
+
+        tfidf_weight(occurrences, words.len(), &term_words.join(" "), corpus_frequencies, corpus_size)
+    }
+
+    /// Terms from the content ranked by TF-IDF against a corpus
+    pub fn suggest_related_keywords_by_tfidf(
+        content: &str,
+        corpus: &HashMap<String, usize>,
+        corpus_size: usize,
+        top_n: usize,
+    ) -> Vec<(String, f32)> {
+        let words = tokenize(content);
+
+        let mut occurrences: HashMap<&str, usize> = HashMap::new();
+        for word in &words {
+            let is_candidate = word.len() > 3
+                && !word.chars().all(|c| c.is_ascii_digit())
+                && !lsi::STOP_WORDS.contains(&word.as_str());
+            if is_candidate {
+                *occurrences.entry(word.as_str()).or_insert(0) += 1;
+            }
+        }
+
+        let mut scored: Vec<(String, f32)> = occurrences.into_iter()
+            .map(|(word, count)| (word.to_string(), tfidf_weight(count, words.len(), word, corpus, corpus_size)))
+            .collect();
+
+        scored.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal).then_with(|| a.0.cmp(&b.0))
+        });
+        scored.truncate(top_n);
+        scored
+    }
+
+    /// Top 10 pages that mention the target's keywords but don't link to it yet, best first
+    ///
+    /// `all_content` holds `(url, content_excerpt, existing_links)` tuples.
+    pub fn find_internal_linking_opportunities(
+        target_url: &str,
+        target_keywords: &[String],
+        all_content: &[(String, String, Vec<String>)],
+    ) -> Vec<LinkingOpportunity> {
+        let normalize = |url: &str| url.trim_end_matches('/').to_lowercase();
+        let target = normalize(target_url);
+
+        let keywords: Vec<String> = target_keywords.iter()
+            .map(|k| k.trim().to_lowercase())
+            .filter(|k| !k.is_empty())
+            .collect();
+        if keywords.is_empty() {
+            return vec![];
+        }
+
+        let mut opportunities: Vec<LinkingOpportunity> = all_content.iter()
+            .filter(|(url, _, links)| {
+                normalize(url) != target && !links.iter().any(|link| normalize(link) == target)
+            })
+            .filter_map(|(url, excerpt, _)| {
+                let lower = excerpt.to_lowercase();
+                let matched: Vec<&String> = keywords.iter().filter(|k| lower.contains(k.as_str())).collect();
+                let keyword = matched.first()?;
+
+                let sentence = split_sentences(excerpt)
+                    .into_iter()
+                    .find(|sentence| sentence.to_lowercase().contains(keyword.as_str()))?;
+
+                // Anchor text keeps the casing used on the source page
+                let start = sentence.to_lowercase().find(keyword.as_str())?;
+                let anchor = sentence.get(start..start + keyword.len()).unwrap_or(keyword);
+
+                let mentions: usize = matched.iter().map(|k| lower.matches(k.as_str()).count()).sum();
+                let coverage = matched.len() as f32 / keywords.len() as f32;
+                let frequency = mentions.min(5) as f32 / 5.0;
+
+                Some(LinkingOpportunity {
+                    source_url: url.clone(),
+                    suggested_anchor: anchor.to_string(),
+                    context_sentence: sentence.to_string(),
+                    relevance_score: coverage * 0.7 + frequency * 0.3,
+                })
+            })
+            .collect();
+
+        opportunities.sort_by(|a, b| {
+            b.relevance_score.partial_cmp(&a.relevance_score).unwrap_or(std::cmp::Ordering::Equal)
+        });
+        opportunities.truncate(10);
+        opportunities
+    }
+
+    /// Pages with no incoming internal links, shortest URL first
+    ///
+    /// `link_graph` maps each source URL to the URLs it links to; self-links don't count.
+    pub fn detect_orphaned_pages(all_urls: &[String], link_graph: &HashMap<String, Vec<String>>) -> Vec<String> {
+        let normalize = |url: &str| url.trim_end_matches('/').to_lowercase();
+
+        let linked: HashSet<String> = link_graph.iter()
+            .flat_map(|(source, targets)| {
+                let source = normalize(source);
+                targets.iter().map(|t| normalize(t)).filter(move |t| *t != source)
+            })
+            .collect();
+
+        let mut seen = HashSet::new();
+        let mut orphaned: Vec<String> = all_urls.iter()
+            .filter(|url| !linked.contains(&normalize(url)) && seen.insert(normalize(url)))
+            .cloned()
+            .collect();
+
+        orphaned.sort_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+        orphaned
+    }
+
+    /// Experience, expertise, authority and trust signals in HTML content
+    pub fn analyze_eeat_signals(
+        content: &str,
+        author_profile: Option<&AuthorProfile>,
+        has_citations: bool,
+    ) -> EeatSignalAnalysis {
+        let mut analysis = EeatSignalAnalysis::default();
+        let mut score = 0;
+
+        match author_profile {
+            Some(author) if author.bio.as_deref().is_some_and(|bio| !bio.trim().is_empty()) => {
+                score += 25;
+                analysis.authority_signals.push(format!("Author bio present for {}", author.name));
+                if !author.credentials.is_empty() {
+                    analysis.authority_signals.push(format!("Author credentials: {}", author.credentials.join(", ")));
+                }
+            }
+            Some(_) => analysis.issues.push(AnalysisIssue::new(
+                IssueSeverity::Warning,
+                "Author bio missing",
+                "Add a short bio that explains the author's experience with the topic.",
+            )),
+            None => analysis.issues.push(AnalysisIssue::new(
+                IssueSeverity::Warning,
+                "No author attribution",
+                "Attribute the content to a named author with a bio.",
+            )),
+        }
+
+        let authoritative_links: HashSet<String> = regex::Regex::new(
+            r#"(?i)href\s*=\s*["']https?://(?:[a-z0-9-]+\.)*([a-z0-9-]+\.(?:gov|edu|org))(?:[/:"'?#]|$)"#,
+        )
+        .map(|re| re.captures_iter(content).map(|c| c[1].to_lowercase()).collect())
+        .unwrap_or_default();
+
+        if !authoritative_links.is_empty() {
+            score += 25;
+            let mut domains: Vec<String> = authoritative_links.into_iter().collect();
+            domains.sort();
+            analysis.authority_signals.push(format!("Cites authoritative sources: {}", domains.join(", ")));
+        } else if has_citations {
+            score += 15;
+            analysis.authority_signals.push("Cites external sources".to_string());
+        } else {
+            analysis.issues.push(AnalysisIssue::new(
+                IssueSeverity::Info,
+                "No citations",
+                "Link to primary sources such as .gov, .edu or .org sites to back up claims.",
+            ));
+        }
+
+        let has_publication_date = regex::Regex::new(
+            r#"(?i)<time[^>]+datetime\s*=|datePublished|\b(?:published|updated|last modified)\s*(?:on|:)"#,
+        )
+        .is_ok_and(|re| re.is_match(content));
+        if has_publication_date {
+            score += 15;
+            analysis.trust_signals.push("Publication date present".to_string());
+        } else {
+            analysis.issues.push(AnalysisIssue::new(
+                IssueSeverity::Warning,
+                "No publication date",
+                "Show when the content was published or last updated.",
+            ));
+        }
+
+        let has_reviewer = regex::Regex::new(r"(?i)\b(?:medically |fact[- ])?(?:reviewed|edited|fact[- ]checked) by\b")
+            .is_ok_and(|re| re.is_match(content));
+        if has_reviewer {
+            score += 15;
+            analysis.trust_signals.push("Reviewer or editor byline present".to_string());
+        }
+
+        let word_count = regex::Regex::new(r"<[^>]*>")
+            .map(|re| re.replace_all(content, " ").split_whitespace().count())
+            .unwrap_or_else(|_| content.split_whitespace().count());
+        if word_count > 1500 {
+            score += 20;
+            analysis.authority_signals.push(format!("In-depth coverage ({} words)", word_count));
+        }
+
+        analysis.expertise_score = score.min(100);
+        analysis
+    }
+
+    /// Freshness signals: current-year mentions, update phrases, dated headings and recent sources
+    pub fn analyze_freshness_signals(content: &str, external_links: &[ExternalLinkInput]) -> FreshnessSignalReport {
+        let now = Utc::now();
+        let current_year = now.year();
+        let mut report = FreshnessSignalReport::default();
+        let mut adjustment = 0;
+
+        let text = regex::Regex::new(r"<[^>]*>")
+            .map(|re| re.replace_all(content, " ").into_owned())
+            .unwrap_or_else(|_| content.to_string());
+
+        let years: Vec<i32> = regex::Regex::new(r"\b(?:19|20)\d{2}\b")
+            .map(|re| re.find_iter(&text).filter_map(|m| m.as_str().parse().ok()).collect())
+            .unwrap_or_default();
+        report.current_year_mentioned = years.contains(&current_year);
+        if report.current_year_mentioned {
+            adjustment += 5;
+        }
+
+        if let Ok(re) = regex::Regex::new(r"(?i)\b(last updated|updated|revised|new for)\b") {
+            for m in re.find_iter(&text) {
+                let phrase = m.as_str().to_lowercase();
+                if !report.freshness_phrases.contains(&phrase) {
+                    report.freshness_phrases.push(phrase);
+                }
+            }
+        }
+        adjustment += (2 * report.freshness_phrases.len() as i32).min(6);
+
+        // A month name only counts next to a day, so "you may" is not a date
+        let months = "january|february|march|april|may|june|july|august|september|october|november|december";
+        let dated_heading = regex::Regex::new(&format!(
+            r"(?i)\b(?:19|20)\d{{2}}\b|\b(?:{m})\s+\d{{1,2}}(?:st|nd|rd|th)?\b|\b\d{{1,2}}(?:st|nd|rd|th)?\s+(?:{m})\b",
+            m = months,
+        ));
+        let headings = regex::Regex::new(r"(?is)<h[1-6][^>]*>(.*?)</h[1-6]>|(?m)^#{1,6}[ \t]+([^\n]+)$");
+        if let (Ok(dated_heading), Ok(headings)) = (dated_heading, headings) {
+            let dated: Vec<String> = headings.captures_iter(content)
+                .filter_map(|c| c.get(1).or_else(|| c.get(2)))
+                .map(|m| m.as_str().trim().to_string())
+                .filter(|heading| dated_heading.is_match(heading))
+                .collect();
+            if !dated.is_empty() {
+                adjustment += 3;
+            }
+            report.freshness_phrases.extend(dated);
+        }
+
+        let recent_links = external_links.iter()
+            .filter_map(|link| link.link_date)
+            .filter(|date| now.signed_duration_since(*date).num_days() <= 365)
+            .count() as i32;
+        adjustment += (2 * recent_links).min(4);
+
+        // Only old years mentioned and nothing suggesting an update
+        if adjustment == 0 && years.iter().max().is_some_and(|year| *year < current_year - 1) {
+            adjustment = -5;
+        }
+
+        report.score_adjustment = adjustment;
+        report
+    }
+
+    /// Compare a page's canonical URL with its own URL under the site's canonical strategy
+    pub fn analyze_canonical_consistency(
+        canonical_url: &str,
+        page_url: &str,
+        strategy: &CanonicalStrategy,
+    ) -> Vec<AnalysisIssue> {
+        let mut issues = Vec::new();
+        let canonical = normalize_canonical_url(canonical_url, strategy);
+        let page = normalize_canonical_url(page_url, strategy);
+
+        if canonical != canonical_url.trim() {
+            issues.push(AnalysisIssue::new(
+                IssueSeverity::Warning,
+                "Canonical URL doesn't match the canonical strategy",
+                &format!("Use {} as the canonical URL.", canonical),
+            ));
+        }
+
+        if !canonical.eq_ignore_ascii_case(&page) {
+            issues.push(AnalysisIssue::new(
+                IssueSeverity::Info,
+                "Canonical URL points to another page",
+                &format!("This page will be treated as a duplicate of {}.", canonical),
+            ));
+        }
+
+        issues
+    }
+
+    /// Score history points in chronological order
+    pub fn score_history(analyses: &[(DateTime<Utc>, SeoAnalysis)]) -> Vec<ScoreHistoryPoint> {
+        let mut points: Vec<ScoreHistoryPoint> = analyses.iter()
+            .map(|(date, analysis)| ScoreHistoryPoint {
+                date: *date,
+                overall: analysis.overall_score.score,
+                title: analysis.title_analysis.score,
+                meta: analysis.meta_analysis.score,
+                content: analysis.content_analysis.score,
+                keyword: analysis.keyword_analysis.score,
+            })
+            .collect();
+        points.sort_by_key(|p| p.date);
+        points
+    }
+
+    /// Trailing moving average of the overall score
+    pub fn moving_average(points: &[ScoreHistoryPoint], window: usize) -> Vec<f32> {
+        let window = window.max(1);
+        (0..points.len())
+            .map(|i| {
+                let start = (i + 1).saturating_sub(window);
+                let slice = &points[start..=i];
+                slice.iter().map(|p| p.overall as f32).sum::<f32>() / slice.len() as f32
+            })
+            .collect()
+    }
+
+    /// Overall score trend from the regression slope of the latest points
+    pub fn score_trend(points: &[ScoreHistoryPoint]) -> ScoreTrend {
+        const TREND_WINDOW: usize = 10;
+        const STABLE_SLOPE: f32 = 0.5;
+
+        let recent = &points[points.len().saturating_sub(TREND_WINDOW)..];
+        if recent.len() < 2 {
+            return ScoreTrend::Stable;
+        }
+
+        let n = recent.len() as f32;
+        let mean_x = (n - 1.0) / 2.0;
+        let mean_y = recent.iter().map(|p| p.overall as f32).sum::<f32>() / n;
+        let (covariance, variance) = recent.iter().enumerate()
+            .fold((0.0, 0.0), |(cov, var), (i, p)| {
+                let dx = i as f32 - mean_x;
+                (cov + dx * (p.overall as f32 - mean_y), var + dx * dx)
+            });
+        let slope = covariance / variance;
+
+        if slope >= STABLE_SLOPE {
+            ScoreTrend::Improving(slope)
+        } else if slope <= -STABLE_SLOPE {
+            ScoreTrend::Declining(slope)
+        } else {
+            ScoreTrend::Stable
+        }
+    }
+
+    /// Heuristic named entity extraction, top 10 most mentioned first
+    ///
+    /// Runs of capitalized words that don't start a sentence are candidates;
+    /// a small gazetteer of places, companies and products classifies them.
+    pub fn extract_entities(content: &str) -> Vec<NamedEntity> {
+        const HONORIFICS: &[&str] = &["mr", "mrs", "ms", "dr", "prof", "sir"];
+        const ORGANIZATION_SUFFIXES: &[&str] = &[
+            "inc", "ltd", "llc", "corp", "corporation", "company", "co", "gmbh", "university",
+            "foundation", "institute", "group",
+        ];
+
+        let Ok(candidate) = regex::Regex::new(r"\b[A-Z][\w'&-]*(?:\s+[A-Z][\w'&-]*)*") else {
+            return vec![];
+        };
+
+        let mut entities: Vec<NamedEntity> = Vec::new();
+        for m in candidate.find_iter(content) {
+            let before = content[..m.start()].trim_end();
+            let preceding_word = before
+                .rsplit(|c: char| c.is_whitespace())
+                .next()
+                .unwrap_or_default()
+                .trim_end_matches('.')
+                .to_lowercase();
+            let sentence_start = before.is_empty() || before.ends_with(['.', '!', '?', ':', '>', '"', '\n']);
+            let after_honorific = HONORIFICS.contains(&preceding_word.as_str());
+
+            let mut text = m.as_str();
+            let mut start = m.start();
+            let classified = classify_entity(text);
+            if sentence_start && !after_honorific && classified.is_none() {
+                // The first word is capitalized because it opens the sentence
+                match text.split_once(char::is_whitespace) {
+                    Some((_, rest)) => {
+                        let rest = rest.trim_start();
+                        start += text.len() - rest.len();
+                        text = rest;
+                    }
+                    None => continue,
+                }
+            }
+
+            let lower = text.to_lowercase();
+            if ENTITY_STOPWORDS.contains(&lower.as_str()) || HONORIFICS.contains(&lower.as_str()) || text.len() < 2 {
+                continue;
+            }
+
+            let last_word = lower.rsplit(' ').next().unwrap_or_default().trim_end_matches('.');
+            let entity_type = if after_honorific {
+                EntityType::Person
+            } else if let Some(entity_type) = classify_entity(text) {
+                entity_type
+            } else if ORGANIZATION_SUFFIXES.contains(&last_word) {
+                EntityType::Organization
+            } else {
+                EntityType::Unknown
+            };
+
+            match entities.iter_mut().find(|e| e.text == text) {
+                Some(entity) => {
+                    entity.count += 1;
+                    entity.start_positions.push(start);
+                }
+                None => entities.push(NamedEntity {
+                    text: text.to_string(),
+                    entity_type,
+                    count: 1,
+                    start_positions: vec![start],
+                }),
+            }
+        }
+
+        entities.sort_by(|a, b| b.count.cmp(&a.count).then(a.start_positions[0].cmp(&b.start_positions[0])));
+        entities.truncate(10);
+        entities
+    }
+
+    /// Whether a sentence looks like it's written in passive voice
+    pub fn is_passive_sentence(sentence: &str) -> bool {
+        let lower = format!("{} ", sentence.to_lowercase());
+        PASSIVE_PATTERNS.iter().any(|p| lower.contains(p))
+    }
+
+    /// Full sentences containing a passive construction
+    ///
+    /// Line breaks inside a sentence are collapsed; blank lines end a sentence.
+    pub fn extract_passive_sentences(content: &str) -> Vec<String> {
+        split_sentences(content)
+            .into_iter()
+            .filter(|sentence| Self::is_passive_sentence(sentence))
+            .map(|sentence| sentence.split_whitespace().collect::<Vec<_>>().join(" "))
+            .collect()
+    }
+
+    /// Dependent clause complexity of a single sentence
+    pub fn analyze_sentence_complexity(sentence: &str) -> SentenceComplexity {
+        const CLAUSE_INDICATORS: &[&str] = &[
+            "because", "although", "while", "when", "if", "since", "unless",
+            "who", "which", "that",
+        ];
+
+        let mut clause_count = 0;
+        let mut has_nested_clauses = false;
+
+        // Indicators sharing a comma-free stretch are nested in one another
+        for segment in sentence.split([',', ';', ':', '(', ')']) {
+            let indicators = segment
+                .split_whitespace()
+                .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric()).to_lowercase())
+                .filter(|w| CLAUSE_INDICATORS.contains(&w.as_str()))
+                .count();
+
+            clause_count += indicators;
+            has_nested_clauses |= indicators >= 2;
+        }
+
+        SentenceComplexity {
+            clause_count,
+            has_nested_clauses,
+            complexity_score: clause_count as f32 * 0.5,
+        }
+    }
+
+    /// Minimum word count expected for a content type
+    pub fn adaptive_min_word_count(content_type: &ContentType) -> usize {
+        AnalysisSettings::adaptive_min_word_count(content_type)
+    }
+
     /// Perform complete SEO analysis
     pub fn analyze(&self, content_id: Uuid, data: AnalysisInput) -> SeoAnalysis {
-        let title_analysis = TitleAnalysis::analyze(
+        let mut title_analysis = TitleAnalysis::analyze(
             &data.title,
             data.focus_keyword.as_deref(),
         );
@@ -35,65 +669,305 @@ impl AnalysisService {
             data.focus_keyword.as_deref(),
         );
 
-        let content_analysis = ContentAnalysis::analyze(
-            &data.content,
-            self.settings.min_word_count,
-        );
+        let min_word_count = self.settings.min_word_count_for(data.is_cornerstone);
+        let mut content_analysis = ContentAnalysis::analyze(&data.content, min_word_count);
+        if data.is_cornerstone {
+            Self::apply_cornerstone_rules(&data, &mut title_analysis, &mut content_analysis);
+        }
 
-        let keyword_analysis = self.analyze_keywords(&data);
+        let mut keyword_analysis = self.analyze_keywords(&data, data.focus_keyword.as_deref());
+        if let Some(keyword) = &data.focus_keyword {
+            keyword_analysis.related_keywords = lsi::extract_related_keywords(&data.content, keyword);
+        }
+        let secondary_keyword_analyses = data.secondary_keywords.iter()
+            .filter(|keyword| !keyword.trim().is_empty())
+            .map(|keyword| self.analyze_keywords(&data, Some(keyword)))
+            .collect();
         let readability_analysis = self.analyze_readability(&data.content);
         let link_analysis = self.analyze_links(&data);
         let image_analysis = self.analyze_images(&data);
         let technical_analysis = self.analyze_technical(&data);
 
-        // Calculate overall score
-        let scores = [
-            title_analysis.score,
-            meta_analysis.score,
-            content_analysis.score,
-            keyword_analysis.score,
-            readability_analysis.score,
-            link_analysis.score,
-            image_analysis.score,
-            technical_analysis.score,
-        ];
-
-        let overall_score = SeoScore::new(
-            scores.iter().sum::<i32>() / scores.len() as i32
+        let target_keywords: Vec<String> = data.focus_keyword.iter()
+            .chain(&data.secondary_keywords)
+            .cloned()
+            .collect();
+        let internal_link_opportunities = Self::find_internal_linking_opportunities(
+            &data.url,
+            &target_keywords,
+            &data.linking_candidates,
         );
+        let detected_entities = Self::extract_entities(&data.content);
+        let freshness_signals = Self::analyze_freshness_signals(&data.content, &data.outbound_links);
+        let eeat_signals = Self::analyze_eeat_signals(&data.content, data.author.as_ref(), data.external_links > 0);
+        content_analysis.score = (content_analysis.score + freshness_signals.score_adjustment).clamp(0, 100);
+        let paragraphs: Vec<String> = data.content.split("\n\n")
+            .map(|p| p.trim())
+            .filter(|p| !p.is_empty() && !p.starts_with('#'))
+            .map(str::to_string)
+            .collect();
+        let passage_analysis = self.analyze_passage_potential(&paragraphs, data.focus_keyword.as_deref());
 
         // Generate suggestions
-        let suggestions = self.generate_suggestions(
-            &title_analysis,
-            &meta_analysis,
-            &content_analysis,
-            &keyword_analysis,
+        let suggestions = Self::prioritize_suggestions(
+            self.generate_suggestions(
+                &title_analysis,
+                &meta_analysis,
+                &content_analysis,
+                &keyword_analysis,
+                min_word_count,
+            ),
+            10,
         );
 
-        SeoAnalysis {
+        let mut analysis = SeoAnalysis {
             id: Uuid::now_v7(),
             content_id,
-            overall_score,
+            overall_score: SeoScore::new(0),
             title_analysis,
             meta_analysis,
             content_analysis,
             keyword_analysis,
+            secondary_keyword_analyses,
             readability_analysis,
             link_analysis,
             image_analysis,
             technical_analysis,
+            internal_link_opportunities,
+            detected_entities,
+            freshness_signals,
+            eeat_signals,
+            passage_analysis,
             suggestions,
             analyzed_at: Utc::now(),
+        };
+        analysis.overall_score = analysis.calculate_overall_score();
+        analysis
+    }
+
+    /// Stricter heading and title rules for cornerstone content
+    fn apply_cornerstone_rules(
+        data: &AnalysisInput,
+        title: &mut TitleAnalysis,
+        content: &mut ContentAnalysis,
+    ) {
+        let has_h2 = collect_headings(data).iter().any(|(level, _)| *level == 2)
+            || data.content.to_lowercase().contains("<h2");
+        if !has_h2 {
+            content.issues.push(AnalysisIssue::new(
+                IssueSeverity::Error,
+                "Cornerstone content has no subheadings",
+                "Add at least one H2 subheading to structure your cornerstone content.",
+            ));
+            content.score = (content.score - 15).max(0);
+        }
+
+        if let Some(pos) = title.keyword_position.filter(|pos| *pos > 5) {
+            title.issues.push(AnalysisIssue::new(
+                IssueSeverity::Error,
+                "Keyword not at start of cornerstone title",
+                &format!(
+                    "The focus keyword starts at character {}. Cornerstone titles should open with it.",
+                    pos
+                ),
+            ));
+            title.score = (title.score - 15).max(0);
+        }
+    }
+
+    /// Whether running a full analysis is useful for this content
+    ///
+    /// Search result pages are never analyzed; date archives are skipped when they are noindexed.
+    pub fn is_analysis_worthwhile(meta: &SeoMeta, context: &ContentContext, settings: &SeoSettings) -> bool {
+        if !meta.robots.index || context.is_search {
+            return false;
+        }
+
+        !(context.is_archive && settings.meta.noindex_archives)
+    }
+
+    /// Sort suggestions by priority and impact, keeping the top `max`
+    pub fn prioritize_suggestions(mut suggestions: Vec<SeoSuggestion>, max: usize) -> Vec<SeoSuggestion> {
+        fn priority_rank(priority: SuggestionPriority) -> u8 {
+            match priority {
+                SuggestionPriority::High => 0,
+                SuggestionPriority::Medium => 1,
+                SuggestionPriority::Low => 2,
+            }
+        }
+
+        fn impact_rank(category: &str) -> u8 {
+            match category {
+                "Title" => 0,
+                "Meta Description" => 1,
+                "Keywords" => 2,
+                "Content" => 3,
+                _ => 4,
+            }
+        }
+
+        suggestions.sort_by_key(|s| (priority_rank(s.priority), impact_rank(&s.category)));
+        suggestions.truncate(max);
+        suggestions
+    }
+
+    /// Score paragraphs as standalone passages for passage indexing
+    pub fn analyze_passage_potential(
+        &self,
+        paragraphs: &[String],
+        focus_keyword: Option<&str>,
+    ) -> PassageAnalysis {
+        let kw_lower = focus_keyword.map(|k| k.to_lowercase());
+        let context_refs = [
+            "above", "below", "as mentioned", "previously", "earlier", "aforementioned",
+        ];
+
+        let mut passages: Vec<PassageScore> = paragraphs.iter()
+            .map(|p| p.trim())
+            .filter(|p| !p.is_empty())
+            .map(|text| {
+                let lower = text.to_lowercase();
+                let word_count = text.split_whitespace().count();
+                let has_keyword = kw_lower.as_ref()
+                    .map(|kw| lower.contains(kw.as_str()))
+                    .unwrap_or(false);
+
+                // Ideal passage length is 40-300 words
+                let length_score = if (40..=300).contains(&word_count) {
+                    40.0
+                } else if word_count < 40 {
+                    40.0 * word_count as f32 / 40.0
+                } else {
+                    20.0
+                };
+
+                // Passages must make sense without the surrounding content
+                let words: Vec<&str> = lower
+                    .split(|c: char| !c.is_alphanumeric())
+                    .filter(|w| !w.is_empty())
+                    .collect();
+                let self_contained = !context_refs.iter().any(|r| {
+                    let phrase: Vec<&str> = r.split(' ').collect();
+                    words.windows(phrase.len()).any(|w| w == phrase.as_slice())
+                });
+                let context_score = if self_contained { 30.0 } else { 0.0 };
+
+                let keyword_score = if has_keyword { 30.0 } else { 0.0 };
+
+                PassageScore {
+                    text: text.to_string(),
+                    score: length_score + context_score + keyword_score,
+                    word_count,
+                    has_keyword,
+                }
+            })
+            .collect();
+
+        let total_passages = passages.len();
+        let avg_passage_score = if total_passages > 0 {
+            passages.iter().map(|p| p.score).sum::<f32>() / total_passages as f32
+        } else {
+            0.0
+        };
+
+        passages.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+        passages.truncate(3);
+
+        PassageAnalysis {
+            top_passages: passages,
+            avg_passage_score,
+            total_passages,
+        }
+    }
+
+    /// Analyze where the focus keyword appears within headings
+    pub fn analyze_heading_keyword_placement(
+        &self,
+        headings: &[(u8, String)],
+        focus_keyword: &str,
+    ) -> HeadingKeywordPlacement {
+        let kw_stems: Vec<String> = focus_keyword.split_whitespace().map(stem_word).collect();
+        let mut placement = HeadingKeywordPlacement::default();
+
+        if kw_stems.is_empty() {
+            return placement;
+        }
+
+        // (level, position) of the best match so far
+        let mut best: Option<(u8, usize)> = None;
+
+        for (level, text) in headings {
+            let stems: Vec<String> = text.split_whitespace().map(stem_word).collect();
+            let position = stems.windows(kw_stems.len())
+                .position(|w| w == kw_stems.as_slice());
+
+            match position {
+                Some(pos) => {
+                    if *level == 1 {
+                        if !placement.in_h1 {
+                            placement.h1_keyword_position = Some(pos);
+                        }
+                        placement.in_h1 = true;
+                    } else if *level == 2 {
+                        placement.in_h2 = true;
+                    }
+
+                    let is_better = best
+                        .map(|(l, p)| (*level, pos) < (l, p))
+                        .unwrap_or(true);
+                    if is_better {
+                        best = Some((*level, pos));
+                        placement.best_heading_match = Some(text.clone());
+                    }
+                }
+                None => {
+                    if kw_stems.iter().any(|k| stems.contains(k)) {
+                        placement.partial_matches += 1;
+                    }
+                }
+            }
+        }
+
+        placement
+    }
+
+    /// Validate structured data and collect errors per schema type
+    pub fn analyze_schema_errors(&self, schemas: &[Value]) -> SchemaErrorReport {
+        let mut errors = Vec::new();
+        let mut warnings = Vec::new();
+        let mut seen_errors = HashSet::new();
+        let mut seen_warnings = HashSet::new();
+
+        for schema in schemas {
+            for (schema_type, result) in SchemaService::validate_schema_nodes(schema) {
+                for error in result.errors {
+                    if seen_errors.insert((schema_type.clone(), error.path.clone())) {
+                        errors.push((schema_type.clone(), error));
+                    }
+                }
+                for warning in result.warnings {
+                    if seen_warnings.insert((schema_type.clone(), warning.path.clone())) {
+                        warnings.push((schema_type.clone(), warning));
+                    }
+                }
+            }
+        }
+
+        SchemaErrorReport {
+            error_count: errors.len(),
+            warning_count: warnings.len(),
+            errors,
+            warnings,
         }
     }
 
     /// Analyze keyword usage
-    fn analyze_keywords(&self, data: &AnalysisInput) -> KeywordAnalysis {
+    fn analyze_keywords(&self, data: &AnalysisInput, focus_keyword: Option<&str>) -> KeywordAnalysis {
         let mut issues = Vec::new();
         let mut score = 100;
 
-        let (keyword, count, density, in_first, in_headings, in_url) =
-            if let Some(kw) = &data.focus_keyword {
+        let (keyword, count, density, in_first, in_headings, heading_placement, in_url, prominence) =
+            if let Some(kw) = focus_keyword {
                 let content_lower = data.content.to_lowercase();
                 let kw_lower = kw.to_lowercase();
 
@@ -110,13 +984,26 @@ impl AnalysisService {
                 let in_first = first_para.to_lowercase().contains(&kw_lower);
 
                 // Check if in headings
-                let in_headings = data.headings.iter()
-                    .any(|h| h.to_lowercase().contains(&kw_lower));
+                let heading_placement = self.analyze_heading_keyword_placement(
+                    &collect_headings(data),
+                    kw,
+                );
+                let in_headings = heading_placement.in_h1 || heading_placement.in_h2
+                    || data.headings.iter().any(|h| h.to_lowercase().contains(&kw_lower));
 
                 // Check if in URL
                 let in_url = data.url.to_lowercase().contains(&kw_lower);
 
-                // Issues
+                // Prominence: first match offset as a fraction of the content length
+                let prominence = content_lower.find(&kw_lower)
+                    .map(|offset| offset as f32 / content_lower.len().max(1) as f32);
+
+                // Issues; cornerstone content treats density problems as errors
+                let density_severity = if data.is_cornerstone {
+                    IssueSeverity::Error
+                } else {
+                    IssueSeverity::Warning
+                };
                 if kw_count == 0 {
                     issues.push(AnalysisIssue::new(
                         IssueSeverity::Error,
@@ -126,14 +1013,14 @@ impl AnalysisService {
                     score -= 30;
                 } else if kw_density < self.settings.target_keyword_density * 0.5 {
                     issues.push(AnalysisIssue::new(
-                        IssueSeverity::Warning,
+                        density_severity,
                         "Keyword density too low",
                         "Consider using your focus keyword more often.",
                     ));
                     score -= 15;
                 } else if kw_density > self.settings.max_keyword_density {
                     issues.push(AnalysisIssue::new(
-                        IssueSeverity::Warning,
+                        density_severity,
                         "Keyword density too high",
                         "You may be over-optimizing. Use the keyword more naturally.",
                     ));
@@ -156,6 +1043,23 @@ impl AnalysisService {
                         "Consider adding the keyword to at least one subheading.",
                     ));
                     score -= 5;
+                } else if heading_placement.best_heading_match.is_some()
+                    && !heading_placement.in_h1
+                    && data.content.lines().any(|l| l.trim().starts_with("# "))
+                {
+                    issues.push(AnalysisIssue::new(
+                        IssueSeverity::Warning,
+                        "Keyword not in H1",
+                        "Include your focus keyword in the main H1 heading.",
+                    ));
+                    score -= 5;
+                } else if heading_placement.h1_keyword_position.map(|p| p >= 3).unwrap_or(false) {
+                    issues.push(AnalysisIssue::new(
+                        IssueSeverity::Info,
+                        "Keyword late in H1",
+                        "Move the focus keyword closer to the start of the H1.",
+                    ));
+                    score -= 2;
                 }
 
                 if !in_url {
@@ -167,7 +1071,24 @@ impl AnalysisService {
                     score -= 5;
                 }
 
-                (Some(kw.clone()), kw_count, kw_density, in_first, in_headings, in_url)
+                match prominence {
+                    Some(p) if p < 0.1 => issues.push(AnalysisIssue::new(
+                        IssueSeverity::Info,
+                        "Keyword appears early",
+                        "Your focus keyword shows up near the start of the content. Well done!",
+                    )),
+                    Some(p) if p > 0.5 => {
+                        issues.push(AnalysisIssue::new(
+                            IssueSeverity::Warning,
+                            "Keyword appears late",
+                            "The focus keyword first appears in the second half of the content. Mention it sooner.",
+                        ));
+                        score -= 10;
+                    }
+                    _ => {}
+                }
+
+                (Some(kw.to_string()), kw_count, kw_density, in_first, in_headings, Some(heading_placement), in_url, prominence)
             } else {
                 issues.push(AnalysisIssue::new(
                     IssueSeverity::Warning,
@@ -175,7 +1096,7 @@ impl AnalysisService {
                     "Set a focus keyword to optimize your content.",
                 ));
                 score = 50;
-                (None, 0, 0.0, false, false, false)
+                (None, 0, 0.0, false, false, None, false, None)
             };
 
         KeywordAnalysis {
@@ -185,21 +1106,24 @@ impl AnalysisService {
             keyword_density: density,
             in_first_paragraph: in_first,
             in_headings,
+            heading_placement,
             in_url,
+            keyword_prominence: prominence,
+            related_keywords: vec![],
             issues,
         }
     }
 
     /// Analyze readability
-    fn analyze_readability(&self, content: &str) -> ReadabilityAnalysis {
+    pub fn analyze_readability(&self, content: &str) -> ReadabilityAnalysis {
         let mut issues = Vec::new();
         let mut score = 100;
 
         let words: Vec<&str> = content.split_whitespace().collect();
         let word_count = words.len();
 
-        // Count sentences
-        let sentence_count = content.matches(|c| c == '.' || c == '!' || c == '?').count().max(1);
+        let sentences = split_sentences(content);
+        let sentence_count = sentences.len().max(1);
 
         // Average sentence length
         let avg_sentence = word_count as f32 / sentence_count as f32;
@@ -217,8 +1141,10 @@ impl AnalysisService {
         let total_chars: usize = words.iter().map(|w| w.len()).sum();
         let avg_word = total_chars as f32 / word_count.max(1) as f32;
 
-        // Simple Flesch Reading Ease approximation
-        let flesch = 206.835 - (1.015 * avg_sentence) - (84.6 * (avg_word / 5.0));
+        // Flesch Reading Ease
+        let syllable_total: usize = words.iter().map(|w| syllable::count_syllables(w)).sum();
+        let syllables_per_word = syllable_total as f32 / word_count.max(1) as f32;
+        let flesch = 206.835 - (1.015 * avg_sentence) - (84.6 * syllables_per_word);
 
         if flesch < 30.0 {
             issues.push(AnalysisIssue::new(
@@ -237,11 +1163,33 @@ impl AnalysisService {
         }
 
         // Flesch-Kincaid Grade Level
-        let grade = 0.39 * avg_sentence + 11.8 * (avg_word / 5.0) - 15.59;
+        let grade = 0.39 * avg_sentence + 11.8 * syllables_per_word - 15.59;
+
+        // Coleman-Liau and SMOG grade levels
+        let letter_count = content.chars().filter(|c| c.is_alphabetic()).count();
+        let polysyllable_count = words.iter().filter(|w| syllable::count_syllables(w) >= 3).count();
+        let coleman_liau = Self::coleman_liau_index(letter_count, word_count, sentence_count);
+        let smog = Self::smog_grade(polysyllable_count, sentence_count);
+
+        let target_grade = self.settings.target_grade_level as f32;
+        if sentence_count >= 3 {
+            for (name, value) in [("Coleman-Liau index", coleman_liau), ("SMOG grade", smog)] {
+                if value > target_grade {
+                    issues.push(AnalysisIssue::new(
+                        IssueSeverity::Suggestion,
+                        &format!("{} above target grade", name),
+                        &format!(
+                            "The {} is {:.1}, above the target grade level of {}. Use shorter words and sentences.",
+                            name, value, self.settings.target_grade_level
+                        ),
+                    ));
+                    score -= 5;
+                }
+            }
+        }
 
         // Passive voice detection (simple heuristic)
-        let passive_patterns = ["was ", "were ", "been ", "being ", "is being", "are being"];
-        let passive_count: usize = passive_patterns.iter()
+        let passive_count: usize = PASSIVE_PATTERNS.iter()
             .map(|p| content.to_lowercase().matches(p).count())
             .sum();
         let passive_pct = (passive_count as f32 / sentence_count as f32) * 100.0;
@@ -255,12 +1203,17 @@ impl AnalysisService {
             score -= 5;
         }
 
-        // Transition words (simple check)
+        // Transition words: share of sentences that open with one
         let transitions = ["however", "therefore", "moreover", "furthermore", "additionally",
             "consequently", "meanwhile", "nevertheless", "also", "first", "second", "finally"];
-        let transition_count: usize = transitions.iter()
-            .map(|t| content.to_lowercase().matches(t).count())
-            .sum();
+        let transition_count = sentences.iter()
+            .filter(|sentence| {
+                let first_word = sentence.split_whitespace().next().unwrap_or_default()
+                    .trim_matches(|c: char| !c.is_alphanumeric())
+                    .to_lowercase();
+                transitions.contains(&first_word.as_str())
+            })
+            .count();
         let transition_pct = (transition_count as f32 / sentence_count as f32) * 100.0;
 
         if transition_pct < 20.0 && sentence_count > 3 {
@@ -272,18 +1225,75 @@ impl AnalysisService {
             score -= 5;
         }
 
+        // Dependent clause complexity
+        let complex_sentence_count = sentences.iter()
+            .filter(|sentence| Self::analyze_sentence_complexity(sentence).is_complex())
+            .count();
+
+        if complex_sentence_count > 0 {
+            issues.push(AnalysisIssue::new(
+                IssueSeverity::Info,
+                "Complex sentences",
+                &format!(
+                    "{} sentences have many dependent clauses. Split them into shorter sentences.",
+                    complex_sentence_count
+                ),
+            ));
+            score -= (complex_sentence_count as i32 * 5).min(15);
+        }
+
+        // Sentence length distribution
+        let max_words = self.settings.max_sentence_word_count;
+        let long_sentences: Vec<&str> = sentences.iter()
+            .copied()
+            .filter(|sentence| sentence.split_whitespace().count() > max_words)
+            .collect();
+        let long_sentence_pct = (long_sentences.len() as f32 / sentence_count as f32) * 100.0;
+
+        if long_sentence_pct > 25.0 {
+            issues.push(AnalysisIssue::new(
+                IssueSeverity::Info,
+                "Too many long sentences",
+                &format!(
+                    "{:.0}% of sentences contain more than {} words. Try to keep this under 25%.",
+                    long_sentence_pct, max_words
+                ),
+            ));
+            score -= 5;
+        }
+
         ReadabilityAnalysis {
             score: score.max(0),
             flesch_reading_ease: flesch.max(0.0),
             flesch_kincaid_grade: grade.max(0.0),
+            coleman_liau_index: coleman_liau.max(0.0),
+            smog_grade: smog,
             avg_sentence_length: avg_sentence,
             avg_word_length: avg_word,
             passive_voice_percentage: passive_pct,
+            passive_sentences: Self::extract_passive_sentences(content),
             transition_word_percentage: transition_pct,
+            complex_sentence_count,
+            long_sentence_count: long_sentences.len(),
+            long_sentence_percentage: long_sentence_pct,
+            long_sentences: long_sentences.iter().take(5).map(|s| s.to_string()).collect(),
             issues,
         }
     }
 
+    /// Coleman-Liau index from letter, word and sentence counts
+    pub fn coleman_liau_index(letters: usize, words: usize, sentences: usize) -> f32 {
+        let words = words.max(1) as f32;
+        let letters_per_100 = letters as f32 / words * 100.0;
+        let sentences_per_100 = sentences as f32 / words * 100.0;
+        0.0588 * letters_per_100 - 0.296 * sentences_per_100 - 15.8
+    }
+
+    /// SMOG grade from the number of words with three or more syllables
+    pub fn smog_grade(polysyllables: usize, sentences: usize) -> f32 {
+        1.0430 * (polysyllables as f32 * 30.0 / sentences.max(1) as f32).sqrt() + 3.1291
+    }
+
     /// Analyze links
     fn analyze_links(&self, data: &AnalysisInput) -> LinkAnalysis {
         let mut issues = Vec::new();
@@ -381,6 +1391,24 @@ impl AnalysisService {
             }
         }
 
+        let alt_quality: Vec<ImageAltQuality> = data.images.iter()
+            .filter_map(|img| {
+                let alt = img.alt.as_deref().filter(|a| !a.is_empty())?;
+                let report = Self::analyze_alt_text_quality(alt, &img.src, data.focus_keyword.as_deref());
+                Some(ImageAltQuality { src: img.src.clone(), quality: report.quality, issues: report.issues })
+            })
+            .collect();
+
+        let poor_alts = alt_quality.iter().filter(|q| q.quality == AltQuality::Poor).count();
+        if poor_alts > 0 {
+            issues.push(AnalysisIssue::new(
+                IssueSeverity::Warning,
+                "Alt text is not descriptive",
+                &format!("{} images have alt text that doesn't describe the image.", poor_alts),
+            ));
+            score -= 10;
+        }
+
         ImageAnalysis {
             score: score.max(0),
             total_images: data.images.len(),
@@ -389,10 +1417,62 @@ impl AnalysisService {
                 .count(),
             images_with_keyword: 0, // Already calculated above
             large_images: data.large_images.clone(),
+            alt_quality,
             issues,
         }
     }
 
+    /// Judge whether alt text actually describes the image
+    pub fn analyze_alt_text_quality(alt: &str, image_src: &str, focus_keyword: Option<&str>) -> AltQualityReport {
+        const GENERIC_TERMS: &[&str] = &["image", "photo", "picture", "img", "pic", "graphic", "photograph"];
+
+        let alt = alt.trim();
+        let lower = alt.to_lowercase();
+        let src = image_src.trim();
+        let filename = src.split(['?', '#']).next().unwrap_or(src).rsplit('/').next().unwrap_or(src);
+        let stem = filename.rsplit_once('.').map(|(stem, _)| stem).unwrap_or(filename);
+
+        let mut issues = Vec::new();
+        if alt.chars().count() < 5 {
+            issues.push("Alt text is too short to describe the image".to_string());
+        }
+        if alt == src || lower == filename.to_lowercase() || lower == stem.to_lowercase() {
+            issues.push("Alt text repeats the image file name".to_string());
+        } else if regex::Regex::new(r"(?i)^[\w\-. ]+\.(jpe?g|png|gif|webp|svg|avif|bmp|tiff?)$|^(img|dsc|dscn|pxl|screenshot)[_\- ]?\d+$")
+            .is_ok_and(|re| re.is_match(alt))
+        {
+            issues.push("Alt text is a file name".to_string());
+        }
+        if GENERIC_TERMS.contains(&lower.trim_matches(|c: char| !c.is_alphanumeric())) {
+            issues.push("Alt text is a generic term".to_string());
+        }
+        if !issues.is_empty() {
+            return AltQualityReport { quality: AltQuality::Poor, issues };
+        }
+
+        let length = alt.chars().count();
+        let has_keyword = focus_keyword
+            .map(|kw| kw.trim().to_lowercase())
+            .is_some_and(|kw| !kw.is_empty() && lower.contains(&kw));
+
+        if length < 40 {
+            issues.push("Alt text is shorter than 40 characters".to_string());
+        } else if length > 125 {
+            issues.push("Alt text is longer than 125 characters and may be cut off by screen readers".to_string());
+        }
+        if focus_keyword.is_some() && !has_keyword {
+            issues.push("Alt text doesn't contain the focus keyword".to_string());
+        }
+
+        let quality = if (40..=125).contains(&length) && has_keyword {
+            AltQuality::Good
+        } else {
+            AltQuality::Acceptable
+        };
+
+        AltQualityReport { quality, issues }
+    }
+
     /// Analyze technical SEO
     fn analyze_technical(&self, data: &AnalysisInput) -> TechnicalAnalysis {
         let mut issues = Vec::new();
@@ -432,8 +1512,47 @@ impl AnalysisService {
                 "Add schema.org structured data for rich snippets.",
             ));
             score -= 10;
+        } else if !data.schemas.is_empty() {
+            let report = self.analyze_schema_errors(&data.schemas);
+            if report.error_count > 0 {
+                issues.push(AnalysisIssue::new(
+                    IssueSeverity::Error,
+                    "Structured data has errors",
+                    &format!("Fix {} schema errors to be eligible for rich results.", report.error_count),
+                ));
+                score -= (report.error_count as i32 * 5).min(25);
+            }
         }
 
+        // Mobile friendliness is worth 10 points
+        let mobile_issues = data.html.as_deref().map(Self::detect_mobile_issues);
+        let mobile_friendly = match &mobile_issues {
+            Some(report) => {
+                let penalty = if report.has_viewport_meta {
+                    (report.issues.len() as i32 * 4).min(10)
+                } else {
+                    10
+                };
+                score -= penalty;
+                issues.extend(report.issues.iter().cloned());
+                report.is_mobile_friendly()
+            }
+            None => {
+                if !data.mobile_friendly {
+                    issues.push(AnalysisIssue::new(
+                        IssueSeverity::Warning,
+                        "Not mobile friendly",
+                        "Make sure the page is usable on small screens.",
+                    ));
+                    score -= 10;
+                }
+                data.mobile_friendly
+            }
+        };
+
+        let voice_search = (!data.content.trim().is_empty())
+            .then(|| Self::analyze_voice_search_readiness(&data.content, &data.title));
+
         TechnicalAnalysis {
             score: score.max(0),
             has_canonical: data.has_canonical,
@@ -442,11 +1561,88 @@ impl AnalysisService {
             has_twitter_card: data.has_twitter_card,
             has_schema: data.has_schema,
             page_load_time: data.page_load_time,
-            mobile_friendly: data.mobile_friendly,
+            mobile_friendly,
+            mobile_issues,
+            voice_search,
             issues,
         }
     }
 
+    /// Whether content gives short, direct answers a voice assistant can read out
+    pub fn analyze_voice_search_readiness(content: &str, title: &str) -> VoiceSearchAnalysis {
+        const QUESTION_WORDS: &[&str] = &[
+            "what", "how", "why", "when", "where", "which", "who", "does", "do", "is", "are",
+            "can", "should", "the", "a", "an", "to", "for", "of", "in", "and", "your", "you",
+        ];
+
+        let text = regex::Regex::new(r"<[^>]*>")
+            .map(|re| re.replace_all(content, " ").into_owned())
+            .unwrap_or_else(|_| content.to_string());
+        let sentences = split_sentences(&text);
+
+        let mut analysis = VoiceSearchAnalysis::default();
+        if sentences.is_empty() {
+            analysis.recommendations.push("Add content that answers the question in the title.".to_string());
+            return analysis;
+        }
+
+        let word_count: usize = sentences.iter().map(|s| s.split_whitespace().count()).sum();
+        analysis.avg_sentence_length = word_count as f32 / sentences.len() as f32;
+
+        // "X is Y" definitions and "To do X, you need to Y" instructions
+        let answer_patterns = regex::Regex::new(
+            r"(?i)^(?:[\w'-]+\s+){1,6}(?:is|are|means|refers to)\s+(?:a|an|the)\b|^to\s+\w+[^,]{0,80},\s*(?:you need to|you should|you can|you must|first)\b",
+        );
+        let pattern_answers = answer_patterns
+            .map(|re| sentences.iter().filter(|s| re.is_match(s)).count())
+            .unwrap_or(0);
+        let answered_questions = sentences.windows(2)
+            .filter(|pair| pair[0].ends_with('?') && !pair[1].ends_with('?'))
+            .count();
+        analysis.question_answer_pairs = pattern_answers + answered_questions;
+
+        let subject: Vec<String> = title.to_lowercase()
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| w.len() > 2 && !QUESTION_WORDS.contains(w))
+            .map(str::to_string)
+            .collect();
+        let first = sentences[0];
+        let first_lower = first.to_lowercase();
+        let matched = subject.iter().filter(|w| first_lower.contains(w.as_str())).count();
+        analysis.has_direct_answer = !first.ends_with('?')
+            && first.split_whitespace().count() <= 30
+            && matched > 0
+            && matched * 2 >= subject.len();
+
+        let mut score = if analysis.avg_sentence_length <= 20.0 {
+            40
+        } else {
+            (40.0 - 2.0 * (analysis.avg_sentence_length - 20.0)).max(0.0) as i32
+        };
+        if analysis.has_direct_answer {
+            score += 30;
+        }
+        score += (analysis.question_answer_pairs as i32 * 10).min(30);
+        analysis.score = score.clamp(0, 100);
+
+        if analysis.avg_sentence_length > 20.0 {
+            analysis.recommendations.push(format!(
+                "Shorten sentences: voice assistants read out answers of under 20 words, the average here is {:.0}.",
+                analysis.avg_sentence_length
+            ));
+        }
+        if !analysis.has_direct_answer {
+            analysis.recommendations.push("Open with a one-sentence answer to the question in the title.".to_string());
+        }
+        if analysis.question_answer_pairs == 0 {
+            analysis.recommendations.push(
+                "Answer common questions explicitly, e.g. \"X is ...\" or \"To do X, you need to ...\".".to_string(),
+            );
+        }
+
+        analysis
+    }
+
     /// Generate improvement suggestions
     fn generate_suggestions(
         &self,
@@ -454,6 +1650,7 @@ impl AnalysisService {
         meta: &MetaAnalysis,
         content: &ContentAnalysis,
         keyword: &KeywordAnalysis,
+        min_word_count: usize,
     ) -> Vec<SeoSuggestion> {
         let mut suggestions = Vec::new();
 
@@ -479,14 +1676,14 @@ impl AnalysisService {
         }
 
         // Medium priority
-        if content.word_count < self.settings.min_word_count {
+        if content.word_count < min_word_count {
             suggestions.push(SeoSuggestion {
                 category: "Content".to_string(),
                 priority: SuggestionPriority::Medium,
                 title: "Add more content".to_string(),
                 description: format!(
                     "Your content has {} words. Aim for at least {}.",
-                    content.word_count, self.settings.min_word_count
+                    content.word_count, min_word_count
                 ),
                 action: None,
             });
@@ -506,12 +1703,209 @@ impl AnalysisService {
     }
 }
 
+/// Auxiliary verb patterns used to spot passive voice
+const PASSIVE_PATTERNS: &[&str] = &["was ", "were ", "been ", "being ", "is being", "are being"];
+
+/// Countries and major cities recognized as locations
+const ENTITY_LOCATIONS: &[&str] = &[
+    "united states", "usa", "canada", "mexico", "brazil", "argentina", "united kingdom", "uk",
+    "ireland", "france", "germany", "spain", "portugal", "italy", "netherlands", "belgium",
+    "switzerland", "austria", "sweden", "norway", "denmark", "finland", "poland", "ukraine",
+    "russia", "turkey", "egypt", "nigeria", "kenya", "south africa", "india", "pakistan",
+    "china", "japan", "south korea", "indonesia", "vietnam", "thailand", "australia", "new zealand",
+    "europe", "asia", "africa", "north america", "south america",
+    "new york", "los angeles", "chicago", "san francisco", "seattle", "boston", "toronto",
+    "vancouver", "london", "paris", "berlin", "madrid", "barcelona", "rome", "milan", "amsterdam",
+    "brussels", "vienna", "zurich", "stockholm", "dublin", "lisbon", "moscow", "istanbul", "dubai",
+    "cairo", "lagos", "nairobi", "mumbai", "delhi", "bangalore", "beijing", "shanghai",
+    "hong kong", "singapore", "tokyo", "seoul", "sydney", "melbourne", "mexico city", "são paulo",
+];
+
+/// Well-known companies recognized as organizations
+const ENTITY_ORGANIZATIONS: &[&str] = &[
+    "google", "alphabet", "microsoft", "apple", "amazon", "meta", "facebook", "netflix", "tesla",
+    "ibm", "intel", "amd", "nvidia", "oracle", "salesforce", "adobe", "shopify", "spotify",
+    "samsung", "sony", "nintendo", "toyota", "volkswagen", "bmw", "nike", "adidas", "coca-cola",
+    "pepsi", "mcdonald's", "starbucks", "walmart", "ikea", "uber", "airbnb", "twitter", "linkedin",
+    "youtube", "wikipedia", "mozilla", "openai", "github", "wordpress", "yoast", "bing", "yahoo",
+    "united nations", "world health organization", "european union", "nasa",
+];
+
+/// Well-known products
+const ENTITY_PRODUCTS: &[&str] = &[
+    "iphone", "ipad", "macbook", "android", "windows", "chrome", "firefox", "safari", "gmail",
+    "google search console", "google analytics", "excel", "photoshop", "playstation", "xbox",
+    "kindle", "alexa", "chatgpt", "rust", "javascript", "python",
+];
+
+/// Capitalized words that are not entities on their own
+const ENTITY_STOPWORDS: &[&str] = &[
+    "i", "the", "a", "an", "this", "that", "these", "those", "it", "we", "you", "he", "she",
+    "they", "my", "our", "your", "in", "on", "at", "for", "if", "but", "and", "or", "so",
+    "monday", "tuesday", "wednesday", "thursday", "friday", "saturday", "sunday",
+    "january", "february", "march", "april", "may", "june", "july", "august",
+    "september", "october", "november", "december",
+];
+
+/// Gazetteer type of an entity, if listed
+fn classify_entity(text: &str) -> Option<EntityType> {
+    let lower = text.to_lowercase();
+    if ENTITY_LOCATIONS.contains(&lower.as_str()) {
+        Some(EntityType::Location)
+    } else if ENTITY_ORGANIZATIONS.contains(&lower.as_str()) {
+        Some(EntityType::Organization)
+    } else if ENTITY_PRODUCTS.contains(&lower.as_str()) {
+        Some(EntityType::Product)
+    } else {
+        None
+    }
+}
+
+/// Collect headings with their level from markdown content and input headings
+fn collect_headings(data: &AnalysisInput) -> Vec<(u8, String)> {
+    let mut headings: Vec<(u8, String)> = data.content.lines()
+        .filter_map(|line| {
+            let trimmed = line.trim();
+            let level = trimmed.chars().take_while(|c| *c == '#').count();
+            if (1..=6).contains(&level) && trimmed[level..].starts_with(' ') {
+                Some((level as u8, trimmed[level..].trim().to_string()))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    // Headings supplied without a level are treated as subheadings
+    for heading in &data.headings {
+        if !headings.iter().any(|(_, h)| h == heading) {
+            headings.push((2, heading.clone()));
+        }
+    }
+
+    headings
+}
+
+/// TF-IDF from a term's occurrence count, or plain TF without a corpus
+fn tfidf_weight(
+    occurrences: usize,
+    total_words: usize,
+    term: &str,
+    corpus_frequencies: &HashMap<String, usize>,
+    corpus_size: usize,
+) -> f32 {
+    let tf = occurrences as f32 / total_words as f32;
+    if corpus_frequencies.is_empty() || corpus_size == 0 {
+        return tf;
+    }
+
+    let df = corpus_frequencies.get(term).copied().unwrap_or(0);
+    let idf = (corpus_size as f32 / (1 + df) as f32).ln();
+
+    tf * idf
+}
+
+/// Lowercase words with surrounding punctuation stripped
+fn tokenize(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric()).to_lowercase())
+        .filter(|w| !w.is_empty())
+        .collect()
+}
+
+/// Reduce a word to a rough stem so plurals and inflections match
+fn stem_word(word: &str) -> String {
+    let word: String = word.chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect::<String>()
+        .to_lowercase();
+
+    if word.len() <= 3 {
+        return word;
+    }
+
+    if let Some(base) = word.strip_suffix("ies") {
+        return format!("{}y", base);
+    }
+    if let Some(base) = word.strip_suffix("es") {
+        if ["s", "x", "z", "ch", "sh"].iter().any(|e| base.ends_with(e)) {
+            return base.to_string();
+        }
+    }
+    if word.len() > 5 {
+        if let Some(base) = word.strip_suffix("ing").or_else(|| word.strip_suffix("ed")) {
+            return base.to_string();
+        }
+    }
+    if word.ends_with('s') && !word.ends_with("ss") {
+        return word[..word.len() - 1].to_string();
+    }
+
+    word
+}
+
 impl Default for AnalysisService {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// In-memory cache of analysis results
+#[derive(Debug)]
+pub struct AnalysisCache {
+    ttl: Duration,
+    cache: HashMap<Uuid, (SeoAnalysis, Instant)>,
+}
+
+impl AnalysisCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Cache using the configured `advanced.cache_ttl`, keeping nothing when caching is off
+    pub fn from_settings(settings: &SeoSettings) -> Self {
+        if !settings.advanced.cache_enabled {
+            return Self::new(Duration::ZERO);
+        }
+        Self::new(Duration::from_secs(settings.advanced.cache_ttl.max(0) as u64))
+    }
+
+    /// Get a cached analysis unless it has expired
+    pub fn get(&self, content_id: Uuid) -> Option<&SeoAnalysis> {
+        self.cache.get(&content_id)
+            .filter(|(_, stored_at)| stored_at.elapsed() < self.ttl)
+            .map(|(analysis, _)| analysis)
+    }
+
+    pub fn put(&mut self, analysis: SeoAnalysis) {
+        self.cache.insert(analysis.content_id, (analysis, Instant::now()));
+    }
+
+    pub fn invalidate(&mut self, content_id: Uuid) {
+        self.cache.remove(&content_id);
+    }
+
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+
+    /// Remove expired entries, returning how many were removed
+    pub fn prune_expired(&mut self) -> usize {
+        let before = self.cache.len();
+        let ttl = self.ttl;
+        self.cache.retain(|_, (_, stored_at)| stored_at.elapsed() < ttl);
+        before - self.cache.len()
+    }
+}
+
+impl Default for AnalysisCache {
+    fn default() -> Self {
+        Self::from_settings(&SeoSettings::default())
+    }
+}
+
 /// Input data for analysis
 pub struct AnalysisInput {
     pub title: String,
@@ -519,9 +1913,13 @@ pub struct AnalysisInput {
     pub content: String,
     pub url: String,
     pub focus_keyword: Option<String>,
+    /// Additional keywords analyzed separately at reduced weight
+    pub secondary_keywords: Vec<String>,
     pub headings: Vec<String>,
     pub internal_links: usize,
     pub external_links: usize,
+    /// Outbound links with the linked pages' publication dates, used for freshness signals
+    pub outbound_links: Vec<ExternalLinkInput>,
     pub nofollow_links: usize,
     pub broken_links: Vec<String>,
     pub images: Vec<ImageInput>,
@@ -531,11 +1929,365 @@ pub struct AnalysisInput {
     pub has_open_graph: bool,
     pub has_twitter_card: bool,
     pub has_schema: bool,
+    /// JSON-LD schemas on the page
+    pub schemas: Vec<Value>,
     pub page_load_time: Option<f32>,
     pub mobile_friendly: bool,
+    /// Rendered page HTML, used for mobile checks
+    pub html: Option<String>,
+    /// Cornerstone content gets stricter word count, heading and keyword checks
+    pub is_cornerstone: bool,
+    /// Other pages as `(url, content_excerpt, existing_links)`, searched for linking opportunities
+    pub linking_candidates: Vec<(String, String, Vec<String>)>,
+    /// Author attribution, used for E-E-A-T signals
+    pub author: Option<AuthorProfile>,
 }
 
 pub struct ImageInput {
     pub src: String,
     pub alt: Option<String>,
 }
+
+/// Outbound link, with the linked page's publication date when known
+pub struct ExternalLinkInput {
+    pub url: String,
+    pub link_date: Option<DateTime<Utc>>,
+}
+
+/// Structured data errors found during analysis
+pub struct SchemaErrorReport {
+    pub error_count: usize,
+    pub warning_count: usize,
+    /// Errors keyed by schema `@type`
+    pub errors: Vec<(String, SchemaError)>,
+    pub warnings: Vec<(String, SchemaWarning)>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_analysis(content_id: Uuid) -> SeoAnalysis {
+        AnalysisService::new().analyze(content_id, sample_input())
+    }
+
+    fn sample_input() -> AnalysisInput {
+        AnalysisInput {
+            title: "Test title".to_string(),
+            meta_description: None,
+            content: "Some content.".to_string(),
+            url: "/test".to_string(),
+            focus_keyword: None,
+            secondary_keywords: vec![],
+            headings: vec![],
+            internal_links: 0,
+            external_links: 0,
+            outbound_links: vec![],
+            nofollow_links: 0,
+            broken_links: vec![],
+            images: vec![],
+            large_images: vec![],
+            has_canonical: true,
+            has_robots_meta: true,
+            has_open_graph: true,
+            has_twitter_card: true,
+            has_schema: false,
+            schemas: vec![],
+            page_load_time: None,
+            mobile_friendly: true,
+            html: None,
+            is_cornerstone: false,
+            linking_candidates: vec![],
+            author: None,
+        }
+    }
+
+    #[test]
+    fn test_tfidf_suggestions_skip_stop_words() {
+        let content = "Sourdough starter needs flour. Feed the starter daily, because starter flour ratios matter.";
+        let mut corpus = HashMap::new();
+        corpus.insert("flour".to_string(), 40);
+
+        let suggestions = AnalysisService::suggest_related_keywords_by_tfidf(content, &corpus, 100, 3);
+
+        assert_eq!(suggestions[0].0, "starter");
+        assert!(suggestions.iter().all(|(word, _)| word != "because"));
+        let expected = AnalysisService::compute_tfidf("starter", content, &corpus, 100);
+        assert!((suggestions[0].1 - expected).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_is_analysis_worthwhile() {
+        let mut settings = SeoSettings::default();
+        settings.meta.noindex_search = false;
+        settings.meta.noindex_archives = true;
+        let meta = SeoMeta::new(Uuid::now_v7(), ContentType::Post);
+
+        assert!(AnalysisService::is_analysis_worthwhile(&meta, &ContentContext::new(ContentType::Post), &settings));
+
+        let search = ContentContext { is_search: true, ..ContentContext::new(ContentType::Page) };
+        assert!(!AnalysisService::is_analysis_worthwhile(&meta, &search, &settings));
+
+        let archive = ContentContext { is_archive: true, ..ContentContext::new(ContentType::Archive) };
+        assert!(!AnalysisService::is_analysis_worthwhile(&meta, &archive, &settings));
+
+        let noindex = SeoMeta { robots: crate::models::meta::MetaRobots::noindex(), ..meta };
+        assert!(!AnalysisService::is_analysis_worthwhile(&noindex, &ContentContext::new(ContentType::Post), &settings));
+    }
+
+    #[test]
+    fn test_cache_expiry() {
+        let id = Uuid::now_v7();
+
+        let mut cache = AnalysisCache::new(Duration::from_secs(3600));
+        cache.put(sample_analysis(id));
+        assert!(cache.get(id).is_some());
+
+        let mut expired = AnalysisCache::new(Duration::ZERO);
+        expired.put(sample_analysis(id));
+        assert!(expired.get(id).is_none());
+
+        cache.invalidate(id);
+        assert!(cache.get(id).is_none());
+    }
+
+    #[test]
+    fn test_analyze_cached_until_invalidated() {
+        let service = AnalysisService::new().with_cache_ttl(Duration::from_secs(3600));
+        let id = Uuid::now_v7();
+
+        let first = service.analyze_cached(id, sample_input());
+        assert_eq!(service.analyze_cached(id, sample_input()).id, first.id);
+
+        service.invalidate_cache(id);
+        assert_ne!(service.analyze_cached(id, sample_input()).id, first.id);
+
+        service.clear_cache();
+        assert!(service.cached_analysis(id).is_none());
+    }
+
+    #[test]
+    fn test_prune_expired() {
+        let fresh_id = Uuid::now_v7();
+        let stale_id = Uuid::now_v7();
+
+        let mut cache = AnalysisCache::new(Duration::from_secs(60));
+        cache.put(sample_analysis(fresh_id));
+        cache.put(sample_analysis(stale_id));
+
+        let stale_at = Instant::now().checked_sub(Duration::from_secs(120)).unwrap();
+        cache.cache.get_mut(&stale_id).unwrap().1 = stale_at;
+
+        assert_eq!(cache.prune_expired(), 1);
+        assert!(cache.get(fresh_id).is_some());
+        assert!(!cache.cache.contains_key(&stale_id));
+        assert_eq!(cache.prune_expired(), 0);
+    }
+
+    #[test]
+    fn test_merge_keeps_unchanged_components() {
+        let id = Uuid::now_v7();
+        let mut old = sample_analysis(id);
+        old.content_analysis.score = 11;
+        old.readability_analysis.score = 22;
+        old.link_analysis.score = 33;
+        old.image_analysis.score = 44;
+        old.meta_analysis.score = 55;
+        old.title_analysis.score = 66;
+
+        let new = sample_analysis(id);
+        let new_title_score = new.title_analysis.score;
+
+        let merged = SeoAnalysis::merge_with_previous(new, old, ChangedFields {
+            title_changed: true,
+            ..Default::default()
+        });
+
+        assert_eq!(merged.title_analysis.score, new_title_score);
+        assert_eq!(merged.content_analysis.score, 11);
+        assert_eq!(merged.readability_analysis.score, 22);
+        assert_eq!(merged.link_analysis.score, 33);
+        assert_eq!(merged.image_analysis.score, 44);
+        assert_eq!(merged.meta_analysis.score, 55);
+        assert_eq!(merged.overall_score.score, merged.calculate_overall_score().score);
+    }
+
+    #[test]
+    fn test_merge_uses_new_content_components_when_content_changed() {
+        let id = Uuid::now_v7();
+        let mut old = sample_analysis(id);
+        old.content_analysis.score = 11;
+        old.meta_analysis.score = 55;
+
+        let new = sample_analysis(id);
+        let new_content_score = new.content_analysis.score;
+
+        let merged = SeoAnalysis::merge_with_previous(new, old, ChangedFields {
+            content_changed: true,
+            ..Default::default()
+        });
+
+        assert_eq!(merged.content_analysis.score, new_content_score);
+        assert_eq!(merged.meta_analysis.score, 55);
+    }
+
+    #[test]
+    fn test_transition_words_count_sentence_openers_only() {
+        let analysis = AnalysisService::new().analyze_readability(
+            "However, the test passed. It also ran also quickly. Finally, we shipped. The end.",
+        );
+        assert_eq!(analysis.transition_word_percentage, 50.0);
+    }
+
+    #[test]
+    fn test_long_sentence_distribution() {
+        let settings = AnalysisSettings::builder().max_sentence_word_count(5).build().unwrap();
+        let analysis = AnalysisService::new().with_settings(settings).analyze_readability(
+            "Short one here. This sentence has quite a few more words in it. Tiny. Another rather long sentence that goes on.",
+        );
+        assert_eq!(analysis.long_sentence_count, 2);
+        assert_eq!(analysis.long_sentence_percentage, 50.0);
+        assert_eq!(analysis.long_sentences[0], "This sentence has quite a few more words in it.");
+    }
+
+    #[test]
+    fn test_coleman_liau_index_formula() {
+        // 500 letters across 100 words in 5 sentences
+        let index = AnalysisService::coleman_liau_index(500, 100, 5);
+        assert!((index - 12.12).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_smog_grade_formula() {
+        // 30 polysyllabic words in a 30-sentence sample
+        let grade = AnalysisService::smog_grade(30, 30);
+        assert!((grade - 8.842).abs() < 0.01);
+        assert!((AnalysisService::smog_grade(0, 10) - 3.1291).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_readability_grades_rank_reference_texts() {
+        let service = AnalysisService::new();
+        let simple = service.analyze_readability(
+            "The cat sat on the mat. It was a warm day. The dog ran to the park. We had fun.",
+        );
+        let technical = service.analyze_readability(
+            "Pharmacokinetic variability necessitates individualized therapeutic monitoring. \
+             Immunological complications frequently accompany experimental interventions. \
+             Comprehensive epidemiological documentation substantially improves reproducibility.",
+        );
+
+        assert!(simple.coleman_liau_index < technical.coleman_liau_index);
+        assert!(simple.smog_grade < technical.smog_grade);
+        assert!(technical.issues.iter().any(|i| i.title == "SMOG grade above target grade"));
+        assert!(!simple.issues.iter().any(|i| i.title.contains("above target grade")));
+    }
+
+    fn five_page_graph() -> (Vec<String>, HashMap<String, Vec<String>>) {
+        let urls: Vec<String> = ["/", "/about", "/blog", "/blog/old-post", "/landing/spring-sale"]
+            .iter()
+            .map(|u| u.to_string())
+            .collect();
+
+        let mut graph = HashMap::new();
+        graph.insert("/".to_string(), vec!["/about".to_string(), "/blog".to_string()]);
+        graph.insert("/blog".to_string(), vec!["/".to_string(), "/blog".to_string()]);
+        graph.insert("/blog/old-post".to_string(), vec!["/about/".to_string()]);
+        (urls, graph)
+    }
+
+    #[test]
+    fn test_detect_orphaned_pages() {
+        let (urls, graph) = five_page_graph();
+        let orphaned = AnalysisService::detect_orphaned_pages(&urls, &graph);
+        assert_eq!(orphaned, vec!["/blog/old-post".to_string(), "/landing/spring-sale".to_string()]);
+    }
+
+    #[test]
+    fn test_detect_orphaned_pages_ignores_self_links() {
+        let (urls, mut graph) = five_page_graph();
+        graph.insert("/".to_string(), vec!["/about".to_string()]);
+        let orphaned = AnalysisService::detect_orphaned_pages(&urls, &graph);
+        assert_eq!(orphaned, vec!["/blog", "/blog/old-post", "/landing/spring-sale"]);
+    }
+
+    #[test]
+    fn test_freshness_dated_headings_need_a_day_or_year() {
+        let report = AnalysisService::analyze_freshness_signals("<h2>What you may need</h2>", &[]);
+        assert!(report.freshness_phrases.is_empty());
+        assert_eq!(report.score_adjustment, 0);
+
+        let report = AnalysisService::analyze_freshness_signals("<h2>Prices as of May 5</h2><h3>1 March changes</h3>", &[]);
+        assert_eq!(report.freshness_phrases, vec!["Prices as of May 5", "1 March changes"]);
+        assert_eq!(report.score_adjustment, 3);
+    }
+
+    #[test]
+    fn test_analysis_includes_freshness_signals() {
+        let mut input = sample_input();
+        input.content = format!("Last updated for {}.", Utc::now().year());
+
+        let analysis = AnalysisService::new().analyze(Uuid::now_v7(), input);
+
+        assert!(analysis.freshness_signals.current_year_mentioned);
+        assert!(analysis.freshness_signals.score_adjustment > 0);
+    }
+
+    #[test]
+    fn test_analysis_includes_eeat_signals() {
+        let mut input = sample_input();
+        input.author = Some(AuthorProfile {
+            name: "Ada".to_string(),
+            bio: Some("Baker for twenty years.".to_string()),
+            url: None,
+            credentials: vec![],
+        });
+
+        let analysis = AnalysisService::new().analyze(Uuid::now_v7(), input);
+
+        assert!(analysis.eeat_signals.authority_signals.iter().any(|s| s == "Author bio present for Ada"));
+        assert!(analysis.eeat_signals.expertise_score > 0);
+    }
+
+    #[test]
+    fn test_analysis_includes_passages() {
+        let mut input = sample_input();
+        input.focus_keyword = Some("sourdough".to_string());
+        input.content = "## Feeding\n\nFeed your sourdough starter once a day.\n\nAs mentioned above, keep it warm.".to_string();
+
+        let passages = AnalysisService::new().analyze(Uuid::now_v7(), input).passage_analysis;
+
+        assert_eq!(passages.total_passages, 2);
+        assert!(passages.top_passages[0].has_keyword);
+        assert!(passages.top_passages[0].text.starts_with("Feed your sourdough"));
+    }
+
+    #[test]
+    fn test_analysis_includes_top_linking_opportunities() {
+        let mut input = sample_input();
+        input.focus_keyword = Some("rust".to_string());
+        input.linking_candidates = (0..12)
+            .map(|i| (format!("/post-{}", i), "We write Rust every day.".to_string(), vec![]))
+            .chain([("/linked".to_string(), "More Rust.".to_string(), vec!["/test".to_string()])])
+            .collect();
+
+        let analysis = AnalysisService::new().analyze(Uuid::now_v7(), input);
+        assert_eq!(analysis.internal_link_opportunities.len(), 10);
+        assert!(analysis.internal_link_opportunities.iter().all(|o| o.source_url != "/linked"));
+        assert_eq!(analysis.internal_link_opportunities[0].suggested_anchor, "Rust");
+    }
+
+    #[test]
+    fn test_analysis_includes_top_entities() {
+        let mut input = sample_input();
+        input.content = (1..=12)
+            .map(|i| format!("We met Dr Person{} in Berlin.", "x".repeat(i)))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let analysis = AnalysisService::new().analyze(Uuid::now_v7(), input);
+        assert_eq!(analysis.detected_entities.len(), 10);
+        assert_eq!(analysis.detected_entities[0].text, "Berlin");
+        assert_eq!(analysis.detected_entities[0].count, 12);
+    }
+}
@@ -0,0 +1,88 @@
+//! Related Keyword Extraction
+//!
+//! Frequent content terms surfaced as semantically related keywords.
+
+use crate::models::analysis::RelatedKeyword;
+use std::collections::HashMap;
+
+/// Most related keywords returned for a piece of content
+pub const MAX_RELATED_KEYWORDS: usize = 10;
+
+/// Common English words that carry no topical meaning
+pub(crate) const STOP_WORDS: &[&str] = &[
+    "a", "about", "above", "after", "again", "against", "all", "also", "am", "an", "and",
+    "any", "are", "as", "at", "be", "because", "been", "before", "being", "below",
+    "between", "both", "but", "by", "can", "could", "did", "do", "does", "doing", "down",
+    "during", "each", "even", "few", "for", "from", "further", "get", "got", "had", "has",
+    "have", "having", "he", "her", "here", "hers", "him", "his", "how", "i", "if", "in",
+    "into", "is", "it", "its", "just", "like", "make", "many", "me", "more", "most", "much",
+    "my", "no", "nor", "not", "now", "of", "off", "on", "once", "one", "only", "or", "other",
+    "our", "out", "over", "own", "same", "she", "should", "so", "some", "such", "than",
+    "that", "the", "their", "them", "then", "there", "these", "they", "this", "those",
+    "through", "to", "too", "under", "until", "up", "use", "used", "very", "was", "way",
+    "we", "well", "were", "what", "when", "where", "which", "while", "who", "why", "will",
+    "with", "would", "you", "your",
+];
+
+/// Most frequent non-stop-words in `content`, excluding the focus keyword's own terms
+///
+/// Returns at most `MAX_RELATED_KEYWORDS` entries by descending frequency.
+pub fn extract_related_keywords(content: &str, focus_keyword: &str) -> Vec<RelatedKeyword> {
+    let words: Vec<String> = content
+        .split_whitespace()
+        .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric()).to_lowercase())
+        .filter(|w| !w.is_empty())
+        .collect();
+    if words.is_empty() {
+        return vec![];
+    }
+
+    let focus_terms: Vec<String> = focus_keyword.split_whitespace().map(str::to_lowercase).collect();
+
+    let mut frequencies: HashMap<&str, usize> = HashMap::new();
+    for word in &words {
+        let is_candidate = word.chars().count() > 2
+            && !word.chars().all(|c| c.is_ascii_digit())
+            && !STOP_WORDS.contains(&word.as_str())
+            && !focus_terms.contains(word);
+        if is_candidate {
+            *frequencies.entry(word.as_str()).or_insert(0) += 1;
+        }
+    }
+
+    let mut ranked: Vec<(&str, usize)> = frequencies.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    ranked.truncate(MAX_RELATED_KEYWORDS);
+
+    ranked.into_iter()
+        .map(|(keyword, occurrences)| RelatedKeyword {
+            keyword: keyword.to_string(),
+            occurrences: occurrences as i32,
+            density: occurrences as f32 / words.len() as f32 * 100.0,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_related_keywords() {
+        let content = "Rust coffee brewing guide. Brewing coffee needs fresh beans, \
+            and fresh beans need a good grinder. The grinder matters for brewing.";
+        let related = extract_related_keywords(content, "coffee");
+
+        let keywords: Vec<&str> = related.iter().map(|r| r.keyword.as_str()).collect();
+        assert_eq!(&keywords[..4], &["brewing", "beans", "fresh", "grinder"]);
+        assert!(!keywords.contains(&"coffee"));
+        assert!(!keywords.contains(&"the"));
+        assert_eq!(related[0].occurrences, 3);
+    }
+
+    #[test]
+    fn test_extract_related_keywords_caps_results() {
+        let content = (0..30).map(|i| format!("term{}", i)).collect::<Vec<_>>().join(" ");
+        assert_eq!(extract_related_keywords(&content, "focus").len(), MAX_RELATED_KEYWORDS);
+    }
+}
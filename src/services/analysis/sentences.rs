@@ -0,0 +1,105 @@
+//! Sentence Splitting
+//!
+//! Sentence boundary detection shared by the readability checks.
+
+use std::sync::OnceLock;
+
+/// Abbreviations whose trailing period doesn't end a sentence
+const ABBREVIATIONS: &[&str] = &[
+    "mr", "mrs", "ms", "dr", "prof", "sr", "jr", "st", "vs", "etc", "e.g", "i.e",
+    "inc", "ltd", "co", "no", "fig", "approx",
+];
+
+/// Split text into trimmed sentences, keeping their terminal punctuation
+///
+/// A sentence ends at `.`, `!` or `?` followed by whitespace or the end of the
+/// text, or at a blank line. Decimals (`3.5`), common abbreviations (`e.g.`)
+/// and closing quotes or brackets after the punctuation stay in the sentence.
+pub fn split_sentences(content: &str) -> Vec<&str> {
+    static PARAGRAPH_BREAK: OnceLock<regex::Regex> = OnceLock::new();
+    let paragraph_break = PARAGRAPH_BREAK.get_or_init(|| {
+        regex::Regex::new(r"\n[ \t\r]*\n").expect("paragraph break pattern is valid")
+    });
+
+    let mut sentences = Vec::new();
+    let paragraphs: Vec<&str> = paragraph_break.split(content).collect();
+
+    for paragraph in paragraphs {
+        let chars: Vec<(usize, char)> = paragraph.char_indices().collect();
+        let mut start = 0;
+        let mut i = 0;
+
+        while i < chars.len() {
+            let (pos, c) = chars[i];
+            i += 1;
+            if !matches!(c, '.' | '!' | '?') {
+                continue;
+            }
+
+            // Runs like "?!" or "..." and closing quotes belong to this sentence
+            while i < chars.len() && matches!(chars[i].1, '.' | '!' | '?' | '"' | '\'' | ')' | ']' | '”' | '’') {
+                i += 1;
+            }
+
+            let at_boundary = i == chars.len() || chars[i].1.is_whitespace();
+            if at_boundary && !(c == '.' && ends_with_abbreviation(&paragraph[start..pos])) {
+                let end = chars.get(i).map_or(paragraph.len(), |(offset, _)| *offset);
+                push_sentence(&mut sentences, &paragraph[start..end]);
+                start = end;
+            }
+        }
+
+        push_sentence(&mut sentences, &paragraph[start..]);
+    }
+
+    sentences
+}
+
+fn push_sentence<'a>(sentences: &mut Vec<&'a str>, sentence: &'a str) {
+    let sentence = sentence.trim();
+    if sentence.chars().any(char::is_alphanumeric) {
+        sentences.push(sentence);
+    }
+}
+
+fn ends_with_abbreviation(text: &str) -> bool {
+    let last_word = text
+        .rsplit(char::is_whitespace)
+        .next()
+        .unwrap_or_default()
+        .trim_start_matches(['(', '"', '\''])
+        .to_lowercase();
+    ABBREVIATIONS.contains(&last_word.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_sentences() {
+        let text = "First sentence. Second one!  Is this the third?\nYes, it spans\ntwo lines.";
+        assert_eq!(
+            split_sentences(text),
+            vec!["First sentence.", "Second one!", "Is this the third?", "Yes, it spans\ntwo lines."]
+        );
+    }
+
+    #[test]
+    fn test_split_sentences_keeps_decimals_and_abbreviations() {
+        let text = "Version 2.5 ships today, e.g. on Linux. Dr. Smith approved it.";
+        assert_eq!(
+            split_sentences(text),
+            vec!["Version 2.5 ships today, e.g. on Linux.", "Dr. Smith approved it."]
+        );
+    }
+
+    #[test]
+    fn test_split_sentences_on_blank_lines() {
+        let text = "A heading without punctuation\n\nThe body starts here. \"Quoted ending.\" Done";
+        assert_eq!(
+            split_sentences(text),
+            vec!["A heading without punctuation", "The body starts here.", "\"Quoted ending.\"", "Done"]
+        );
+    }
+}
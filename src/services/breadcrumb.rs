@@ -0,0 +1,124 @@
+//! Breadcrumb Service
+//!
+//! Service for building breadcrumb trails from URLs.
+
+use crate::models::breadcrumb::{Breadcrumb, BreadcrumbSettings};
+
+/// Label lookup for a URL path segment, e.g. a category slug to its name
+pub type SegmentResolver = Box<dyn Fn(&str) -> Option<String> + Send + Sync>;
+
+/// Service for building breadcrumb trails
+pub struct BreadcrumbService {
+    settings: BreadcrumbSettings,
+    resolver: Option<SegmentResolver>,
+}
+
+impl BreadcrumbService {
+    pub fn new() -> Self {
+        Self {
+            settings: BreadcrumbSettings::default(),
+            resolver: None,
+        }
+    }
+
+    pub fn with_settings(mut self, settings: BreadcrumbSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    pub fn with_resolver(mut self, resolver: SegmentResolver) -> Self {
+        self.resolver = Some(resolver);
+        self
+    }
+
+    pub fn settings(&self) -> &BreadcrumbSettings {
+        &self.settings
+    }
+
+    /// Build a trail for a URL, one item per path segment
+    ///
+    /// The last item is labelled with `content_title`; other segments use the
+    /// resolver, falling back to a title-cased slug.
+    pub fn build_from_url(&self, url: &str, site_url: &str, content_title: &str) -> Breadcrumb {
+        let site_url = site_url.trim_end_matches('/');
+        let path = match url.strip_prefix(site_url) {
+            Some(path) => path,
+            None => match url.split_once("://") {
+                Some((_, rest)) => rest.find('/').map(|i| &rest[i..]).unwrap_or_default(),
+                None => url,
+            },
+        };
+
+        let mut breadcrumb = Breadcrumb::from_path(path, site_url, |segment| {
+            self.resolver.as_ref().and_then(|resolve| resolve(segment))
+        });
+        breadcrumb.separator = self.settings.separator.clone();
+        breadcrumb.home_text = self.settings.home_text.clone();
+        breadcrumb.show_home = self.settings.show_home;
+
+        if let Some(home) = breadcrumb.items.first_mut() {
+            home.name = self.settings.home_text.clone();
+        }
+        if !self.settings.show_home && !breadcrumb.items.is_empty() {
+            breadcrumb.items.remove(0);
+            for (i, item) in breadcrumb.items.iter_mut().enumerate() {
+                item.position = i as i32 + 1;
+            }
+        }
+
+        // Keep the home label when the URL is the home page itself
+        let has_trail = breadcrumb.items.len() > usize::from(self.settings.show_home);
+        if let Some(current) = breadcrumb.items.last_mut().filter(|_| has_trail && !content_title.is_empty()) {
+            current.name = content_title.to_string();
+        }
+        breadcrumb.mark_current();
+
+        breadcrumb
+    }
+}
+
+impl Default for BreadcrumbService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_from_url() {
+        let service = BreadcrumbService::new().with_resolver(Box::new(|segment| {
+            (segment == "seo").then(|| "SEO Guides".to_string())
+        }));
+
+        let breadcrumb = service.build_from_url(
+            "https://example.com/blog/seo/keyword-research/?ref=nav",
+            "https://example.com/",
+            "Keyword Research 101",
+        );
+        let names: Vec<&str> = breadcrumb.items.iter().map(|i| i.name.as_str()).collect();
+
+        assert_eq!(names, vec!["Home", "Blog", "SEO Guides", "Keyword Research 101"]);
+        assert_eq!(breadcrumb.items[2].url.as_deref(), Some("https://example.com/blog/seo/"));
+        assert!(breadcrumb.items[3].is_current);
+        assert_eq!(breadcrumb.items[3].position, 4);
+    }
+
+    #[test]
+    fn test_to_html_with_settings() {
+        let settings = BreadcrumbSettings {
+            separator: " / ".to_string(),
+            bold_current: true,
+            ..BreadcrumbSettings::default()
+        };
+        let service = BreadcrumbService::new().with_settings(settings.clone());
+        let breadcrumb = service.build_from_url("https://example.com/shop/", "https://example.com", "Shop & Deals");
+
+        let html = breadcrumb.to_html(&settings);
+        assert!(html.contains("<a href=\"https://example.com/\">Home</a>"));
+        assert!(html.contains("<span class=\"breadcrumb-separator\"> / </span>"));
+        assert!(html.contains("<span aria-current=\"page\"><strong>Shop &amp; Deals</strong></span>"));
+    }
+}
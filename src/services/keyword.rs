@@ -0,0 +1,137 @@
+//! Keyword Research Service
+//!
+//! Service for managing planned keywords and their target content.
+
+use crate::models::keyword::{keyword_to_url_slug, KeywordResearch, SearchIntent};
+use chrono::Utc;
+use uuid::Uuid;
+
+/// Service for keyword research tracking
+pub struct KeywordService {
+    keywords: Vec<KeywordResearch>,
+}
+
+impl KeywordService {
+    pub fn new() -> Self {
+        Self {
+            keywords: Vec::new(),
+        }
+    }
+
+    /// Add a keyword, returning the existing entry if already tracked
+    pub fn add_keyword(&mut self, keyword: &str, intent: SearchIntent) -> &KeywordResearch {
+        let keyword = keyword.trim();
+        let lower = keyword.to_lowercase();
+
+        let pos = match self.keywords.iter().position(|k| k.keyword.to_lowercase() == lower) {
+            Some(pos) => pos,
+            None => {
+                self.keywords.push(KeywordResearch::new(keyword.to_string(), intent));
+                self.keywords.len() - 1
+            }
+        };
+
+        &self.keywords[pos]
+    }
+
+    /// Get all keywords
+    pub fn get_keywords(&self) -> &[KeywordResearch] {
+        &self.keywords
+    }
+
+    /// Get keyword by ID
+    pub fn get_keyword(&self, id: Uuid) -> Option<&KeywordResearch> {
+        self.keywords.iter().find(|k| k.id == id)
+    }
+
+    /// Get keywords assigned to a content item
+    pub fn get_keywords_for_content(&self, content_id: Uuid) -> Vec<&KeywordResearch> {
+        self.keywords.iter()
+            .filter(|k| k.assigned_content_id == Some(content_id))
+            .collect()
+    }
+
+    /// Get keywords not yet assigned to any content
+    pub fn get_unassigned(&self) -> Vec<&KeywordResearch> {
+        self.keywords.iter()
+            .filter(|k| k.assigned_content_id.is_none())
+            .collect()
+    }
+
+    /// Update keyword metrics
+    pub fn update_metrics(
+        &mut self,
+        id: Uuid,
+        search_volume: Option<i64>,
+        difficulty: Option<f32>,
+        cpc: Option<f32>,
+    ) -> bool {
+        if let Some(keyword) = self.keywords.iter_mut().find(|k| k.id == id) {
+            if search_volume.is_some() {
+                keyword.search_volume = search_volume;
+            }
+            if difficulty.is_some() {
+                keyword.difficulty = difficulty;
+            }
+            if cpc.is_some() {
+                keyword.cpc = cpc;
+            }
+            keyword.updated_at = Utc::now();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Assign a keyword to content, defaulting the target URL to the keyword slug
+    pub fn assign_to_content(&mut self, id: Uuid, content_id: Uuid, target_url: Option<String>) -> bool {
+        if let Some(keyword) = self.keywords.iter_mut().find(|k| k.id == id) {
+            keyword.assigned_content_id = Some(content_id);
+            keyword.target_url = target_url
+                .or_else(|| Some(format!("/{}", keyword_to_url_slug(&keyword.keyword))));
+            keyword.updated_at = Utc::now();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Remove content assignment from a keyword
+    pub fn unassign(&mut self, id: Uuid) -> bool {
+        if let Some(keyword) = self.keywords.iter_mut().find(|k| k.id == id) {
+            keyword.assigned_content_id = None;
+            keyword.target_url = None;
+            keyword.updated_at = Utc::now();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Record the current ranking position
+    pub fn update_rank(&mut self, id: Uuid, rank: Option<i32>) -> bool {
+        if let Some(keyword) = self.keywords.iter_mut().find(|k| k.id == id) {
+            keyword.current_rank = rank;
+            keyword.updated_at = Utc::now();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Remove a keyword
+    pub fn remove_keyword(&mut self, id: Uuid) -> bool {
+        if let Some(pos) = self.keywords.iter().position(|k| k.id == id) {
+            self.keywords.remove(pos);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl Default for KeywordService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
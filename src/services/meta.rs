@@ -2,10 +2,19 @@
 //!
 //! Service for managing SEO meta tags.
 
-use crate::models::meta::{SeoMeta, ContentType, MetaRobots};
-use crate::models::social::{OpenGraphData, TwitterCardData, OpenGraphType, TwitterCardType};
+use crate::models::meta::{html_escape, hreflang_html, HreflangEntry, SeoMeta, ContentType, MetaRobots, PaginationLinks};
+use crate::settings::{normalize_canonical_url, AdvancedSettings, ContentContext, MetaSettings};
+use crate::models::social::{
+    MastodonMetaData, OpenGraphData, OpenGraphType, TwitterCardData, TwitterCardType,
+};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+use std::time::{Duration, Instant};
 use uuid::Uuid;
 
+/// Body text lookup for a content item, used to generate descriptions
+pub type ContentSource = Box<dyn Fn(ContentType, Uuid) -> Option<String> + Send + Sync>;
+
 /// Service for managing SEO meta data
 pub struct MetaService {
     site_name: String,
@@ -13,6 +22,11 @@ pub struct MetaService {
     separator: String,
     default_og_image: Option<String>,
     twitter_site: Option<String>,
+    mastodon: Option<MastodonMetaData>,
+    settings: MetaSettings,
+    context: Option<ContentContext>,
+    store: Arc<dyn MetaStore>,
+    content_source: Option<ContentSource>,
 }
 
 impl MetaService {
@@ -23,6 +37,11 @@ impl MetaService {
             separator: " | ".to_string(),
             default_og_image: None,
             twitter_site: None,
+            mastodon: None,
+            settings: MetaSettings::default(),
+            context: None,
+            store: Arc::new(InMemoryMetaStore::new()),
+            content_source: None,
         }
     }
 
@@ -41,6 +60,92 @@ impl MetaService {
         self
     }
 
+    pub fn with_settings(mut self, settings: MetaSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    /// Page context used to decide the robots directives
+    pub fn with_content_context(mut self, context: ContentContext) -> Self {
+        self.context = Some(context);
+        self
+    }
+
+    pub fn with_mastodon(mut self, mastodon: MastodonMetaData) -> Self {
+        self.mastodon = Some(mastodon);
+        self
+    }
+
+    /// Storage backend for meta records
+    pub fn with_store(mut self, store: Arc<dyn MetaStore>) -> Self {
+        self.store = store;
+        self
+    }
+
+    /// Storage backend, for sharing with a rebuilt service
+    pub fn store(&self) -> Arc<dyn MetaStore> {
+        Arc::clone(&self.store)
+    }
+
+    pub fn with_content_source(mut self, source: ContentSource) -> Self {
+        self.content_source = Some(source);
+        self
+    }
+
+    /// Load the stored meta for a content item
+    ///
+    /// A missing description is generated from the content body when
+    /// description generation is enabled and a content source is set.
+    pub fn get_meta(&self, content_type: ContentType, content_id: Uuid) -> Result<Option<SeoMeta>, ServiceError> {
+        let mut meta = self.store.get(content_type, content_id)?;
+
+        if let Some(meta) = meta.as_mut().filter(|m| m.description.is_none() && !m.use_custom_description) {
+            let content = self.content_source.as_ref()
+                .filter(|_| self.settings.generate_description)
+                .and_then(|source| source(content_type, content_id));
+            if let Some(content) = content {
+                let max_length = self.settings.description_length.max(0) as usize;
+                let description = Self::generate_auto_description(&content, max_length);
+                meta.description = Some(description).filter(|d| !d.is_empty());
+            }
+        }
+
+        Ok(meta)
+    }
+
+    /// Validate and store meta for its content item
+    pub fn save_meta(&self, meta: &SeoMeta) -> Result<(), ServiceError> {
+        let errors = Self::validate_meta(meta);
+        if !errors.is_empty() {
+            return Err(ServiceError::ValidationError(errors));
+        }
+        self.store.save(meta)
+    }
+
+    /// Remove the stored meta for a content item
+    pub fn delete_meta(&self, content_type: ContentType, content_id: Uuid) -> Result<(), ServiceError> {
+        self.store.delete(content_type, content_id)
+    }
+
+    /// Problems that keep meta from being saved
+    fn validate_meta(meta: &SeoMeta) -> Vec<String> {
+        let mut errors = Vec::new();
+
+        if let Some(url) = meta.canonical_url.as_deref().filter(|u| !u.is_empty()) {
+            if !url.starts_with("http://") && !url.starts_with("https://") {
+                errors.push(format!("Canonical URL must be absolute: {}", url));
+            }
+        }
+        if meta.max_snippet.is_some_and(|n| n < -1) {
+            errors.push("max-snippet must be -1 or greater".to_string());
+        }
+        if meta.max_video_preview.is_some_and(|n| n < -1) {
+            errors.push("max-video-preview must be -1 or greater".to_string());
+        }
+
+        errors
+    }
+
     /// Generate complete head meta tags
     pub fn generate_head(
         &self,
@@ -49,27 +154,47 @@ impl MetaService {
         content_url: &str,
         image: Option<&str>,
         author: Option<&str>,
+        pagination: Option<&PaginationLinks>,
     ) -> String {
         let mut html = String::new();
 
-        // Basic meta tags
-        html.push_str(&meta.to_html(title, &self.site_name, &self.separator));
+        let restricted;
+        let meta = match &self.context {
+            Some(context) => {
+                restricted = self.restrict_robots(meta, context);
+                &restricted
+            }
+            None => meta,
+        };
+
+        // Basic meta tags; the canonical is resolved below
+        let basic = SeoMeta { canonical_url: None, ..meta.clone() };
+        html.push_str(&basic.to_html(title, &self.site_name, &self.separator));
 
         // Canonical
-        if meta.use_custom_canonical {
-            if let Some(canonical) = &meta.canonical_url {
-                html.push_str(&format!(
-                    "<link rel=\"canonical\" href=\"{}\">\n",
-                    canonical
-                ));
-            }
+        let canonical = if meta.use_custom_canonical {
+            meta.canonical_url.as_deref()
         } else {
+            Some(pagination.map(|p| p.canonical.as_str()).unwrap_or(content_url))
+        };
+        let canonical = canonical.map(|c| normalize_canonical_url(c, &self.settings.canonical_strategy));
+        if let Some(canonical) = &canonical {
             html.push_str(&format!(
                 "<link rel=\"canonical\" href=\"{}\">\n",
-                content_url
+                html_escape(canonical)
             ));
         }
 
+        // Language alternates
+        html.push_str(&Self::generate_hreflang_tags(&meta.hreflang_alternates, canonical.as_deref()));
+
+        // Pagination
+        if self.settings.add_next_prev {
+            if let Some(pagination) = pagination {
+                html.push_str(&pagination.to_html());
+            }
+        }
+
         // OpenGraph
         let og = self.generate_opengraph(meta, title, content_url, image, author);
         html.push_str(&og.to_html());
@@ -78,9 +203,29 @@ impl MetaService {
         let twitter = self.generate_twitter_card(meta, title, image);
         html.push_str(&twitter.to_html());
 
+        // Fediverse
+        if let Some(mastodon) = &self.mastodon {
+            html.push_str(&mastodon.to_html());
+        }
+
         html
     }
 
+    /// Meta with the settings-level robots for a page context applied
+    ///
+    /// Settings-level noindex/nofollow always wins over the per-content values.
+    pub fn restrict_robots(&self, meta: &SeoMeta, context: &ContentContext) -> SeoMeta {
+        let robots = self.settings.effective_robots_for(context);
+        SeoMeta {
+            robots: MetaRobots {
+                index: meta.robots.index && robots.index,
+                follow: meta.robots.follow && robots.follow,
+                ..meta.robots.clone()
+            },
+            ..meta.clone()
+        }
+    }
+
     /// Generate OpenGraph data
     pub fn generate_opengraph(
         &self,
@@ -150,6 +295,35 @@ impl MetaService {
         }
     }
 
+    /// Generate hreflang alternate links, with `x-default` pointing at the canonical URL
+    pub fn generate_hreflang_tags(alternates: &[HreflangEntry], canonical: Option<&str>) -> String {
+        hreflang_html(alternates, canonical)
+    }
+
+    /// Generate prev/next/canonical links for paginated content
+    pub fn generate_pagination_links(base_url: &str, current_page: u32, total_pages: u32) -> PaginationLinks {
+        let base = base_url
+            .split(['?', '#'])
+            .next()
+            .unwrap_or(base_url);
+        let total_pages = total_pages.max(1);
+        let current_page = current_page.clamp(1, total_pages);
+
+        let page_url = |page: u32| {
+            if page <= 1 {
+                base.to_string()
+            } else {
+                format!("{}?page={}", base, page)
+            }
+        };
+
+        PaginationLinks {
+            prev: (current_page > 1).then(|| page_url(current_page - 1)),
+            next: (current_page < total_pages).then(|| page_url(current_page + 1)),
+            canonical: page_url(current_page),
+        }
+    }
+
     /// Truncate description to optimal length
     pub fn truncate_description(description: &str, max_length: usize) -> String {
         if description.len() <= max_length {
@@ -175,6 +349,77 @@ impl MetaService {
         truncated
     }
 
+    /// Estimated rendered width of `text` in Arial at `font_px`
+    ///
+    /// Uses approximate Arial advance widths (in em) grouped by character class;
+    /// non-Latin characters count as a full em.
+    pub fn compute_pixel_width(text: &str, font_px: f32) -> f32 {
+        text.chars().map(|c| char_width_em(c) * font_px).sum()
+    }
+
+    /// Character index where a snippet wider than `max_px` gets cut, if it does
+    ///
+    /// Leaves room for a trailing ellipsis and backs off to the previous word
+    /// boundary when one is within a few characters of the cut.
+    pub fn snippet_truncation_point(text: &str, max_px: f32, font_px: f32) -> Option<usize> {
+        if Self::compute_pixel_width(text, font_px) <= max_px {
+            return None;
+        }
+
+        let budget = max_px - Self::compute_pixel_width("...", font_px);
+        let chars: Vec<char> = text.chars().collect();
+        let mut width = 0.0;
+        let mut cut = 0;
+        for c in &chars {
+            width += char_width_em(*c) * font_px;
+            if width > budget {
+                break;
+            }
+            cut += 1;
+        }
+
+        let boundary = chars[..cut].iter().rposition(|c| c.is_whitespace());
+        match boundary {
+            Some(space) if cut - space <= 12 && space > 0 => Some(space),
+            _ => Some(cut),
+        }
+    }
+
+    /// Meta description from the start of the content body
+    ///
+    /// Formatting is stripped, then whole sentences are kept while they fit in
+    /// `max_length` bytes. When not even the first sentence fits, the text is
+    /// cut at the last word boundary. `…` is appended (within the limit) only
+    /// when something was cut off.
+    pub fn generate_auto_description(content: &str, max_length: usize) -> String {
+        const ELLIPSIS: &str = "…";
+
+        let text = strip_formatting(content);
+        if text.len() <= max_length {
+            return text;
+        }
+
+        let budget = max_length.saturating_sub(ELLIPSIS.len());
+        let sentence_end = text
+            .rmatch_indices(['.', '!', '?'])
+            .map(|(i, mark)| i + mark.len())
+            .find(|&end| end <= budget && text[end..].starts_with(' '));
+
+        let kept = match sentence_end {
+            Some(end) => &text[..end],
+            None => {
+                let mut end = budget;
+                while !text.is_char_boundary(end) {
+                    end -= 1;
+                }
+                let word_end = if text[end..].starts_with(' ') { Some(end) } else { text[..end].rfind(' ') };
+                word_end.map_or("", |end| text[..end].trim_end_matches([',', ':', ';']))
+            }
+        };
+
+        format!("{}{}", kept, ELLIPSIS)
+    }
+
     /// Generate excerpt from content for description
     pub fn generate_excerpt(content: &str, max_length: usize) -> String {
         // Remove HTML tags (simple approach)
@@ -201,6 +446,212 @@ impl MetaService {
     }
 }
 
+/// Strip HTML tags and Markdown formatting, collapsing whitespace
+fn strip_formatting(content: &str) -> String {
+    let patterns = [
+        // HTML tags and Markdown images become spaces / alt text
+        (r"(?s)<[^>]*>", " "),
+        (r"!\[([^\]]*)\]\([^)]*\)", "$1"),
+        (r"\[([^\]]*)\]\([^)]*\)", "$1"),
+        // Rules, then headings, blockquotes and list markers at line starts
+        (r"(?m)^[ \t]*(?:[-*_][ \t]*){3,}$", ""),
+        (r"(?m)^[ \t]*(?:#{1,6}|>+|[-*+]|\d+[.)])[ \t]+", ""),
+        // Emphasis and code markers
+        (r"`+|\*+|(?:^|\b)__?|__?(?:\b|$)|~~", ""),
+    ];
+
+    let mut text = content.to_string();
+    for (pattern, replacement) in patterns {
+        if let Ok(re) = regex::Regex::new(pattern) {
+            text = re.replace_all(&text, replacement).into_owned();
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Approximate Arial advance width in em
+fn char_width_em(c: char) -> f32 {
+    match c {
+        'i' | 'j' | 'l' => 0.222,
+        'I' | 'f' | 't' | ' ' | '.' | ',' | ':' | ';' | '!' | '\'' | '|' | '/' | '(' | ')' | '[' | ']' => 0.278,
+        'r' | '-' => 0.333,
+        'c' | 'k' | 's' | 'v' | 'x' | 'y' | 'z' => 0.5,
+        'm' | 'M' => 0.833,
+        'w' => 0.722,
+        'W' => 0.944,
+        'C' | 'D' | 'G' | 'H' | 'N' | 'O' | 'Q' | 'R' | 'U' => 0.722,
+        'a'..='z' | '0'..='9' | '?' | '_' | '$' => 0.556,
+        'A'..='Z' | '&' => 0.667,
+        c if c.is_ascii() => 0.5,
+        _ => 1.0,
+    }
+}
+
+/// Errors returned by meta storage operations
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ServiceError {
+    #[error("meta not found")]
+    NotFound,
+    #[error("invalid meta: {}", .0.join("; "))]
+    ValidationError(Vec<String>),
+    #[error("storage error: {0}")]
+    StorageError(String),
+}
+
+/// Persistence backend for SEO meta records
+pub trait MetaStore: Send + Sync {
+    fn get(&self, content_type: ContentType, content_id: Uuid) -> Result<Option<SeoMeta>, ServiceError>;
+
+    fn save(&self, meta: &SeoMeta) -> Result<(), ServiceError>;
+
+    /// Fails with `NotFound` when nothing is stored for the content item
+    fn delete(&self, content_type: ContentType, content_id: Uuid) -> Result<(), ServiceError>;
+}
+
+/// Meta store kept in process memory
+#[derive(Debug, Default)]
+pub struct InMemoryMetaStore {
+    records: RwLock<HashMap<(ContentType, Uuid), SeoMeta>>,
+}
+
+impl InMemoryMetaStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl MetaStore for InMemoryMetaStore {
+    fn get(&self, content_type: ContentType, content_id: Uuid) -> Result<Option<SeoMeta>, ServiceError> {
+        let records = self.records.read().unwrap_or_else(PoisonError::into_inner);
+        Ok(records.get(&(content_type, content_id)).cloned())
+    }
+
+    fn save(&self, meta: &SeoMeta) -> Result<(), ServiceError> {
+        let mut records = self.records.write().unwrap_or_else(PoisonError::into_inner);
+        records.insert((meta.content_type, meta.content_id), meta.clone());
+        Ok(())
+    }
+
+    fn delete(&self, content_type: ContentType, content_id: Uuid) -> Result<(), ServiceError> {
+        let mut records = self.records.write().unwrap_or_else(PoisonError::into_inner);
+        records
+            .remove(&(content_type, content_id))
+            .map(|_| ())
+            .ok_or(ServiceError::NotFound)
+    }
+}
+
+type MetaCache = HashMap<(ContentType, Uuid), (SeoMeta, Instant)>;
+
+/// `MetaService` with a shared in-memory cache of loaded meta
+///
+/// Clones share the same service and cache, so one instance can serve
+/// concurrent `head_output` calls.
+#[derive(Clone)]
+pub struct CachedMetaService {
+    inner: Arc<MetaService>,
+    cache: Arc<Mutex<MetaCache>>,
+    ttl: Duration,
+    enabled: bool,
+}
+
+impl CachedMetaService {
+    pub fn new(inner: MetaService, ttl: Duration) -> Self {
+        Self {
+            inner: Arc::new(inner),
+            cache: Arc::new(Mutex::new(HashMap::new())),
+            ttl,
+            enabled: true,
+        }
+    }
+
+    /// Cache configured from `cache_enabled` and `cache_ttl` (seconds)
+    pub fn from_settings(inner: MetaService, settings: &AdvancedSettings) -> Self {
+        let mut service = Self::new(inner, Duration::from_secs(settings.cache_ttl.max(0) as u64));
+        service.enabled = settings.cache_enabled;
+        service
+    }
+
+    pub fn inner(&self) -> &MetaService {
+        &self.inner
+    }
+
+    /// Load meta through the cache, evicting the entry if it has expired
+    pub fn get_meta(&self, content_type: ContentType, content_id: Uuid) -> Result<Option<SeoMeta>, ServiceError> {
+        if !self.enabled {
+            return self.inner.get_meta(content_type, content_id);
+        }
+
+        let key = (content_type, content_id);
+        {
+            let mut cache = self.cache.lock().unwrap_or_else(PoisonError::into_inner);
+            match cache.get(&key) {
+                Some((meta, cached_at)) if cached_at.elapsed() < self.ttl => return Ok(Some(meta.clone())),
+                Some(_) => {
+                    cache.remove(&key);
+                }
+                None => {}
+            }
+        }
+
+        // Load without holding the lock; misses are not cached
+        let meta = self.inner.get_meta(content_type, content_id)?;
+        if let Some(meta) = &meta {
+            let mut cache = self.cache.lock().unwrap_or_else(PoisonError::into_inner);
+            cache.retain(|_, (_, cached_at)| cached_at.elapsed() < self.ttl);
+            cache.insert(key, (meta.clone(), Instant::now()));
+        }
+        Ok(meta)
+    }
+
+    /// Save meta and drop its cached copy
+    pub fn save_meta(&self, meta: &SeoMeta) -> Result<(), ServiceError> {
+        self.inner.save_meta(meta)?;
+        self.invalidate(meta.content_type, meta.content_id);
+        Ok(())
+    }
+
+    /// Delete meta and drop its cached copy
+    pub fn delete_meta(&self, content_type: ContentType, content_id: Uuid) -> Result<(), ServiceError> {
+        let result = self.inner.delete_meta(content_type, content_id);
+        self.invalidate(content_type, content_id);
+        result
+    }
+
+    /// Drop the cached meta for one content item
+    pub fn invalidate(&self, content_type: ContentType, content_id: Uuid) {
+        self.cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&(content_type, content_id));
+    }
+
+    /// Drop all cached meta
+    pub fn invalidate_all(&self) {
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner).clear();
+    }
+
+    /// Number of cached entries, including expired ones not yet evicted
+    pub fn len(&self) -> usize {
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner).len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl std::fmt::Debug for CachedMetaService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CachedMetaService")
+            .field("entries", &self.len())
+            .field("ttl", &self.ttl)
+            .field("enabled", &self.enabled)
+            .finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -212,6 +663,201 @@ mod tests {
         assert!(truncated.len() <= 33); // 30 + "..."
     }
 
+    #[test]
+    fn test_generate_head() {
+        let mut meta = SeoMeta::new(Uuid::nil(), ContentType::Archive);
+        meta.title = Some("Fish & Chips <Best> \"Guide\"".to_string());
+        meta.use_custom_title = true;
+        meta.description = Some("Tom's favourite recipe".to_string());
+        meta.keywords = vec!["fish".to_string(), "chips & peas".to_string()];
+        meta.canonical_url = Some("https://example.com/fish?a=1&b=2".to_string());
+        meta.use_custom_canonical = true;
+
+        let service = MetaService::new("Fish & Chips".to_string(), "https://example.com".to_string())
+            .with_settings(MetaSettings { noindex_archives: true, ..MetaSettings::default() })
+            .with_content_context(ContentContext { is_archive: true, ..ContentContext::new(ContentType::Archive) });
+        let html = service.generate_head(&meta, "Fish", "https://example.com/fish", None, None, None);
+
+        assert!(html.starts_with("<title>Fish &amp; Chips &lt;Best&gt; &quot;Guide&quot;</title>\n"));
+        assert!(html.contains("<meta name=\"description\" content=\"Tom&#39;s favourite recipe\">"));
+        assert!(html.contains("<meta name=\"robots\" content=\"noindex, follow"));
+        assert_eq!(html.matches("rel=\"canonical\"").count(), 1);
+        assert!(html.contains("&amp;b=2\">"));
+        assert!(html.contains("<meta name=\"keywords\" content=\"fish, chips &amp; peas\">"));
+        assert!(html.contains("<meta property=\"og:title\""));
+        assert!(html.contains("<meta name=\"twitter:title\""));
+        assert!(!html.contains("<Best>"));
+    }
+
+    #[test]
+    fn test_save_and_get_meta() {
+        let service = MetaService::new("Site".to_string(), "https://example.com".to_string());
+        let content_id = Uuid::now_v7();
+        assert!(service.get_meta(ContentType::Post, content_id).unwrap().is_none());
+
+        let mut meta = SeoMeta::new(content_id, ContentType::Post);
+        meta.title = Some("Stored".to_string());
+        service.save_meta(&meta).unwrap();
+
+        let stored = service.get_meta(ContentType::Post, content_id).unwrap().unwrap();
+        assert_eq!(stored.title.as_deref(), Some("Stored"));
+        assert!(service.get_meta(ContentType::Page, content_id).unwrap().is_none());
+
+        meta.canonical_url = Some("/relative".to_string());
+        assert!(matches!(service.save_meta(&meta), Err(ServiceError::ValidationError(e)) if e.len() == 1));
+
+        service.delete_meta(ContentType::Post, content_id).unwrap();
+        assert!(matches!(service.delete_meta(ContentType::Post, content_id), Err(ServiceError::NotFound)));
+    }
+
+    #[test]
+    fn test_auto_description_shorter_than_limit() {
+        let content = "## Intro\n\nThis is **short** and [linked](https://example.com).";
+        let description = MetaService::generate_auto_description(content, 160);
+        assert_eq!(description, "Intro This is short and linked.");
+    }
+
+    #[test]
+    fn test_auto_description_equal_to_limit() {
+        let content = "<p>Exactly this long.</p>";
+        let description = MetaService::generate_auto_description(content, "Exactly this long.".len());
+        assert_eq!(description, "Exactly this long.");
+    }
+
+    #[test]
+    fn test_auto_description_longer_than_limit() {
+        let content = "First sentence here. Second sentence is a bit longer. Third.";
+        let description = MetaService::generate_auto_description(content, 30);
+        assert_eq!(description, "First sentence here.…");
+        assert!(description.len() <= 30);
+
+        let description = MetaService::generate_auto_description("One very long sentence without a break", 20);
+        assert_eq!(description, "One very long…");
+    }
+
+    #[test]
+    fn test_get_meta_generates_missing_description() {
+        let service = MetaService::new("Site".to_string(), "https://example.com".to_string())
+            .with_content_source(Box::new(|_, _| Some("<p>Generated from the body.</p>".to_string())));
+        let content_id = Uuid::now_v7();
+        service.save_meta(&SeoMeta::new(content_id, ContentType::Post)).unwrap();
+
+        let meta = service.get_meta(ContentType::Post, content_id).unwrap().unwrap();
+        assert_eq!(meta.description.as_deref(), Some("Generated from the body."));
+    }
+
+    #[test]
+    fn test_cached_meta_service() {
+        let service = CachedMetaService::new(
+            MetaService::new("Site".to_string(), "https://example.com".to_string()),
+            Duration::from_secs(60),
+        );
+        let content_id = Uuid::now_v7();
+        let mut meta = SeoMeta::new(content_id, ContentType::Page);
+        meta.title = Some("First".to_string());
+        service.save_meta(&meta).unwrap();
+
+        assert_eq!(service.get_meta(ContentType::Page, content_id).unwrap().unwrap().title.as_deref(), Some("First"));
+        assert_eq!(service.len(), 1);
+
+        // Writes straight to the store are hidden until invalidated
+        meta.title = Some("Second".to_string());
+        service.inner().save_meta(&meta).unwrap();
+        assert_eq!(service.get_meta(ContentType::Page, content_id).unwrap().unwrap().title.as_deref(), Some("First"));
+
+        service.invalidate(ContentType::Page, content_id);
+        assert_eq!(service.get_meta(ContentType::Page, content_id).unwrap().unwrap().title.as_deref(), Some("Second"));
+
+        service.invalidate_all();
+        assert!(service.is_empty());
+    }
+
+    #[test]
+    fn test_cached_meta_service_expires_entries() {
+        let service = CachedMetaService::new(
+            MetaService::new("Site".to_string(), "https://example.com".to_string()),
+            Duration::ZERO,
+        );
+        let first = Uuid::now_v7();
+        let second = Uuid::now_v7();
+        service.save_meta(&SeoMeta::new(first, ContentType::Post)).unwrap();
+        service.save_meta(&SeoMeta::new(second, ContentType::Post)).unwrap();
+
+        service.get_meta(ContentType::Post, first).unwrap();
+        assert_eq!(service.len(), 1);
+
+        // Reads only evict the entry they look up
+        service.get_meta(ContentType::Post, Uuid::now_v7()).unwrap();
+        assert_eq!(service.len(), 1);
+
+        // Inserting sweeps the expired entries
+        service.get_meta(ContentType::Post, second).unwrap();
+        assert_eq!(service.len(), 1);
+    }
+
+    #[test]
+    fn test_generate_hreflang_tags() {
+        let alternates = vec![
+            HreflangEntry::new("en", "https://example.com/post"),
+            HreflangEntry::new("de_DE", "https://example.com/de/post"),
+        ];
+        let html = MetaService::generate_hreflang_tags(&alternates, Some("https://example.com/post"));
+
+        assert_eq!(html.lines().count(), 3);
+        assert!(html.contains("<link rel=\"alternate\" hreflang=\"de-DE\" href=\"https://example.com/de/post\" />"));
+        assert!(html.ends_with("<link rel=\"alternate\" hreflang=\"x-default\" href=\"https://example.com/post\" />\n"));
+        assert!(MetaService::generate_hreflang_tags(&[], Some("https://example.com/post")).is_empty());
+    }
+
+    #[test]
+    fn test_pagination_links_first_page() {
+        let links = MetaService::generate_pagination_links("https://example.com/post?utm=x", 1, 3);
+        assert_eq!(links.canonical, "https://example.com/post");
+        assert_eq!(links.prev, None);
+        assert_eq!(links.next.as_deref(), Some("https://example.com/post?page=2"));
+    }
+
+    #[test]
+    fn test_pagination_links_middle_page() {
+        let links = MetaService::generate_pagination_links("https://example.com/post", 2, 3);
+        assert_eq!(links.canonical, "https://example.com/post?page=2");
+        assert_eq!(links.prev.as_deref(), Some("https://example.com/post"));
+        assert_eq!(links.next.as_deref(), Some("https://example.com/post?page=3"));
+
+        let html = links.to_html();
+        assert!(html.contains("<link rel=\"prev\" href=\"https://example.com/post\">"));
+        assert!(html.contains("<link rel=\"next\" href=\"https://example.com/post?page=3\">"));
+    }
+
+    #[test]
+    fn test_pagination_links_last_page() {
+        let links = MetaService::generate_pagination_links("https://example.com/post", 3, 3);
+        assert_eq!(links.canonical, "https://example.com/post?page=3");
+        assert_eq!(links.prev.as_deref(), Some("https://example.com/post?page=2"));
+        assert_eq!(links.next, None);
+    }
+
+    #[test]
+    fn test_wide_characters_truncate_earlier() {
+        let wide = "M".repeat(60);
+        let narrow = "i".repeat(60);
+
+        let wide_cut = MetaService::snippet_truncation_point(&wide, 600.0, 20.0);
+        let narrow_cut = MetaService::snippet_truncation_point(&narrow, 600.0, 20.0);
+
+        assert!(wide_cut.is_some());
+        assert_eq!(narrow_cut, None);
+        assert!(MetaService::compute_pixel_width(&wide, 20.0) > MetaService::compute_pixel_width(&narrow, 20.0));
+    }
+
+    #[test]
+    fn test_truncation_point_prefers_word_boundary() {
+        let title = "Complete Guide to Monitoring Distributed Systems With Open Source Tooling";
+        let cut = MetaService::snippet_truncation_point(title, 600.0, 20.0).unwrap();
+        assert!(cut < title.chars().count());
+        assert_eq!(title.chars().nth(cut), Some(' '));
+    }
+
     #[test]
     fn test_generate_excerpt() {
         let html = "<p>This is a <strong>test</strong> paragraph.</p>";
@@ -8,10 +8,14 @@ pub mod schema;
 pub mod analysis;
 pub mod redirect;
 pub mod robots;
+pub mod keyword;
+pub mod breadcrumb;
 
-pub use meta::MetaService;
+pub use meta::{CachedMetaService, MetaService};
 pub use sitemap::SitemapService;
 pub use schema::SchemaService;
 pub use analysis::AnalysisService;
 pub use redirect::RedirectService;
 pub use robots::RobotsService;
+pub use keyword::KeywordService;
+pub use breadcrumb::BreadcrumbService;
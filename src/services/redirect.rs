@@ -2,16 +2,23 @@
 //!
 //! Service for managing URL redirects.
 
+use crate::admin::redirects::ImportError;
 use crate::models::redirect::{Redirect, RedirectType, MatchType, NotFoundLog, RedirectSettings};
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
 use uuid::Uuid;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, PoisonError};
 
 /// Service for managing URL redirects
+#[derive(Debug)]
 pub struct RedirectService {
     redirects: Vec<Redirect>,
     settings: RedirectSettings,
     not_found_log: HashMap<String, NotFoundLog>,
+    sitemap_urls: Vec<String>,
+    /// Compiled regex sources, `None` for invalid patterns
+    patterns: Mutex<HashMap<String, Option<regex::Regex>>>,
 }
 
 impl RedirectService {
@@ -20,46 +27,201 @@ impl RedirectService {
             redirects: Vec::new(),
             settings: RedirectSettings::default(),
             not_found_log: HashMap::new(),
+            sitemap_urls: Vec::new(),
+            patterns: Mutex::new(HashMap::new()),
         }
     }
 
     pub fn with_settings(mut self, settings: RedirectSettings) -> Self {
-        self.settings = settings;
+        self.set_settings(settings);
         self
     }
 
-    /// Add a redirect
-    pub fn add_redirect(&mut self, redirect: Redirect) {
+    /// URLs currently listed in the sitemap, used for impact analysis
+    pub fn with_sitemap_urls(mut self, urls: Vec<String>) -> Self {
+        self.sitemap_urls = urls;
+        self
+    }
+
+    /// Replace the sitemap URLs used for impact analysis
+    pub fn set_sitemap_urls(&mut self, urls: Vec<String>) {
+        self.sitemap_urls = urls;
+    }
+
+    pub fn settings(&self) -> &RedirectSettings {
+        &self.settings
+    }
+
+    /// Replace the settings, keeping the redirects and the 404 log
+    pub fn set_settings(&mut self, settings: RedirectSettings) {
+        self.settings = settings;
+        // Patterns are compiled for the old case sensitivity
+        self.patterns.get_mut().unwrap_or_else(PoisonError::into_inner).clear();
+    }
+
+    /// Add a redirect, rejecting it if it would close a redirect loop
+    pub fn add_redirect(&mut self, redirect: Redirect) -> Result<(), RedirectError> {
+        let id = redirect.id;
         self.redirects.push(redirect);
+
+        if let Some(chain) = self.loop_through(id) {
+            self.redirects.pop();
+            return Err(RedirectError::RedirectLoop { chain });
+        }
+
+        Ok(())
+    }
+
+    /// All redirect loops among active redirects
+    ///
+    /// Walks the redirect graph depth-first from every known URL. Each chain lists
+    /// the loop in redirect order; its last URL redirects back to the first.
+    pub fn detect_redirect_loops(&self) -> Vec<Vec<String>> {
+        let mut finished: HashSet<String> = HashSet::new();
+        self.redirects.iter()
+            .filter(|r| r.is_active)
+            .flat_map(Self::loop_starts)
+            .filter_map(|start| self.walk_redirects(start, &mut finished))
+            .collect()
+    }
+
+    /// URLs a loop through `redirect` must pass through
+    ///
+    /// Exact rules are entered at their source; any loop also runs through the
+    /// target, which is fixed for all but regex rules.
+    fn loop_starts(redirect: &Redirect) -> Vec<String> {
+        let mut starts = Vec::with_capacity(2);
+        if redirect.match_type == MatchType::Exact {
+            starts.push(redirect.source_url.clone());
+        }
+        starts.push(redirect.target_url.clone());
+        starts
+    }
+
+    /// Follow redirects from `start` and return the loop it runs into, if any
+    ///
+    /// Walks stop at URLs in `finished`, which were already followed to their end.
+    fn walk_redirects(&self, start: String, finished: &mut HashSet<String>) -> Option<Vec<String>> {
+        // Regex rules can keep producing new URLs, so bound each walk
+        let max_depth = self.redirects.len() * 2 + 2;
+        let mut path: Vec<String> = Vec::new();
+        let mut positions: HashMap<String, usize> = HashMap::new();
+        let mut current = start;
+        let mut found = None;
+
+        while path.len() < max_depth {
+            let key = self.normalize(&current);
+            if let Some(&pos) = positions.get(&key) {
+                found = Some(path[pos..].to_vec());
+                break;
+            }
+            if finished.contains(&key) {
+                break;
+            }
+
+            let Some(redirect) = self.find_redirect(&current) else {
+                break;
+            };
+            let next = self.target_for(redirect, &current);
+            positions.insert(key, path.len());
+            path.push(current);
+            current = next;
+        }
+
+        finished.extend(positions.into_keys());
+        found
+    }
+
+    /// Loop that passes through the given redirect, if any
+    ///
+    /// Only the paths leaving this redirect are walked, not the whole graph.
+    fn loop_through(&self, id: Uuid) -> Option<Vec<String>> {
+        let redirect = self.get_redirect(id).filter(|r| r.is_active)?;
+        let mut starts = Self::loop_starts(redirect);
+        if redirect.match_type == MatchType::Regex {
+            // A loop enters a regex rule through another rule's target
+            if let Some(re) = self.compiled(&redirect.source_url) {
+                starts.extend(self.redirects.iter()
+                    .filter(|r| r.is_active && re.is_match(&self.normalize(&r.target_url)))
+                    .map(|r| r.target_url.clone()));
+            }
+        }
+
+        let mut finished = HashSet::new();
+        starts
+            .into_iter()
+            .filter_map(|start| self.walk_redirects(start, &mut finished))
+            .find(|chain| chain.iter().any(|url| self.find_redirect(url).is_some_and(|r| r.id == id)))
+            .map(|mut chain| {
+                // Report the loop from where the new rule leads
+                let target = self.normalize(&redirect.target_url);
+                if let Some(pos) = chain.iter().position(|url| self.normalize(url) == target) {
+                    chain.rotate_left(pos);
+                }
+                chain
+            })
     }
 
     /// Create a simple 301 redirect
-    pub fn add_301(&mut self, source: &str, target: &str) {
-        let redirect = Redirect::new(
+    pub fn add_301(&mut self, source: &str, target: &str) -> Result<(), RedirectError> {
+        self.add_redirect(Redirect::new(
             source.to_string(),
             target.to_string(),
             RedirectType::Permanent,
-        );
-        self.redirects.push(redirect);
+        ))
     }
 
     /// Create a 302 temporary redirect
-    pub fn add_302(&mut self, source: &str, target: &str) {
-        let redirect = Redirect::new(
+    pub fn add_302(&mut self, source: &str, target: &str) -> Result<(), RedirectError> {
+        self.add_redirect(Redirect::new(
             source.to_string(),
             target.to_string(),
             RedirectType::Temporary,
-        );
-        self.redirects.push(redirect);
+        ))
     }
 
-    /// Find redirect for a URL
-    pub fn find_redirect(&self, url: &str) -> Option<&Redirect> {
-        let url_to_check = if self.settings.case_insensitive {
+    /// URL as compared against sources, lowercased when matching is case-insensitive
+    fn normalize(&self, url: &str) -> String {
+        if self.settings.case_insensitive {
             url.to_lowercase()
         } else {
             url.to_string()
-        };
+        }
+    }
+
+    /// Compiled regex source, built once per pattern
+    ///
+    /// Case-insensitive matching compiles the pattern case-insensitively, so a
+    /// mixed-case source still matches the lowercased URL.
+    fn compiled(&self, pattern: &str) -> Option<regex::Regex> {
+        let mut patterns = self.patterns.lock().unwrap_or_else(PoisonError::into_inner);
+        patterns
+            .entry(pattern.to_string())
+            .or_insert_with(|| {
+                regex::RegexBuilder::new(pattern)
+                    .case_insensitive(self.settings.case_insensitive)
+                    .build()
+                    .ok()
+            })
+            .clone()
+    }
+
+    /// Target of `redirect` for a URL it matched
+    ///
+    /// Captures come from the normalized URL, the same string `find_redirect` matched.
+    fn target_for(&self, redirect: &Redirect, url: &str) -> String {
+        match redirect.match_type {
+            MatchType::Regex => match self.compiled(&redirect.source_url) {
+                Some(re) => redirect.get_target_with(&re, &self.normalize(url)),
+                None => redirect.target_url.clone(),
+            },
+            _ => redirect.target_url.clone(),
+        }
+    }
+
+    /// Find redirect for a URL
+    pub fn find_redirect(&self, url: &str) -> Option<&Redirect> {
+        let url_to_check = self.normalize(url);
 
         for redirect in &self.redirects {
             if !redirect.is_active {
@@ -76,13 +238,8 @@ impl RedirectService {
                 MatchType::Exact => url_to_check == source,
                 MatchType::Prefix => url_to_check.starts_with(&source),
                 MatchType::Contains => url_to_check.contains(&source),
-                MatchType::Regex => {
-                    if let Ok(re) = regex::Regex::new(&redirect.source_url) {
-                        re.is_match(&url_to_check)
-                    } else {
-                        false
-                    }
-                }
+                MatchType::Regex => self.compiled(&redirect.source_url)
+                    .is_some_and(|re| re.is_match(&url_to_check)),
             };
 
             if matches {
@@ -96,7 +253,7 @@ impl RedirectService {
     /// Process a redirect and get target URL
     pub fn process_redirect(&mut self, url: &str) -> Option<RedirectResult> {
         if let Some(redirect) = self.find_redirect(url) {
-            let target = redirect.get_target(url);
+            let target = self.target_for(redirect, url);
             let status_code = redirect.redirect_type.status_code();
 
             // Record hit (would need mutable access in real implementation)
@@ -137,6 +294,106 @@ impl RedirectService {
         logs
     }
 
+    /// Suggest regex redirects for groups of 404s sharing a URL structure
+    pub fn suggest_redirects_from_404_log(&self) -> Vec<SuggestedRedirect> {
+        let Ok(date_segment) = regex::Regex::new(r"^(.*?)/\d{4}/\d{2}/(?:\d{2}/)?([^/]+/?)$") else {
+            return vec![];
+        };
+        let Ok(legacy_extension) = regex::Regex::new(r"^(.*/[^/.]+)\.(html?|php|aspx?)$") else {
+            return vec![];
+        };
+
+        // (prefix, kind) -> (urls, hit count)
+        let mut groups: HashMap<(String, &'static str), (Vec<String>, i64)> = HashMap::new();
+
+        for log in self.not_found_log.values().filter(|l| !l.is_ignored && !l.has_redirect) {
+            let path = log.url.split(['?', '#']).next().unwrap_or_default();
+
+            let key = if let Some(caps) = date_segment.captures(path) {
+                (caps[1].to_string(), "date")
+            } else if let Some(caps) = legacy_extension.captures(path) {
+                let dir = caps[1].rsplit_once('/').map(|(dir, _)| dir).unwrap_or_default();
+                (format!("{}|{}", dir, &caps[2]), "extension")
+            } else {
+                continue;
+            };
+
+            let group = groups.entry(key).or_default();
+            group.0.push(log.url.clone());
+            group.1 += log.hit_count;
+        }
+
+        let mut suggestions: Vec<(SuggestedRedirect, i64)> = groups.into_iter()
+            .filter(|(_, (urls, _))| urls.len() >= 3)
+            .map(|((prefix, kind), (mut urls, hits))| {
+                urls.sort();
+                let (source_pattern, target_pattern, reason) = match kind {
+                    "date" => (
+                        format!(r"^{}/\d{{4}}/\d{{2}}/(?:\d{{2}}/)?([^/]+/?)$", regex::escape(&prefix)),
+                        format!("{}/$1", prefix),
+                        format!("{} 404s use a dated permalink structure that no longer exists", urls.len()),
+                    ),
+                    _ => {
+                        let (dir, extension) = prefix.split_once('|').unwrap_or((prefix.as_str(), ""));
+                        (
+                            format!(r"^{}/([^/.]+)\.{}$", regex::escape(dir), regex::escape(extension)),
+                            format!("{}/$1", dir),
+                            format!("{} 404s use a legacy .{} extension", urls.len(), extension),
+                        )
+                    }
+                };
+
+                let suggestion = SuggestedRedirect {
+                    source_pattern,
+                    target_pattern,
+                    match_type: MatchType::Regex,
+                    reason,
+                    confidence: (0.5 + urls.len() as f32 * 0.1).min(0.95),
+                    affected_urls: urls,
+                };
+                (suggestion, hits)
+            })
+            .collect();
+
+        suggestions.sort_by_key(|(suggestion, hits)| {
+            std::cmp::Reverse(suggestion.affected_urls.len() as i64 * hits)
+        });
+        suggestions.into_iter().map(|(suggestion, _)| suggestion).collect()
+    }
+
+    /// Suggest a live target for each logged 404 by URL similarity
+    ///
+    /// Compares paths by Levenshtein distance and only keeps matches with a
+    /// confidence of at least 0.7, most confident first.
+    pub fn suggest_redirects(&self, known_urls: &[String]) -> Vec<RedirectSuggestion> {
+        let known: Vec<(String, &String)> = known_urls.iter().map(|u| (comparable_path(u), u)).collect();
+
+        let mut suggestions: Vec<(RedirectSuggestion, i64)> = self.not_found_log.values()
+            .filter(|log| !log.is_ignored && !log.has_redirect)
+            .filter_map(|log| {
+                let path = comparable_path(&log.url);
+                let (confidence, target) = known.iter()
+                    .filter(|(known_path, _)| *known_path != path)
+                    .map(|(known_path, url)| (url_similarity(&path, known_path), *url))
+                    .max_by(|a, b| a.0.total_cmp(&b.0))?;
+
+                (confidence >= 0.7).then(|| (
+                    RedirectSuggestion {
+                        source_url: log.url.clone(),
+                        suggested_target: target.clone(),
+                        confidence,
+                    },
+                    log.hit_count,
+                ))
+            })
+            .collect();
+
+        suggestions.sort_by(|(a, a_hits), (b, b_hits)| {
+            b.confidence.total_cmp(&a.confidence).then(b_hits.cmp(a_hits))
+        });
+        suggestions.into_iter().map(|(suggestion, _)| suggestion).collect()
+    }
+
     /// Create redirect from 404
     pub fn create_redirect_from_404(&mut self, url: &str, target: &str) -> Redirect {
         let redirect = Redirect::new(
@@ -171,23 +428,81 @@ impl RedirectService {
         }
     }
 
-    /// Update a redirect
-    pub fn update_redirect(&mut self, id: Uuid, source: Option<String>, target: Option<String>, redirect_type: Option<RedirectType>) -> bool {
-        if let Some(redirect) = self.redirects.iter_mut().find(|r| r.id == id) {
-            if let Some(s) = source {
-                redirect.source_url = s;
-            }
-            if let Some(t) = target {
-                redirect.target_url = t;
-            }
-            if let Some(rt) = redirect_type {
-                redirect.redirect_type = rt;
-            }
-            redirect.updated_at = Utc::now();
-            true
+    /// Analyze the SEO impact of deleting a redirect
+    pub fn analyze_redirect_impact(&self, id: Uuid) -> Option<RedirectImpactReport> {
+        let redirect = self.get_redirect(id)?;
+
+        let estimated_page_rank_loss = if redirect.hit_count > 1000 {
+            "High"
+        } else if redirect.hit_count >= 100 {
+            "Medium"
         } else {
-            false
+            "Low"
+        };
+
+        let days_active = (Utc::now() - redirect.created_at).num_days().max(0);
+
+        let source = redirect.source_url.trim_end_matches('/');
+        let is_in_sitemap = self.sitemap_urls.iter().any(|u| {
+            let u = u.trim_end_matches('/');
+            u == source || (source.starts_with('/') && u.ends_with(source))
+        });
+
+        let recommendation = match estimated_page_rank_loss {
+            "High" if days_active < 365 => {
+                "Keep this redirect: it still receives significant traffic. High-impact redirects should stay in place for at least 12 months."
+            }
+            "High" => "This redirect still receives significant traffic. Consider keeping it in place.",
+            "Medium" => "Review incoming links before deleting; consider keeping the redirect for 12 months.",
+            _ => "Low impact: this redirect can be safely deleted.",
+        };
+
+        Some(RedirectImpactReport {
+            id: redirect.id,
+            source_url: redirect.source_url.clone(),
+            target_url: redirect.target_url.clone(),
+            hit_count: redirect.hit_count,
+            last_hit: redirect.last_hit,
+            days_active,
+            estimated_page_rank_loss,
+            is_in_sitemap,
+            recommendation,
+        })
+    }
+
+    /// Update a redirect, keeping the old rule if the change would close a loop
+    ///
+    /// Returns `Ok(false)` when no redirect has the given ID.
+    pub fn update_redirect(
+        &mut self,
+        id: Uuid,
+        source: Option<String>,
+        target: Option<String>,
+        redirect_type: Option<RedirectType>,
+    ) -> Result<bool, RedirectError> {
+        let Some(index) = self.redirects.iter().position(|r| r.id == id) else {
+            return Ok(false);
+        };
+        let previous = self.redirects[index].clone();
+
+        let redirect = &mut self.redirects[index];
+        if let Some(s) = source {
+            redirect.source_url = s;
+        }
+        if let Some(t) = target {
+            redirect.target_url = t;
+        }
+        if let Some(rt) = redirect_type {
+            redirect.redirect_type = rt;
+        }
+        redirect.updated_at = Utc::now();
+
+        if let Some(chain) = self.loop_through(id) {
+            self.redirects[index] = previous;
+            return Err(RedirectError::RedirectLoop { chain });
         }
+
+        Ok(true)
     }
 
     /// Enable/disable a redirect
@@ -225,7 +540,7 @@ impl RedirectService {
 
             let parts: Vec<&str> = line.split(',').collect();
             if parts.len() < 2 {
-                errors.push(format!("Line {}: Invalid format", line_num + 1));
+                errors.push(import_error(line_num + 1, line, "Invalid format"));
                 skipped += 1;
                 continue;
             }
@@ -248,7 +563,7 @@ impl RedirectService {
 
             // Check for duplicate
             if self.redirects.iter().any(|r| r.source_url == source) {
-                errors.push(format!("Line {}: Duplicate source URL", line_num + 1));
+                errors.push(import_error(line_num + 1, line, "Duplicate source URL"));
                 skipped += 1;
                 continue;
             }
@@ -266,6 +581,143 @@ impl RedirectService {
         ImportResult { imported, skipped, errors }
     }
 
+    /// Import redirects from Apache `.htaccess` rules
+    ///
+    /// Reads `RewriteRule` lines carrying an `R` or `G` flag, plus `Redirect` and
+    /// `RedirectMatch` directives. Other directives are ignored.
+    pub fn import_htaccess(&mut self, content: &str) -> ImportResult {
+        let mut result = ImportResult { imported: 0, skipped: 0, errors: Vec::new() };
+
+        for (line_num, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let tokens = split_directive(line);
+            let Some(directive) = tokens.first() else { continue };
+
+            let parsed = match directive.to_lowercase().as_str() {
+                "rewriterule" => parse_rewrite_rule(&tokens[1..]),
+                "redirect" | "redirectpermanent" | "redirecttemp" => parse_redirect_directive(&tokens),
+                "redirectmatch" => parse_redirect_directive(&tokens)
+                    .map(|((pattern, _), target, redirect_type)| (source_from_pattern(&pattern), target, redirect_type)),
+                _ => continue,
+            };
+
+            match parsed {
+                Ok(((source, match_type), target, redirect_type)) => {
+                    self.import_rule(line_num + 1, line, source, match_type, target, redirect_type, &mut result);
+                }
+                Err(message) => {
+                    result.errors.push(import_error(line_num + 1, line, &message));
+                    result.skipped += 1;
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Import redirects from nginx configuration
+    ///
+    /// Reads `return` directives inside `location` blocks and `rewrite` directives
+    /// with the `permanent` or `redirect` flag.
+    pub fn import_nginx(&mut self, content: &str) -> ImportResult {
+        let mut result = ImportResult { imported: 0, skipped: 0, errors: Vec::new() };
+        let mut locations: Vec<Option<(String, MatchType)>> = Vec::new();
+
+        for (line_num, raw_line) in content.lines().enumerate() {
+            let line = raw_line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let mut statement = String::new();
+            for c in line.chars() {
+                if !matches!(c, '{' | '}' | ';') {
+                    statement.push(c);
+                    continue;
+                }
+
+                let tokens = split_directive(statement.trim());
+                statement.clear();
+
+                match c {
+                    '{' => {
+                        let location = match tokens.first().map(String::as_str) {
+                            Some("location") => parse_nginx_location(&tokens[1..]),
+                            _ => None,
+                        };
+                        locations.push(location);
+                    }
+                    '}' => {
+                        locations.pop();
+                    }
+                    _ => {
+                        let parsed = match tokens.first().map(String::as_str) {
+                            Some("return") => match locations.iter().rev().flatten().next() {
+                                Some(location) => parse_nginx_return(&tokens[1..])
+                                    .map(|(target, redirect_type)| (location.clone(), target, redirect_type)),
+                                None => Err("return directive outside a location block".to_string()),
+                            },
+                            Some("rewrite") => parse_nginx_rewrite(&tokens[1..]),
+                            _ => continue,
+                        };
+
+                        match parsed {
+                            Ok(((source, match_type), target, redirect_type)) => {
+                                self.import_rule(line_num + 1, line, source, match_type, target, redirect_type, &mut result);
+                            }
+                            Err(message) => {
+                                result.errors.push(import_error(line_num + 1, line, &message));
+                                result.skipped += 1;
+                            }
+                        }
+                    }
+                }
+            }
+
+            if !statement.trim().is_empty() {
+                result.errors.push(import_error(line_num + 1, line, "Directive is missing a terminating ';'"));
+                result.skipped += 1;
+            }
+        }
+
+        result
+    }
+
+    /// Add one imported rule, skipping duplicates and loops
+    #[allow(clippy::too_many_arguments)]
+    fn import_rule(
+        &mut self,
+        line: usize,
+        text: &str,
+        source: String,
+        match_type: MatchType,
+        target: String,
+        redirect_type: RedirectType,
+        result: &mut ImportResult,
+    ) {
+        if self.redirects.iter().any(|r| r.source_url == source) {
+            result.errors.push(import_error(line, text, "Duplicate source URL"));
+            result.skipped += 1;
+            return;
+        }
+
+        let mut redirect = Redirect::new(source, target, redirect_type);
+        redirect.is_regex = match_type == MatchType::Regex;
+        redirect.match_type = match_type;
+
+        match self.add_redirect(redirect) {
+            Ok(()) => result.imported += 1,
+            Err(err) => {
+                result.errors.push(import_error(line, text, &err.to_string()));
+                result.skipped += 1;
+            }
+        }
+    }
+
     /// Export redirects to CSV format
     pub fn export_csv(&self) -> String {
         let mut csv = String::from("source,target,type\n");
@@ -282,6 +734,118 @@ impl RedirectService {
         csv
     }
 
+    /// Export active redirects as Apache mod_rewrite rules
+    pub fn export_htaccess(&self) -> String {
+        let mut out = String::from("# BEGIN RustSEO Redirects\n");
+        out.push_str(&format!("# Generated by RustSEO on {}\n", Utc::now().format("%Y-%m-%d %H:%M:%S UTC")));
+        out.push_str("<IfModule mod_rewrite.c>\n");
+        out.push_str("RewriteEngine On\n");
+
+        for redirect in self.redirects.iter().filter(|r| r.is_active) {
+            // Per-directory rewrite rules see the path without its leading slash
+            let path = redirect.source_url.trim_start_matches('/');
+            let pattern = match redirect.match_type {
+                MatchType::Exact => format!("^{}$", regex::escape(path)),
+                MatchType::Prefix => format!("^{}", regex::escape(path)),
+                MatchType::Contains => regex::escape(path),
+                MatchType::Regex => match redirect.source_url.strip_prefix("^/") {
+                    Some(rest) => format!("^{}", rest),
+                    None => redirect.source_url.trim_start_matches('/').to_string(),
+                },
+            };
+            let pattern = pattern.replace(' ', "\\ ");
+
+            let mut flags = Vec::new();
+            if self.settings.case_insensitive {
+                flags.push("NC".to_string());
+            }
+            let target = match redirect.redirect_type {
+                RedirectType::Gone => {
+                    flags.push("G".to_string());
+                    "-".to_string()
+                }
+                RedirectType::LegalRestriction => {
+                    flags.push("R=451".to_string());
+                    "-".to_string()
+                }
+                redirect_type => {
+                    flags.push(format!("R={}", redirect_type.status_code()));
+                    redirect.target_url.replace(' ', "%20")
+                }
+            };
+            flags.push("L".to_string());
+
+            out.push_str(&format!("RewriteRule {} {} [{}]\n", pattern, target, flags.join(",")));
+        }
+
+        out.push_str("</IfModule>\n");
+        out.push_str("# END RustSEO Redirects\n");
+        out
+    }
+
+    /// Export active redirects as nginx `location` blocks
+    pub fn export_nginx(&self) -> String {
+        let mut out = format!(
+            "# RustSEO redirects, generated {}\n",
+            Utc::now().format("%Y-%m-%d %H:%M:%S UTC")
+        );
+        let regex_modifier = if self.settings.case_insensitive { "~*" } else { "~" };
+
+        for redirect in self.redirects.iter().filter(|r| r.is_active) {
+            let location = match redirect.match_type {
+                MatchType::Exact => format!("= {}", redirect.source_url),
+                MatchType::Prefix => format!("^~ {}", redirect.source_url),
+                MatchType::Contains => format!("{} {}", regex_modifier, regex::escape(&redirect.source_url)),
+                MatchType::Regex => format!("{} \"{}\"", regex_modifier, redirect.source_url),
+            };
+
+            let status = redirect.redirect_type.status_code();
+            let action = if redirect.redirect_type.is_redirect() {
+                format!("return {} {};", status, redirect.target_url)
+            } else {
+                format!("return {};", status)
+            };
+
+            out.push_str(&format!("location {} {{\n    {}\n}}\n", location, action));
+        }
+
+        out
+    }
+
+    /// Follow redirects from a URL hop by hop, stopping at a loop
+    pub fn resolve_chain(&self, url: &str) -> RedirectChain {
+        let mut hops: Vec<RedirectResult> = Vec::new();
+        let mut visited = vec![url.to_string()];
+        let mut current = url.to_string();
+        let mut is_loop = false;
+
+        // Hard stop well past the configured limit so the caller can still report it
+        let max_hops = self.settings.max_redirect_chain.max(1) * 4;
+
+        while hops.len() < max_hops {
+            let Some(redirect) = self.find_redirect(&current) else {
+                break;
+            };
+
+            let target = self.target_for(redirect, &current);
+            hops.push(RedirectResult {
+                target_url: target.clone(),
+                status_code: redirect.redirect_type.status_code(),
+                redirect_id: redirect.id,
+            });
+
+            if visited.contains(&target) {
+                is_loop = true;
+                break;
+            }
+
+            visited.push(target.clone());
+            current = target;
+        }
+
+        RedirectChain { hops, is_loop }
+    }
+
     /// Test a URL against redirects
     pub fn test_url(&self, url: &str) -> TestResult {
         if let Some(redirect) = self.find_redirect(url) {
@@ -289,7 +853,7 @@ impl RedirectService {
                 matches: true,
                 redirect_id: Some(redirect.id),
                 source: Some(redirect.source_url.clone()),
-                target: Some(redirect.get_target(url)),
+                target: Some(self.target_for(redirect, url)),
                 status_code: Some(redirect.redirect_type.status_code()),
             }
         } else {
@@ -311,17 +875,302 @@ impl Default for RedirectService {
 }
 
 /// Result of processing a redirect
+#[derive(Debug, Clone)]
 pub struct RedirectResult {
     pub target_url: String,
     pub status_code: u16,
     pub redirect_id: Uuid,
 }
 
+/// Redirect hops followed from a URL
+#[derive(Debug, Clone)]
+pub struct RedirectChain {
+    pub hops: Vec<RedirectResult>,
+    pub is_loop: bool,
+}
+
+impl RedirectChain {
+    /// URL the chain ends on
+    pub fn final_url(&self) -> Option<&str> {
+        self.hops.last().map(|hop| hop.target_url.as_str())
+    }
+}
+
+/// Redirect rule rejected by the service
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum RedirectError {
+    #[error("redirect would create a loop: {}", format_loop(.chain))]
+    RedirectLoop { chain: Vec<String> },
+}
+
+/// Loop chain as `a -> b -> a`
+pub fn format_loop(chain: &[String]) -> String {
+    let mut parts: Vec<&str> = chain.iter().map(String::as_str).collect();
+    if let Some(first) = chain.first() {
+        parts.push(first);
+    }
+    parts.join(" -> ")
+}
+
+/// Redirect suggested from patterns in the 404 log
+#[derive(Debug, Clone, Serialize)]
+pub struct SuggestedRedirect {
+    pub source_pattern: String,
+    pub target_pattern: String,
+    pub match_type: MatchType,
+    pub reason: String,
+    pub confidence: f32,
+    pub affected_urls: Vec<String>,
+}
+
+/// Live URL suggested as the target for a 404
+#[derive(Debug, Clone, Serialize)]
+pub struct RedirectSuggestion {
+    pub source_url: String,
+    pub suggested_target: String,
+    /// Path similarity from 0 to 1
+    pub confidence: f32,
+}
+
+/// Lowercased path of a URL without host, query or trailing slash
+fn comparable_path(url: &str) -> String {
+    let without_scheme = url.split_once("://").map(|(_, rest)| rest).unwrap_or(url);
+    let path = if url.contains("://") {
+        without_scheme.find('/').map(|i| &without_scheme[i..]).unwrap_or("/")
+    } else {
+        without_scheme
+    };
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    path.trim_end_matches('/').to_lowercase()
+}
+
+/// Similarity of two strings from 0 to 1, based on Levenshtein distance
+fn url_similarity(a: &str, b: &str) -> f32 {
+    let max_len = a.chars().count().max(b.chars().count());
+    if max_len == 0 {
+        return 1.0;
+    }
+    1.0 - levenshtein(a, b) as f32 / max_len as f32
+}
+
+/// Levenshtein edit distance between two strings
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
 /// Result of import operation
 pub struct ImportResult {
     pub imported: usize,
     pub skipped: usize,
-    pub errors: Vec<String>,
+    pub errors: Vec<ImportError>,
+}
+
+fn import_error(line: usize, source: &str, message: &str) -> ImportError {
+    ImportError {
+        line: line as i32,
+        source: source.to_string(),
+        message: message.to_string(),
+    }
+}
+
+/// Split a config directive into whitespace-separated tokens
+///
+/// Honours double quotes and backslash-escaped spaces.
+fn split_directive(line: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            '\\' if chars.peek() == Some(&' ') => {
+                current.push(' ');
+                chars.next();
+            }
+            c if c.is_whitespace() && !in_quotes => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    tokens
+}
+
+/// The literal path an anchored pattern matches, if it uses no regex syntax
+fn literal_pattern_path(pattern: &str) -> Option<String> {
+    let inner = pattern.strip_prefix('^')?.strip_suffix('$')?;
+    let mut path = String::new();
+    let mut chars = inner.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => path.push(chars.next().filter(|c| c.is_ascii_punctuation())?),
+            '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|' | '^' | '$' => return None,
+            c => path.push(c),
+        }
+    }
+
+    Some(path)
+}
+
+/// Source URL and match type for a rewrite pattern
+///
+/// Plain anchored paths become exact matches; anything else is kept as a regex.
+fn source_from_pattern(pattern: &str) -> (String, MatchType) {
+    match literal_pattern_path(pattern) {
+        Some(path) => (path, MatchType::Exact),
+        None => (pattern.to_string(), MatchType::Regex),
+    }
+}
+
+type ParsedRule = ((String, MatchType), String, RedirectType);
+
+/// Parse the arguments of an Apache `RewriteRule`
+fn parse_rewrite_rule(args: &[String]) -> Result<ParsedRule, String> {
+    let (pattern, target) = match args {
+        [pattern, target, ..] => (pattern, target),
+        _ => return Err("RewriteRule needs a pattern and a target".to_string()),
+    };
+
+    let flags: Vec<String> = match args.get(2) {
+        Some(flags) if flags.starts_with('[') && flags.ends_with(']') => flags[1..flags.len() - 1]
+            .split(',')
+            .map(|f| f.trim().to_uppercase())
+            .collect(),
+        Some(flags) => return Err(format!("Malformed flag list '{}'", flags)),
+        None => Vec::new(),
+    };
+
+    let mut redirect_type = None;
+    for flag in &flags {
+        if flag == "G" || flag == "GONE" {
+            redirect_type = Some(RedirectType::Gone);
+        } else if flag == "R" || flag == "REDIRECT" {
+            redirect_type = Some(RedirectType::Temporary);
+        } else if let Some(code) = flag.strip_prefix("R=").or_else(|| flag.strip_prefix("REDIRECT=")) {
+            let code = match code {
+                "PERMANENT" => 301,
+                "TEMP" => 302,
+                code => code.parse().map_err(|_| format!("Invalid redirect status '{}'", code))?,
+            };
+            redirect_type = Some(
+                RedirectType::from_status_code(code)
+                    .ok_or_else(|| format!("Unsupported redirect status {}", code))?,
+            );
+        }
+    }
+
+    let redirect_type = redirect_type
+        .ok_or_else(|| "RewriteRule has no R or G flag; internal rewrites are not redirects".to_string())?;
+    let target = if redirect_type.is_redirect() {
+        if target == "-" {
+            return Err("Redirect rule has no target".to_string());
+        }
+        target.clone()
+    } else {
+        String::new()
+    };
+
+    // Per-directory patterns match the path without its leading slash
+    let pattern = match pattern.strip_prefix('^') {
+        Some(rest) if !rest.starts_with('/') => format!("^/{}", rest),
+        _ => pattern.clone(),
+    };
+
+    Ok((source_from_pattern(&pattern), target, redirect_type))
+}
+
+/// Parse an Apache `Redirect` style directive: `Redirect [status] source [target]`
+fn parse_redirect_directive(tokens: &[String]) -> Result<ParsedRule, String> {
+    let directive = tokens[0].to_lowercase();
+    let args = &tokens[1..];
+
+    let (redirect_type, rest) = match directive.as_str() {
+        "redirectpermanent" => (RedirectType::Permanent, args),
+        "redirecttemp" => (RedirectType::Temporary, args),
+        _ => match args.first().map(|a| a.to_lowercase()) {
+            Some(status) if status == "permanent" => (RedirectType::Permanent, &args[1..]),
+            Some(status) if status == "temp" => (RedirectType::Temporary, &args[1..]),
+            Some(status) if status == "gone" => (RedirectType::Gone, &args[1..]),
+            Some(status) if status.chars().all(|c| c.is_ascii_digit()) => {
+                let code: u16 = status.parse().map_err(|_| format!("Invalid redirect status '{}'", status))?;
+                let redirect_type = RedirectType::from_status_code(code)
+                    .ok_or_else(|| format!("Unsupported redirect status {}", code))?;
+                (redirect_type, &args[1..])
+            }
+            _ => (RedirectType::Temporary, args),
+        },
+    };
+
+    match (rest, redirect_type.is_redirect()) {
+        ([source, target], true) => Ok(((source.clone(), MatchType::Prefix), target.clone(), redirect_type)),
+        ([source], false) => Ok(((source.clone(), MatchType::Prefix), String::new(), redirect_type)),
+        _ => Err(format!("{} needs a source and a target", tokens[0])),
+    }
+}
+
+/// Source URL and match type for an nginx `location` block
+fn parse_nginx_location(args: &[String]) -> Option<(String, MatchType)> {
+    match args {
+        [modifier, path] if modifier == "=" => Some((path.clone(), MatchType::Exact)),
+        [modifier, path] if modifier == "^~" => Some((path.clone(), MatchType::Prefix)),
+        [modifier, pattern] if modifier == "~" || modifier == "~*" => Some(source_from_pattern(pattern)),
+        [path] if !path.starts_with('@') => Some((path.clone(), MatchType::Prefix)),
+        _ => None,
+    }
+}
+
+/// Parse the arguments of an nginx `return` directive
+fn parse_nginx_return(args: &[String]) -> Result<(String, RedirectType), String> {
+    let code: u16 = match args.first() {
+        Some(code) => code.parse().map_err(|_| format!("Invalid return code '{}'", code))?,
+        None => return Err("return directive needs a status code".to_string()),
+    };
+    let redirect_type = RedirectType::from_status_code(code)
+        .ok_or_else(|| format!("return {} is not a redirect", code))?;
+
+    match args.get(1) {
+        Some(target) => Ok((target.clone(), redirect_type)),
+        None if !redirect_type.is_redirect() => Ok((String::new(), redirect_type)),
+        None => Err(format!("return {} needs a target URL", code)),
+    }
+}
+
+/// Parse the arguments of an nginx `rewrite` directive
+fn parse_nginx_rewrite(args: &[String]) -> Result<ParsedRule, String> {
+    let (pattern, target) = match args {
+        [pattern, target, ..] => (pattern, target),
+        _ => return Err("rewrite needs a pattern and a replacement".to_string()),
+    };
+
+    let redirect_type = match args.get(2).map(String::as_str) {
+        Some("permanent") => RedirectType::Permanent,
+        Some("redirect") => RedirectType::Temporary,
+        None if target.starts_with("http://") || target.starts_with("https://") => RedirectType::Temporary,
+        _ => return Err("rewrite without permanent or redirect flag is an internal rewrite".to_string()),
+    };
+
+    Ok((source_from_pattern(pattern), target.clone(), redirect_type))
 }
 
 /// Result of URL test
@@ -332,3 +1181,197 @@ pub struct TestResult {
     pub target: Option<String>,
     pub status_code: Option<u16>,
 }
+
+/// Impact report shown before deleting a redirect
+#[derive(Debug, Clone, Serialize)]
+pub struct RedirectImpactReport {
+    pub id: Uuid,
+    pub source_url: String,
+    pub target_url: String,
+    pub hit_count: i64,
+    pub last_hit: Option<DateTime<Utc>>,
+    pub days_active: i64,
+    pub estimated_page_rank_loss: &'static str,
+    pub is_in_sitemap: bool,
+    pub recommendation: &'static str,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_redirect_loops() {
+        let mut service = RedirectService::new();
+        service.add_301("/a", "/b").unwrap();
+        service.add_301("/b", "/c").unwrap();
+        // Stored rules may already loop, e.g. ones saved before loops were checked
+        service.redirects.push(Redirect::new("/c".to_string(), "/a".to_string(), RedirectType::Permanent));
+        service.add_301("/x", "/y").unwrap();
+
+        let loops = service.detect_redirect_loops();
+        assert_eq!(loops, vec![vec!["/a".to_string(), "/b".to_string(), "/c".to_string()]]);
+    }
+
+    #[test]
+    fn test_export_htaccess_and_nginx() {
+        let mut service = RedirectService::new();
+        service.add_301("/old-page.html", "/new-page").unwrap();
+        service.add_302("/sale", "/offers").unwrap();
+        let mut regex = Redirect::new(r"^/blog/(\d+)$".to_string(), "/posts/$1".to_string(), RedirectType::Permanent);
+        regex.match_type = MatchType::Regex;
+        service.add_redirect(regex).unwrap();
+
+        let htaccess = service.export_htaccess();
+        assert!(htaccess.contains("RewriteEngine On"));
+        assert!(htaccess.contains(r"RewriteRule ^old\-page\.html$ /new-page [NC,R=301,L]"));
+        assert!(htaccess.contains("RewriteRule ^sale$ /offers [NC,R=302,L]"));
+        assert!(htaccess.contains(r"RewriteRule ^blog/(\d+)$ /posts/$1 [NC,R=301,L]"));
+
+        let nginx = service.export_nginx();
+        assert!(nginx.contains("location = /old-page.html {\n    return 301 /new-page;\n}"));
+        assert!(nginx.contains("return 302 /offers;"));
+    }
+
+    #[test]
+    fn test_import_htaccess() {
+        let mut service = RedirectService::new();
+        let result = service.import_htaccess(
+            "# BEGIN RustSEO\n\
+             RewriteEngine On\n\
+             RewriteRule ^old\\-page\\.html$ /new-page [R=301,L]\n\
+             RewriteRule ^blog/(\\d+)$ /posts/$1 [R=302,L]\n\
+             RewriteRule ^gone$ - [G,L]\n\
+             RewriteRule ^internal$ /index.php [L]\n\
+             RewriteRule ^broken$\n",
+        );
+
+        assert_eq!(result.imported, 3);
+        assert_eq!(result.skipped, 2);
+        assert_eq!(result.errors.iter().map(|e| e.line).collect::<Vec<_>>(), vec![6, 7]);
+
+        let redirects = service.get_redirects();
+        assert_eq!(redirects[0].source_url, "/old-page.html");
+        assert_eq!(redirects[0].match_type, MatchType::Exact);
+        assert_eq!(redirects[1].source_url, r"^/blog/(\d+)$");
+        assert_eq!(redirects[1].match_type, MatchType::Regex);
+        assert_eq!(redirects[1].redirect_type, RedirectType::Temporary);
+        assert_eq!(redirects[2].redirect_type, RedirectType::Gone);
+    }
+
+    #[test]
+    fn test_import_nginx() {
+        let mut service = RedirectService::new();
+        let result = service.import_nginx(
+            "location = /old { return 301 /new; }\n\
+             location ~ ^/blog/(\\d+)$ {\n\
+                 return 308 /posts/$1;\n\
+             }\n\
+             rewrite ^/sale$ /offers redirect;\n\
+             rewrite ^/app/(.*)$ /index.php?q=$1 last;\n\
+             return 301 /nowhere;\n",
+        );
+
+        assert_eq!(result.imported, 3);
+        assert_eq!(result.skipped, 2);
+
+        let redirects = service.get_redirects();
+        assert_eq!(redirects[0].source_url, "/old");
+        assert_eq!(redirects[1].source_url, r"^/blog/(\d+)$");
+        assert_eq!(redirects[1].redirect_type, RedirectType::PermanentPreserve);
+        assert_eq!(redirects[2].source_url, "/sale");
+        assert_eq!(redirects[2].match_type, MatchType::Exact);
+    }
+
+    #[test]
+    fn test_resolve_chain() {
+        let mut service = RedirectService::new();
+        service.add_301("/a", "/b").unwrap();
+        service.add_301("/b", "/c").unwrap();
+        service.add_302("/c", "/d").unwrap();
+
+        let chain = service.resolve_chain("/a");
+        let targets: Vec<&str> = chain.hops.iter().map(|hop| hop.target_url.as_str()).collect();
+        assert_eq!(targets, vec!["/b", "/c", "/d"]);
+        assert_eq!(chain.hops[2].status_code, 302);
+        assert!(!chain.is_loop);
+
+        // Hops past the limit are kept so the caller can report them
+        let service = service.with_settings(RedirectSettings { max_redirect_chain: 2, ..RedirectSettings::default() });
+        assert_eq!(service.resolve_chain("/a").hops.len(), 3);
+        assert!(service.resolve_chain("/d").hops.is_empty());
+    }
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("same", "same"), 0);
+    }
+
+    #[test]
+    fn test_suggest_redirects() {
+        let mut service = RedirectService::new();
+        service.log_404("/blog/seo-tips-for-begginers", None, None);
+        service.log_404("/completely-unrelated", None, None);
+
+        let known = vec![
+            "https://example.com/blog/seo-tips-for-beginners/".to_string(),
+            "https://example.com/about/".to_string(),
+        ];
+        let suggestions = service.suggest_redirects(&known);
+
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].source_url, "/blog/seo-tips-for-begginers");
+        assert_eq!(suggestions[0].suggested_target, known[0]);
+        assert!(suggestions[0].confidence >= 0.9);
+    }
+
+    #[test]
+    fn test_add_redirect_rejects_loop() {
+        let mut service = RedirectService::new();
+        service.add_redirect(Redirect::new("/a".to_string(), "/b".to_string(), RedirectType::Permanent)).unwrap();
+
+        let err = service
+            .add_redirect(Redirect::new("/b".to_string(), "/a".to_string(), RedirectType::Permanent))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "redirect would create a loop: /a -> /b -> /a");
+        assert_eq!(service.get_redirects().len(), 1);
+
+        let id = service.get_redirects()[0].id;
+        service.add_301("/c", "/a").unwrap();
+        assert!(service.update_redirect(id, None, Some("/c".to_string()), None).is_err());
+        assert_eq!(service.get_redirect(id).unwrap().target_url, "/b");
+    }
+
+    #[test]
+    fn test_add_301_rejects_loop() {
+        let mut service = RedirectService::new();
+        service.add_301("/a", "/b").unwrap();
+        assert!(matches!(service.add_301("/b", "/a"), Err(RedirectError::RedirectLoop { .. })));
+        assert!(service.add_302("/b", "/a").is_err());
+        assert_eq!(service.get_redirects().len(), 1);
+    }
+
+    #[test]
+    fn test_regex_redirect_with_mixed_case_source() {
+        let mut service = RedirectService::new();
+        let mut regex = Redirect::new(r"^/Blog/(\d+)/([a-z-]+)$".to_string(), "/posts/$2-$1".to_string(), RedirectType::Permanent);
+        regex.match_type = MatchType::Regex;
+        service.add_redirect(regex).unwrap();
+
+        let result = service.process_redirect("/BLOG/2024/Hello-World").unwrap();
+        assert_eq!(result.target_url, "/posts/hello-world-2024");
+        assert_eq!(service.test_url("/blog/7/Post").target.as_deref(), Some("/posts/post-7"));
+    }
+
+    #[test]
+    fn test_add_redirect_rejects_regex_loop() {
+        let mut service = RedirectService::new();
+        service.add_301("/archive/1", "/posts/1").unwrap();
+
+        let mut regex = Redirect::new(r"^/posts/(\d+)$".to_string(), "/archive/$1".to_string(), RedirectType::Permanent);
+        regex.match_type = MatchType::Regex;
+        assert!(service.add_redirect(regex).is_err());
+    }
+}
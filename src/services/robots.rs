@@ -2,7 +2,8 @@
 //!
 //! Service for managing robots.txt file.
 
-use crate::models::robots::{RobotsTxt, RobotsRule, RobotsTxtSettings, ai_crawlers};
+use crate::models::robots::{RobotsAuditReport, RobotsTxt, RobotsRule, RobotsTxtSettings, ai_crawlers};
+use serde::{Deserialize, Serialize};
 
 /// Service for managing robots.txt
 pub struct RobotsService {
@@ -29,6 +30,11 @@ impl RobotsService {
             return String::new();
         }
 
+        self.build().to_string()
+    }
+
+    /// Robots.txt rules the settings produce
+    pub fn build(&self) -> RobotsTxt {
         let mut robots = RobotsTxt::default_rules(&self.site_url);
 
         // Block AI crawlers if enabled
@@ -53,7 +59,60 @@ impl RobotsService {
             robots.custom_content = Some(self.settings.custom_rules.clone());
         }
 
-        robots.to_string()
+        robots
+    }
+
+    /// Generated rules with overlays merged on top, in priority order
+    pub fn apply_overrides(&self, overlays: &[RobotsTxt]) -> RobotsTxt {
+        overlays.iter().fold(self.build(), |robots, overlay| robots.merge(overlay))
+    }
+
+    /// Compare the configured rules with a live robots.txt
+    pub fn audit(configured: &RobotsTxt, live_content: &str) -> RobotsAuditReport {
+        // Custom rules are kept as raw text, so compare the rendered file
+        let configured = &RobotsTxt::parse(&configured.to_string());
+        let live = RobotsTxt::parse(live_content);
+
+        let directives = |robots: &RobotsTxt| -> Vec<String> {
+            let mut directives = Vec::new();
+            for rule in &robots.rules {
+                let agent = rule.user_agent.to_lowercase();
+                directives.extend(rule.allow.iter().map(|path| format!("[{}] Allow: {}", agent, path)));
+                directives.extend(rule.disallow.iter().map(|path| format!("[{}] Disallow: {}", agent, path)));
+            }
+            directives
+        };
+        let configured_directives = directives(configured);
+        let live_directives = directives(&live);
+
+        let missing_rules: Vec<String> = configured_directives.iter()
+            .filter(|d| d.contains("] Disallow: ") && !live_directives.contains(d))
+            .cloned()
+            .collect();
+        let extra_rules: Vec<String> = live_directives.iter()
+            .filter(|d| !configured_directives.contains(d))
+            .cloned()
+            .collect();
+
+        let missing_sitemaps: Vec<String> = configured.sitemaps.iter()
+            .filter(|s| !live.sitemaps.contains(s))
+            .cloned()
+            .collect();
+        let extra_sitemaps: Vec<String> = live.sitemaps.iter()
+            .filter(|s| !configured.sitemaps.contains(s))
+            .cloned()
+            .collect();
+
+        RobotsAuditReport {
+            in_sync: missing_rules.is_empty()
+                && extra_rules.is_empty()
+                && missing_sitemaps.is_empty()
+                && extra_sitemaps.is_empty(),
+            missing_rules,
+            extra_rules,
+            missing_sitemaps,
+            extra_sitemaps,
+        }
     }
 
     /// Generate robots.txt from custom configuration
@@ -66,40 +125,100 @@ impl RobotsService {
         RobotsTxt::parse(content)
     }
 
-    /// Validate robots.txt
+    /// Validate robots.txt line by line
     pub fn validate(&self, content: &str) -> ValidationResult {
         let mut errors = Vec::new();
         let mut warnings = Vec::new();
-
-        let robots = RobotsTxt::parse(content);
-
-        // Check for empty rules
-        if robots.rules.is_empty() {
-            warnings.push("No user-agent rules defined".to_string());
-        }
-
-        // Check for conflicting rules
-        for rule in &robots.rules {
-            if rule.user_agent.is_empty() {
-                errors.push("Empty user-agent found".to_string());
+        let error = |line: usize, message: String| RobotsError { line: line as i32, message };
+        let warning = |line: usize, message: String| RobotsWarning { line: line as i32, message };
+
+        // Agents seen in earlier groups, with the line that named them
+        let mut seen_agents: Vec<(String, usize)> = Vec::new();
+        let mut group = RobotsGroup::default();
+        let mut in_agent_lines = false;
+
+        for (index, raw_line) in content.lines().enumerate() {
+            let line_num = index + 1;
+            let line = raw_line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
             }
 
-            // Check for both allow and disallow of same path
-            for allow in &rule.allow {
-                if rule.disallow.contains(allow) {
-                    warnings.push(format!(
-                        "Conflicting rules for path '{}' in {}",
-                        allow, rule.user_agent
-                    ));
+            let Some((directive, value)) = line.split_once(':') else {
+                errors.push(error(line_num, format!("Expected 'directive: value', found '{}'", line)));
+                continue;
+            };
+            let directive = directive.trim().to_lowercase();
+            let value = value.trim();
+
+            match directive.as_str() {
+                "user-agent" => {
+                    if !in_agent_lines {
+                        group.finish(&mut warnings);
+                        group = RobotsGroup::default();
+                    }
+                    in_agent_lines = true;
+
+                    if value.is_empty() {
+                        errors.push(error(line_num, "Empty user-agent found".to_string()));
+                        continue;
+                    }
+
+                    let agent = value.to_lowercase();
+                    if let Some((_, first_line)) = seen_agents.iter().find(|(a, _)| *a == agent) {
+                        warnings.push(warning(line_num, format!(
+                            "Duplicate User-agent block for '{}' (first declared on line {}); crawlers only obey one group",
+                            value, first_line
+                        )));
+                    } else {
+                        seen_agents.push((agent, line_num));
+                    }
+                    group.agents.push(value.to_string());
+                }
+                "allow" | "disallow" => {
+                    in_agent_lines = false;
+
+                    if group.agents.is_empty() {
+                        errors.push(error(line_num, format!(
+                            "{} rule appears before any User-agent line",
+                            if directive == "allow" { "Allow" } else { "Disallow" }
+                        )));
+                        continue;
+                    }
+
+                    if !value.is_empty() && !value.starts_with('/') && !value.starts_with('*') {
+                        errors.push(error(line_num, format!("Path '{}' must start with '/' or '*'", value)));
+                        continue;
+                    }
+
+                    let (this, other) = if directive == "allow" {
+                        (&mut group.allow, &group.disallow)
+                    } else {
+                        (&mut group.disallow, &group.allow)
+                    };
+                    if !value.is_empty() && other.iter().any(|(path, _)| path == value) {
+                        warnings.push(warning(line_num, format!(
+                            "Conflicting rules for path '{}' in {}",
+                            value,
+                            group.agents.join(", ")
+                        )));
+                    }
+                    this.push((value.to_string(), line_num));
+                }
+                "sitemap" => {
+                    if !value.starts_with("http://") && !value.starts_with("https://") {
+                        errors.push(error(line_num, format!("Invalid sitemap URL: {}", value)));
+                    }
+                }
+                _ => {
+                    in_agent_lines = false;
                 }
             }
         }
+        group.finish(&mut warnings);
 
-        // Check sitemap URLs
-        for sitemap in &robots.sitemaps {
-            if !sitemap.starts_with("http://") && !sitemap.starts_with("https://") {
-                errors.push(format!("Invalid sitemap URL: {}", sitemap));
-            }
+        if seen_agents.is_empty() {
+            warnings.push(warning(0, "No user-agent rules defined".to_string()));
         }
 
         ValidationResult {
@@ -111,34 +230,71 @@ impl RobotsService {
 
     /// Check if a path is allowed for a user agent
     pub fn is_allowed(&self, content: &str, path: &str, user_agent: &str) -> bool {
-        let robots = RobotsTxt::parse(content);
-
-        // Find matching rule
-        let rule = robots.rules.iter()
-            .find(|r| r.user_agent == user_agent || r.user_agent == "*")
-            .or_else(|| robots.rules.iter().find(|r| r.user_agent == "*"));
+        Self::test_url_against_rules(&RobotsTxt::parse(content), user_agent, path).allowed
+    }
 
-        if let Some(rule) = rule {
-            // Check allow rules first (more specific)
-            for allow in &rule.allow {
-                if path.starts_with(allow) {
-                    return true;
+    /// Test a URL against parsed rules the way Google does
+    ///
+    /// Uses the group whose user-agent most specifically matches the crawler,
+    /// falling back to `*`. Within it the longest matching path wins, and `Allow`
+    /// wins a tie with `Disallow`.
+    pub fn test_url_against_rules(robots: &RobotsTxt, user_agent: &str, url: &str) -> RobotsTestResult {
+        let product = user_agent
+            .split(|c: char| c == '/' || c.is_whitespace())
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+
+        // Most specific named group, else the wildcard group
+        let agent_len = robots.rules.iter()
+            .map(|r| r.user_agent.to_lowercase())
+            .filter(|agent| agent != "*" && !agent.is_empty() && product.starts_with(agent.as_str()))
+            .map(|agent| agent.len())
+            .max();
+        let groups: Vec<&RobotsRule> = robots.rules.iter()
+            .filter(|r| match agent_len {
+                Some(len) => {
+                    let agent = r.user_agent.to_lowercase();
+                    agent.len() == len && product.starts_with(agent.as_str())
                 }
-            }
+                None => r.user_agent == "*",
+            })
+            .collect();
+
+        let Some(first) = groups.first() else {
+            return RobotsTestResult {
+                allowed: true,
+                matched_rule: None,
+                user_agent_matched: String::new(),
+            };
+        };
 
-            // Check disallow rules
-            for disallow in &rule.disallow {
-                if disallow.is_empty() {
-                    continue; // Empty disallow means allow all
+        let path = url_path(url);
+        let mut best: Option<(usize, bool, &str)> = None;
+        for rule in &groups {
+            let candidates = rule.allow.iter().map(|p| (true, p))
+                .chain(rule.disallow.iter().map(|p| (false, p)));
+            for (allow, pattern) in candidates {
+                if pattern.is_empty() || !robots_pattern_matches(pattern, &path) {
+                    continue;
                 }
-                if path.starts_with(disallow) {
-                    return false;
+                let better = match best {
+                    None => true,
+                    Some((len, best_allow, _)) => pattern.len() > len || (pattern.len() == len && allow && !best_allow),
+                };
+                if better {
+                    best = Some((pattern.len(), allow, pattern));
                 }
             }
         }
 
-        // Default: allowed
-        true
+        RobotsTestResult {
+            allowed: best.is_none_or(|(_, allow, _)| allow),
+            matched_rule: best.map(|(_, allow, pattern)| {
+                format!("{}: {}", if allow { "Allow" } else { "Disallow" }, pattern)
+            }),
+            user_agent_matched: first.user_agent.clone(),
+        }
     }
 
     /// Get sitemap URL from robots.txt
@@ -163,14 +319,145 @@ impl RobotsService {
 /// Validation result
 pub struct ValidationResult {
     pub valid: bool,
-    pub errors: Vec<String>,
-    pub warnings: Vec<String>,
+    pub errors: Vec<RobotsError>,
+    pub warnings: Vec<RobotsWarning>,
+}
+
+/// Error on a robots.txt line
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RobotsError {
+    pub line: i32,
+    pub message: String,
+}
+
+/// Warning on a robots.txt line
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RobotsWarning {
+    pub line: i32,
+    pub message: String,
+}
+
+/// Outcome of testing a URL against robots.txt rules
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RobotsTestResult {
+    pub allowed: bool,
+    /// Directive that decided the result, e.g. `Disallow: /admin/`
+    pub matched_rule: Option<String>,
+    pub user_agent_matched: String,
+}
+
+/// Path and query of a URL, which is what robots.txt rules match against
+fn url_path(url: &str) -> String {
+    let path = match url.split_once("://") {
+        Some((_, rest)) => rest.find('/').map(|i| &rest[i..]).unwrap_or("/"),
+        None => url,
+    };
+    let path = path.split('#').next().unwrap_or_default();
+    if path.starts_with('/') {
+        path.to_string()
+    } else {
+        format!("/{}", path)
+    }
+}
+
+/// Match a robots.txt path pattern, supporting `*` wildcards and a `$` end anchor
+fn robots_pattern_matches(pattern: &str, path: &str) -> bool {
+    let (pattern, anchored) = match pattern.strip_suffix('$') {
+        Some(pattern) => (pattern, true),
+        None => (pattern, false),
+    };
+
+    let parts: Vec<&str> = pattern.split('*').collect();
+    let Some(rest) = path.strip_prefix(parts[0]) else {
+        return false;
+    };
+    if parts.len() == 1 {
+        return !anchored || rest.is_empty();
+    }
+
+    let mut rest = rest;
+    let last = parts[parts.len() - 1];
+    for part in &parts[1..parts.len() - 1] {
+        match rest.find(part) {
+            Some(i) => rest = &rest[i + part.len()..],
+            None => return false,
+        }
+    }
+
+    if anchored {
+        rest.ends_with(last)
+    } else {
+        rest.contains(last)
+    }
+}
+
+/// Rules of one User-agent group while validating, with their line numbers
+#[derive(Default)]
+struct RobotsGroup {
+    agents: Vec<String>,
+    allow: Vec<(String, usize)>,
+    disallow: Vec<(String, usize)>,
+}
+
+impl RobotsGroup {
+    fn finish(&self, warnings: &mut Vec<RobotsWarning>) {
+        if !self.allow.is_empty() {
+            return;
+        }
+        if let Some((_, line)) = self.disallow.iter().find(|(path, _)| path == "/") {
+            warnings.push(RobotsWarning {
+                line: *line as i32,
+                message: format!(
+                    "'Disallow: /' blocks the whole site for {} and no Allow rule re-opens any of it",
+                    self.agents.join(", ")
+                ),
+            });
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn service_with_custom_rules() -> RobotsService {
+        RobotsService::new("https://example.com".to_string()).with_settings(RobotsTxtSettings {
+            custom_rules: "User-agent: Badbot\nDisallow: /".to_string(),
+            ..RobotsTxtSettings::default()
+        })
+    }
+
+    #[test]
+    fn test_audit_in_sync_with_custom_rules() {
+        let service = service_with_custom_rules();
+        let report = RobotsService::audit(&service.build(), &service.generate());
+
+        assert!(report.in_sync, "{:?}", report);
+        assert!(report.extra_rules.is_empty());
+    }
+
+    #[test]
+    fn test_audit_reports_missing_rule() {
+        let service = service_with_custom_rules();
+        let live = service.generate().replace("User-agent: Badbot\nDisallow: /", "");
+        let report = RobotsService::audit(&service.build(), &live);
+
+        assert!(!report.in_sync);
+        assert_eq!(report.missing_rules, vec!["[badbot] Disallow: /".to_string()]);
+        assert!(report.extra_rules.is_empty());
+    }
+
+    #[test]
+    fn test_audit_reports_extra_rule() {
+        let service = service_with_custom_rules();
+        let live = format!("{}\nUser-agent: Otherbot\nDisallow: /private/\n", service.generate());
+        let report = RobotsService::audit(&service.build(), &live);
+
+        assert!(!report.in_sync);
+        assert!(report.missing_rules.is_empty());
+        assert_eq!(report.extra_rules, vec!["[otherbot] Disallow: /private/".to_string()]);
+    }
+
     #[test]
     fn test_generate_robots() {
         let service = RobotsService::new("https://example.com".to_string());
@@ -180,6 +467,110 @@ mod tests {
         assert!(content.contains("Sitemap:"));
     }
 
+    #[test]
+    fn test_validate_path_prefix() {
+        let service = RobotsService::new("https://example.com".to_string());
+        let result = service.validate("User-agent: *\nDisallow: admin/\nAllow: *.css\nDisallow:");
+
+        assert!(!result.valid);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].line, 2);
+    }
+
+    #[test]
+    fn test_validate_disallow_all_without_allow() {
+        let service = RobotsService::new("https://example.com".to_string());
+        let result = service.validate("User-agent: GPTBot\nDisallow: /\n\nUser-agent: *\nDisallow: /\nAllow: /blog/");
+
+        assert!(result.valid);
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(result.warnings[0].line, 2);
+        assert!(result.warnings[0].message.contains("GPTBot"));
+    }
+
+    #[test]
+    fn test_validate_rule_before_user_agent() {
+        let service = RobotsService::new("https://example.com".to_string());
+        let result = service.validate("# robots\nDisallow: /private/\nUser-agent: *\nAllow: /");
+
+        assert!(!result.valid);
+        assert_eq!(result.errors[0].line, 2);
+        assert!(result.errors[0].message.contains("before any User-agent"));
+    }
+
+    #[test]
+    fn test_validate_duplicate_user_agent() {
+        let service = RobotsService::new("https://example.com".to_string());
+        let result = service.validate(
+            "User-agent: Googlebot\nUser-agent: Bingbot\nDisallow: /tmp/\n\nUser-agent: googlebot\nDisallow: /cart",
+        );
+
+        assert!(result.valid);
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(result.warnings[0].line, 5);
+        assert!(result.warnings[0].message.contains("line 1"));
+    }
+
+    #[test]
+    fn test_url_against_rules_longest_match() {
+        let robots = RobotsTxt::parse("User-agent: *\nDisallow: /shop/\nAllow: /shop/public/\nDisallow: /*.pdf$");
+
+        let result = RobotsService::test_url_against_rules(&robots, "Googlebot", "https://example.com/shop/public/item");
+        assert!(result.allowed);
+        assert_eq!(result.matched_rule.as_deref(), Some("Allow: /shop/public/"));
+        assert_eq!(result.user_agent_matched, "*");
+
+        let result = RobotsService::test_url_against_rules(&robots, "Googlebot", "/shop/cart");
+        assert!(!result.allowed);
+        assert_eq!(result.matched_rule.as_deref(), Some("Disallow: /shop/"));
+
+        assert!(!RobotsService::test_url_against_rules(&robots, "Googlebot", "/docs/guide.pdf").allowed);
+        assert!(RobotsService::test_url_against_rules(&robots, "Googlebot", "/docs/guide.pdf?v=2").allowed);
+    }
+
+    #[test]
+    fn test_url_against_rules_equal_specificity_prefers_allow() {
+        let robots = RobotsTxt::parse("User-agent: *\nDisallow: /page\nAllow: /page");
+
+        let result = RobotsService::test_url_against_rules(&robots, "Googlebot", "/page");
+        assert!(result.allowed);
+        assert_eq!(result.matched_rule.as_deref(), Some("Allow: /page"));
+
+        let robots = RobotsTxt::parse("User-agent: *\nAllow: /folder*\nDisallow: /folder/");
+        assert!(RobotsService::test_url_against_rules(&robots, "Googlebot", "/folder/page").allowed);
+    }
+
+    #[test]
+    fn test_url_against_rules_user_agent_groups() {
+        let robots = RobotsTxt::parse(
+            "User-agent: *\nDisallow: /\n\nUser-agent: Googlebot\nUser-agent: Bingbot\nDisallow: /private/",
+        );
+
+        let result = RobotsService::test_url_against_rules(&robots, "Googlebot-News/2.1", "/news");
+        assert!(result.allowed);
+        assert_eq!(result.user_agent_matched, "Googlebot");
+        assert!(!RobotsService::test_url_against_rules(&robots, "bingbot", "/private/x").allowed);
+        assert!(!RobotsService::test_url_against_rules(&robots, "DuckDuckBot", "/news").allowed);
+    }
+
+    #[test]
+    fn test_apply_overrides() {
+        let service = RobotsService::new("https://example.com".to_string());
+        let theme = RobotsTxt::parse("User-agent: *\nDisallow: /admin/\nDisallow: /theme-preview/\nCrawl-delay: 10");
+        let plugin = RobotsTxt::parse(
+            "User-agent: *\nCrawl-delay: 5\n\nUser-agent: GPTBot\nDisallow: /\n\nSitemap: https://example.com/sitemap_index.xml\nSitemap: https://example.com/shop.xml",
+        );
+
+        let robots = service.apply_overrides(&[theme, plugin]);
+        let all = robots.rules.iter().find(|r| r.user_agent == "*").unwrap();
+
+        assert_eq!(all.disallow.iter().filter(|d| *d == "/admin/").count(), 1);
+        assert!(all.disallow.contains(&"/theme-preview/".to_string()));
+        assert_eq!(all.crawl_delay, Some(5));
+        assert!(robots.rules.iter().any(|r| r.user_agent == "GPTBot"));
+        assert_eq!(robots.sitemaps.len(), 2);
+    }
+
     #[test]
     fn test_is_allowed() {
         let service = RobotsService::new("https://example.com".to_string());
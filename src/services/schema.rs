@@ -4,7 +4,9 @@
 
 use crate::models::schema::*;
 use crate::models::breadcrumb::Breadcrumb;
+use crate::admin::settings::SocialProfiles;
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 /// Service for generating schema.org structured data
@@ -12,6 +14,7 @@ pub struct SchemaService {
     site_name: String,
     site_url: String,
     organization: Option<OrganizationSchema>,
+    output_format: SchemaOutputFormat,
 }
 
 impl SchemaService {
@@ -20,6 +23,7 @@ impl SchemaService {
             site_name,
             site_url: site_url.trim_end_matches('/').to_string(),
             organization: None,
+            output_format: SchemaOutputFormat::JsonLd,
         }
     }
 
@@ -28,6 +32,35 @@ impl SchemaService {
         self
     }
 
+    pub fn with_output_format(mut self, format: SchemaOutputFormat) -> Self {
+        self.output_format = format;
+        self
+    }
+
+    pub fn site_url(&self) -> &str {
+        &self.site_url
+    }
+
+    /// Generate the Organization and WebSite schemas behind the knowledge panel
+    pub fn knowledge_panel_schema(
+        &self,
+        org: &OrganizationSchema,
+        social_profiles: &SocialProfiles,
+        search_url: Option<&str>,
+    ) -> KnowledgePanelSchemas {
+        let mut org = org.clone();
+        for url in social_profiles.profile_urls() {
+            if !org.same_as.contains(&url) {
+                org.same_as.push(url);
+            }
+        }
+
+        KnowledgePanelSchemas {
+            organization: org.to_json_ld(),
+            website: self.website_schema(search_url),
+        }
+    }
+
     /// Generate website schema
     pub fn website_schema(&self, search_url: Option<&str>) -> Value {
         let mut schema = WebsiteSchema::new(
@@ -92,7 +125,7 @@ impl SchemaService {
             availability: data.availability,
             condition: data.condition,
             rating: data.rating,
-            reviews: vec![],
+            reviews: data.reviews,
         };
 
         product.to_json_ld()
@@ -136,6 +169,31 @@ impl SchemaService {
         schema.to_json_ld()
     }
 
+    /// Generate job posting schema
+    pub fn job_posting_schema(&self, job: &JobPostingSchema) -> Value {
+        job.to_json_ld()
+    }
+
+    /// Generate course schema
+    pub fn course_schema(&self, course: &CourseSchema) -> Value {
+        course.to_json_ld()
+    }
+
+    /// Generate HowTo schema
+    pub fn howto_schema(&self, howto: &HowToSchema) -> Value {
+        howto.to_json_ld()
+    }
+
+    /// Generate recipe schema
+    pub fn recipe_schema(&self, recipe: &RecipeSchema) -> Value {
+        recipe.to_json_ld()
+    }
+
+    /// Generate event schema
+    pub fn event_schema(&self, event: &EventSchema) -> Value {
+        event.to_json_ld()
+    }
+
     /// Generate all schemas for a page
     pub fn generate_page_schemas(&self, page_type: PageType, data: PageSchemaData) -> Vec<Value> {
         let mut schemas = Vec::new();
@@ -177,21 +235,655 @@ impl SchemaService {
                     schemas.push(self.local_business_schema(business));
                 }
             }
+            PageType::HowTo => {
+                if let Some(howto) = &data.howto {
+                    schemas.push(self.howto_schema(howto));
+                }
+            }
+            PageType::Event => {
+                if let Some(event) = &data.event {
+                    schemas.push(self.event_schema(event));
+                }
+            }
             _ => {}
         }
 
         schemas
     }
 
-    /// Generate script tags for all schemas
+    /// Validate JSON-LD structured data
+    ///
+    /// Paths use JSON Pointer syntax, e.g. `/@graph/0/author/name`.
+    pub fn validate_schema(json_ld: &Value) -> SchemaValidationResult {
+        let mut result = SchemaValidationResult::default();
+
+        for (schema_type, node_result) in Self::validate_schema_nodes(json_ld) {
+            if !result.schema_types.contains(&schema_type) {
+                result.schema_types.push(schema_type);
+            }
+            result.errors.extend(node_result.errors);
+            result.warnings.extend(node_result.warnings);
+        }
+
+        result.valid = result.errors.is_empty();
+        result
+    }
+
+    /// Validate each schema node separately, keyed by its `@type`
+    pub fn validate_schema_nodes(json_ld: &Value) -> Vec<(String, SchemaValidationResult)> {
+        let roots: Vec<(String, &Value)> = match json_ld {
+            Value::Array(items) => items.iter()
+                .enumerate()
+                .map(|(i, item)| (format!("/{}", i), item))
+                .collect(),
+            _ => vec![(String::new(), json_ld)],
+        };
+
+        let mut results = Vec::new();
+
+        for (prefix, root) in roots {
+            let mut context_errors = Vec::new();
+            let mut context_warnings = Vec::new();
+
+            match root.get("@context") {
+                None => context_errors.push(SchemaError {
+                    path: format!("{}/@context", prefix),
+                    message: "Missing @context".to_string(),
+                }),
+                Some(Value::String(context)) if !context.contains("schema.org") => {
+                    context_warnings.push(SchemaWarning {
+                        path: format!("{}/@context", prefix),
+                        message: format!("Unexpected @context '{}'", context),
+                        recommendation: "Use \"https://schema.org\" as the @context".to_string(),
+                    });
+                }
+                _ => {}
+            }
+
+            let nodes: Vec<(String, &Value)> = match root.get("@graph").and_then(|g| g.as_array()) {
+                Some(graph) => graph.iter()
+                    .enumerate()
+                    .map(|(i, node)| (format!("{}/@graph/{}", prefix, i), node))
+                    .collect(),
+                None => vec![(prefix.clone(), root)],
+            };
+
+            if nodes.is_empty() {
+                results.push(("Unknown".to_string(), SchemaValidationResult {
+                    valid: context_errors.is_empty(),
+                    errors: context_errors,
+                    warnings: context_warnings,
+                    ..Default::default()
+                }));
+                continue;
+            }
+
+            for (i, (path, node)) in nodes.into_iter().enumerate() {
+                let (schema_type, mut node_result) = Self::validate_node(node, &path);
+                if i == 0 {
+                    node_result.errors.splice(0..0, context_errors.drain(..));
+                    node_result.warnings.splice(0..0, context_warnings.drain(..));
+                    node_result.valid = node_result.errors.is_empty();
+                }
+                results.push((schema_type, node_result));
+            }
+        }
+
+        results
+    }
+
+    /// Validate a single schema node and the typed objects nested in it
+    fn validate_node(node: &Value, pointer: &str) -> (String, SchemaValidationResult) {
+        let mut result = SchemaValidationResult::default();
+
+        if !node.is_object() {
+            result.errors.push(SchemaError {
+                path: pointer.to_string(),
+                message: "Schema must be a JSON object".to_string(),
+            });
+            return ("Unknown".to_string(), result);
+        }
+
+        let schema_type = match node.get("@type") {
+            Some(Value::String(t)) => t.clone(),
+            Some(Value::Array(types)) => types.iter()
+                .find_map(|t| t.as_str())
+                .unwrap_or("Unknown")
+                .to_string(),
+            _ => {
+                result.errors.push(SchemaError {
+                    path: format!("{}/@type", pointer),
+                    message: "Missing @type".to_string(),
+                });
+                return ("Unknown".to_string(), result);
+            }
+        };
+        result.schema_types.push(schema_type.clone());
+
+        check_required_properties(node, &schema_type, pointer, false, &mut result.errors);
+
+        for property in recommended_properties(&schema_type) {
+            if !has_property(node, property) {
+                result.warnings.push(SchemaWarning {
+                    path: json_pointer(pointer, property),
+                    message: format!("Missing recommended property '{}' for {}", property, schema_type),
+                    recommendation: format!("Add '{}' to improve rich result eligibility", property),
+                });
+            }
+        }
+
+        result.valid = result.errors.is_empty();
+        (schema_type, result)
+    }
+
+    /// Generate script tags, or Microdata blocks, for all schemas
     pub fn to_html(&self, schemas: &[Value]) -> String {
-        schemas.iter().map(|schema| {
-            format!(
+        schemas.iter().map(|schema| match self.output_format {
+            SchemaOutputFormat::JsonLd => format!(
                 "<script type=\"application/ld+json\">\n{}\n</script>\n",
                 serde_json::to_string_pretty(schema).unwrap_or_default()
+            ),
+            SchemaOutputFormat::Microdata => microdata_html(schema, "Thing", ""),
+        }).collect()
+    }
+
+    /// Generate script tags without whitespace in the JSON
+    pub fn to_minified_html(&self, schemas: &[Value]) -> String {
+        schemas.iter().map(|schema| {
+            format!(
+                "<script type=\"application/ld+json\">{}</script>\n",
+                serde_json::to_string(schema).unwrap_or_default()
             )
         }).collect()
     }
+
+    /// Validate a schema before emitting its script tag
+    ///
+    /// With `fallback`, a schema missing required properties is still emitted behind a
+    /// warning comment. Structural problems, such as a missing `@context` or `@type`, never are.
+    pub fn validate_and_emit(&self, schema: Value, fallback: bool) -> Result<String, SchemaEmitError> {
+        let validation = Self::validate_schema(&schema);
+        let html = self.to_html(std::slice::from_ref(&schema));
+
+        if validation.valid {
+            return Ok(html);
+        }
+
+        let schema_type = validation.schema_types.first().cloned().unwrap_or_else(|| "Unknown".to_string());
+        let (required, structural): (Vec<&SchemaError>, Vec<&SchemaError>) = validation.errors.iter()
+            .partition(|error| error.message.starts_with("Missing required property"));
+
+        if !structural.is_empty() {
+            return Err(SchemaEmitError::InvalidStructure {
+                schema_type,
+                errors: structural.iter()
+                    .map(|error| format!("{}: {}", error.path, error.message))
+                    .collect(),
+            });
+        }
+
+        if fallback {
+            tracing::warn!("Emitting incomplete schema: {} errors", validation.errors.len());
+            return Ok(format!("<!-- WARNING: schema incomplete -->\n{}", html));
+        }
+
+        let mut missing_properties: Vec<String> = Vec::new();
+        for error in required {
+            let property = error.path.rsplit('/').next().unwrap_or(&error.path).to_string();
+            if !missing_properties.contains(&property) {
+                missing_properties.push(property);
+            }
+        }
+
+        Err(SchemaEmitError::RequiredPropertyMissing {
+            schema_type,
+            missing_properties,
+        })
+    }
+}
+
+/// Schema type with the properties the editor and validator expect
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchemaTypeInfo {
+    pub schema_type: String,
+    pub name: String,
+    pub description: String,
+    pub recommended_for: Vec<String>,
+    pub required_properties: Vec<String>,
+    pub optional_properties: Vec<String>,
+}
+
+/// Validation rules for a schema type, plus its editor details when offered there
+struct SchemaTypeDef {
+    schema_type: &'static str,
+    /// Offered in the schema editor; other types are only validated
+    in_editor: bool,
+    name: &'static str,
+    description: &'static str,
+    recommended_for: &'static [&'static str],
+    required: &'static [&'static str],
+    recommended: &'static [&'static str],
+    /// Required properties a parent node supplies when the type is nested,
+    /// e.g. a Review inside a Product reviews that product
+    implied_when_nested: &'static [&'static str],
+}
+
+const ARTICLE_REQUIRED: &[&str] = &["headline", "author", "datePublished"];
+const ARTICLE_RECOMMENDED: &[&str] = &["image", "dateModified", "description"];
+
+/// Every schema type the plugin knows about
+const SCHEMA_TYPES: &[SchemaTypeDef] = &[
+    SchemaTypeDef {
+        schema_type: "Article",
+        in_editor: true,
+        name: "Article",
+        description: "A general article",
+        recommended_for: &["posts", "news"],
+        required: ARTICLE_REQUIRED,
+        recommended: ARTICLE_RECOMMENDED,
+        implied_when_nested: &[],
+    },
+    SchemaTypeDef {
+        schema_type: "BlogPosting",
+        in_editor: true,
+        name: "Blog Post",
+        description: "A blog post",
+        recommended_for: &["posts", "blogs"],
+        required: ARTICLE_REQUIRED,
+        recommended: ARTICLE_RECOMMENDED,
+        implied_when_nested: &[],
+    },
+    SchemaTypeDef {
+        schema_type: "NewsArticle",
+        in_editor: true,
+        name: "News Article",
+        description: "A news article",
+        recommended_for: &["news"],
+        required: ARTICLE_REQUIRED,
+        recommended: ARTICLE_RECOMMENDED,
+        implied_when_nested: &[],
+    },
+    SchemaTypeDef {
+        schema_type: "Product",
+        in_editor: true,
+        name: "Product",
+        description: "A product for sale",
+        recommended_for: &["products"],
+        required: &["name"],
+        recommended: &["image", "description", "offers"],
+        implied_when_nested: &[],
+    },
+    SchemaTypeDef {
+        schema_type: "LocalBusiness",
+        in_editor: true,
+        name: "Local Business",
+        description: "A local business",
+        recommended_for: &["pages"],
+        required: &["name", "address"],
+        recommended: &["telephone", "openingHours", "url"],
+        implied_when_nested: &[],
+    },
+    SchemaTypeDef {
+        schema_type: "Organization",
+        in_editor: true,
+        name: "Organization",
+        description: "An organization",
+        recommended_for: &["about"],
+        required: &["name"],
+        recommended: &["logo", "url"],
+        implied_when_nested: &[],
+    },
+    SchemaTypeDef {
+        schema_type: "FAQPage",
+        in_editor: true,
+        name: "FAQ Page",
+        description: "A page with frequently asked questions",
+        recommended_for: &["faq"],
+        required: &["mainEntity"],
+        recommended: &[],
+        implied_when_nested: &[],
+    },
+    SchemaTypeDef {
+        schema_type: "HowTo",
+        in_editor: true,
+        name: "How To",
+        description: "Step-by-step instructions",
+        recommended_for: &["tutorials", "guides"],
+        required: &["name", "step"],
+        recommended: &["image", "totalTime"],
+        implied_when_nested: &[],
+    },
+    SchemaTypeDef {
+        schema_type: "Recipe",
+        in_editor: true,
+        name: "Recipe",
+        description: "A cooking recipe",
+        recommended_for: &["recipes"],
+        required: &["name", "recipeIngredient"],
+        recommended: &["image", "cookTime"],
+        implied_when_nested: &[],
+    },
+    SchemaTypeDef {
+        schema_type: "Event",
+        in_editor: true,
+        name: "Event",
+        description: "An event",
+        recommended_for: &["events"],
+        required: &["name", "startDate", "location"],
+        recommended: &["image", "description"],
+        implied_when_nested: &[],
+    },
+    SchemaTypeDef {
+        schema_type: "JobPosting",
+        in_editor: true,
+        name: "Job Posting",
+        description: "A job vacancy",
+        recommended_for: &["jobs", "careers"],
+        required: &["title", "description", "datePosted", "hiringOrganization"],
+        recommended: &["validThrough", "employmentType", "jobLocation", "baseSalary"],
+        implied_when_nested: &[],
+    },
+    SchemaTypeDef {
+        schema_type: "Course",
+        in_editor: true,
+        name: "Course",
+        description: "An educational course",
+        recommended_for: &["courses", "lessons"],
+        required: &["name", "description", "provider"],
+        recommended: &["courseCode", "educationalLevel", "offers", "hasCourseInstance", "coursePrerequisites"],
+        implied_when_nested: &[],
+    },
+    SchemaTypeDef {
+        schema_type: "Person",
+        in_editor: false,
+        name: "Person",
+        description: "A person",
+        recommended_for: &[],
+        required: &["name"],
+        recommended: &[],
+        implied_when_nested: &[],
+    },
+    SchemaTypeDef {
+        schema_type: "Brand",
+        in_editor: false,
+        name: "Brand",
+        description: "A brand",
+        recommended_for: &[],
+        required: &["name"],
+        recommended: &[],
+        implied_when_nested: &[],
+    },
+    SchemaTypeDef {
+        schema_type: "WebSite",
+        in_editor: false,
+        name: "Website",
+        description: "A website",
+        recommended_for: &[],
+        required: &["name", "url"],
+        recommended: &[],
+        implied_when_nested: &[],
+    },
+    SchemaTypeDef {
+        schema_type: "BreadcrumbList",
+        in_editor: false,
+        name: "Breadcrumb List",
+        description: "A breadcrumb trail",
+        recommended_for: &[],
+        required: &["itemListElement"],
+        recommended: &[],
+        implied_when_nested: &[],
+    },
+    SchemaTypeDef {
+        schema_type: "Review",
+        in_editor: false,
+        name: "Review",
+        description: "A review of an item",
+        recommended_for: &[],
+        required: &["itemReviewed", "reviewRating", "author"],
+        recommended: &[],
+        implied_when_nested: &["itemReviewed"],
+    },
+];
+
+/// Table entry for a schema type
+fn schema_type_def(schema_type: &str) -> Option<&'static SchemaTypeDef> {
+    SCHEMA_TYPES.iter().find(|def| def.schema_type == schema_type)
+}
+
+/// Schema types offered in the editor
+pub fn get_available_schema_types() -> Vec<SchemaTypeInfo> {
+    let strings = |items: &[&str]| items.iter().map(|s| s.to_string()).collect();
+
+    SCHEMA_TYPES.iter()
+        .filter(|def| def.in_editor)
+        .map(|def| SchemaTypeInfo {
+            schema_type: def.schema_type.to_string(),
+            name: def.name.to_string(),
+            description: def.description.to_string(),
+            recommended_for: strings(def.recommended_for),
+            required_properties: strings(def.required),
+            optional_properties: strings(def.recommended),
+        })
+        .collect()
+}
+
+/// Check required properties of a node and of any typed objects nested in it
+///
+/// Nested nodes skip properties their parent implies, such as a Review's `itemReviewed`.
+fn check_required_properties(node: &Value, schema_type: &str, pointer: &str, nested: bool, errors: &mut Vec<SchemaError>) {
+    let implied = match schema_type_def(schema_type) {
+        Some(def) if nested => def.implied_when_nested,
+        _ => &[],
+    };
+
+    for property in required_properties(schema_type).iter().filter(|p| !implied.contains(p)) {
+        if !has_property(node, property) {
+            errors.push(SchemaError {
+                path: json_pointer(pointer, property),
+                message: format!("Missing required property '{}' for {}", property, schema_type),
+            });
+        }
+    }
+
+    let Some(properties) = node.as_object() else {
+        return;
+    };
+    for (key, value) in properties {
+        let children: Vec<(String, &Value)> = match value {
+            Value::Array(items) => items.iter()
+                .enumerate()
+                .map(|(i, item)| (format!("{}/{}", json_pointer(pointer, key), i), item))
+                .collect(),
+            Value::Object(_) => vec![(json_pointer(pointer, key), value)],
+            _ => continue,
+        };
+
+        for (child_pointer, child) in children {
+            if let Some(child_type) = node_types(child).first() {
+                check_required_properties(child, child_type, &child_pointer, true, errors);
+            }
+        }
+    }
+}
+
+/// Append an escaped key to a JSON Pointer
+fn json_pointer(pointer: &str, key: &str) -> String {
+    format!("{}/{}", pointer, key.replace('~', "~0").replace('/', "~1"))
+}
+
+/// Required properties per schema type
+fn required_properties(schema_type: &str) -> &'static [&'static str] {
+    schema_type_def(schema_type).map_or(&[], |def| def.required)
+}
+
+/// Recommended properties per schema type
+fn recommended_properties(schema_type: &str) -> &'static [&'static str] {
+    schema_type_def(schema_type).map_or(&[], |def| def.recommended)
+}
+
+/// Top-level schema nodes, expanding arrays and `@graph`
+fn collect_nodes(json_ld: &Value) -> Vec<&Value> {
+    match json_ld {
+        Value::Array(items) => items.iter().flat_map(collect_nodes).collect(),
+        _ => match json_ld.get("@graph").and_then(|g| g.as_array()) {
+            Some(graph) => graph.iter().collect(),
+            None => vec![json_ld],
+        },
+    }
+}
+
+/// Types declared by a schema node
+fn node_types(node: &Value) -> Vec<&str> {
+    match node.get("@type") {
+        Some(Value::String(t)) => vec![t.as_str()],
+        Some(Value::Array(types)) => types.iter().filter_map(|t| t.as_str()).collect(),
+        _ => vec![],
+    }
+}
+
+/// Rich result eligibility checks
+pub mod schema_testing {
+    use super::{collect_nodes, has_property, node_types, recommended_properties, required_properties};
+    use serde::{Deserialize, Serialize};
+    use serde_json::Value;
+
+    /// Rich result types and the schema types that can produce them
+    const RICH_RESULTS: &[(&str, &[&str])] = &[
+        ("FAQ", &["FAQPage"]),
+        ("HowTo", &["HowTo"]),
+        ("Product", &["Product"]),
+        ("Article", &["Article", "BlogPosting", "NewsArticle"]),
+        ("Recipe", &["Recipe"]),
+        ("Event", &["Event"]),
+        ("Review", &["Review"]),
+    ];
+
+    /// Rich result eligibility for a schema
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct RichResultType {
+        pub name: String,
+        pub required_met: bool,
+        pub recommended_met: bool,
+        pub eligible: bool,
+        pub missing_required: Vec<String>,
+        pub missing_recommended: Vec<String>,
+    }
+
+    /// Rich result types the schema makes the page eligible for
+    pub fn check_rich_result_eligibility(schema: &Value) -> Vec<RichResultType> {
+        check_all_rich_results(schema)
+            .into_iter()
+            .filter(|r| r.eligible)
+            .collect()
+    }
+
+    /// Rich result checks for every schema node, eligible or not
+    pub fn check_all_rich_results(schema: &Value) -> Vec<RichResultType> {
+        let mut results = Vec::new();
+
+        for node in collect_nodes(schema) {
+            for schema_type in node_types(node) {
+                let Some((name, _)) = RICH_RESULTS.iter()
+                    .find(|(_, types)| types.contains(&schema_type))
+                else {
+                    continue;
+                };
+
+                results.push(check_node(name, schema_type, node));
+            }
+
+            // Ratings on other items also produce review snippets
+            let is_review = node_types(node).contains(&"Review");
+            if !is_review && (has_property(node, "aggregateRating") || has_property(node, "review")) {
+                results.push(RichResultType {
+                    name: "Review".to_string(),
+                    required_met: true,
+                    recommended_met: true,
+                    eligible: true,
+                    missing_required: vec![],
+                    missing_recommended: vec![],
+                });
+            }
+        }
+
+        results
+    }
+
+    fn check_node(name: &str, schema_type: &str, node: &Value) -> RichResultType {
+        let missing_required: Vec<String> = required_properties(schema_type).iter()
+            .filter(|p| !has_property(node, p))
+            .map(|p| p.to_string())
+            .collect();
+        let missing_recommended: Vec<String> = recommended_properties(schema_type).iter()
+            .filter(|p| !has_property(node, p))
+            .map(|p| p.to_string())
+            .collect();
+
+        RichResultType {
+            name: name.to_string(),
+            required_met: missing_required.is_empty(),
+            recommended_met: missing_recommended.is_empty(),
+            eligible: missing_required.is_empty(),
+            missing_required,
+            missing_recommended,
+        }
+    }
+}
+
+/// Whether a property is present and not empty
+fn has_property(node: &Value, property: &str) -> bool {
+    match node.get(property) {
+        None | Some(Value::Null) => false,
+        Some(Value::String(s)) => !s.trim().is_empty(),
+        Some(Value::Array(a)) => !a.is_empty(),
+        Some(_) => true,
+    }
+}
+
+/// Result of schema validation
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SchemaValidationResult {
+    pub valid: bool,
+    pub schema_types: Vec<String>,
+    pub errors: Vec<SchemaError>,
+    pub warnings: Vec<SchemaWarning>,
+    /// Rich results the schema is eligible for
+    pub rich_results: Vec<schema_testing::RichResultType>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchemaError {
+    pub path: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchemaWarning {
+    pub path: String,
+    pub message: String,
+    pub recommendation: String,
+}
+
+/// Schema that failed validation before being emitted
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum SchemaEmitError {
+    #[error("{schema_type} schema is missing required properties: {}", .missing_properties.join(", "))]
+    RequiredPropertyMissing {
+        schema_type: String,
+        missing_properties: Vec<String>,
+    },
+    #[error("{schema_type} schema is malformed: {}", .errors.join("; "))]
+    InvalidStructure {
+        schema_type: String,
+        errors: Vec<String>,
+    },
+}
+
+/// Schemas that drive the Google Knowledge Panel
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KnowledgePanelSchemas {
+    pub organization: Value,
+    pub website: Value,
 }
 
 /// Page type for schema selection
@@ -203,6 +895,8 @@ pub enum PageType {
     Category,
     FAQ,
     LocalBusiness,
+    HowTo,
+    Event,
     Contact,
     About,
     Generic,
@@ -237,6 +931,7 @@ pub struct ProductSchemaData {
     pub availability: ProductAvailability,
     pub condition: ProductCondition,
     pub rating: Option<AggregateRating>,
+    pub reviews: Vec<ReviewSchema>,
 }
 
 /// Local business data
@@ -264,6 +959,8 @@ pub struct PageSchemaData {
     pub product: Option<ProductSchemaData>,
     pub faq_items: Vec<(String, String)>,
     pub local_business: Option<LocalBusinessData>,
+    pub howto: Option<HowToSchema>,
+    pub event: Option<EventSchema>,
 }
 
 impl Default for PageSchemaData {
@@ -275,6 +972,84 @@ impl Default for PageSchemaData {
             product: None,
             faq_items: vec![],
             local_business: None,
+            howto: None,
+            event: None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_schema_reports_json_pointer_paths() {
+        let json_ld = serde_json::json!({
+            "@context": "https://schema.org",
+            "@type": "Article",
+            "headline": "Hello",
+            "datePublished": "2024-01-01",
+            "author": { "@type": "Person", "name": "" }
+        });
+
+        let result = SchemaService::validate_schema(&json_ld);
+        assert!(!result.valid);
+        let paths: Vec<&str> = result.errors.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, vec!["/author/name"]);
+        assert!(result.warnings.iter().any(|w| w.path == "/image"));
+
+        let graph = serde_json::json!({
+            "@context": "https://schema.org",
+            "@graph": [{ "@type": "JobPosting", "title": "Engineer" }]
+        });
+        let result = SchemaService::validate_schema(&graph);
+        assert!(result.errors.iter().any(|e| e.path == "/@graph/0/datePosted"));
+    }
+
+    #[test]
+    fn test_validate_and_emit_separates_structural_errors() {
+        let service = SchemaService::new("Site".to_string(), "https://example.com".to_string());
+
+        let incomplete = serde_json::json!({
+            "@context": "https://schema.org",
+            "@type": "Article",
+            "headline": "Title",
+        });
+        match service.validate_and_emit(incomplete.clone(), false) {
+            Err(SchemaEmitError::RequiredPropertyMissing { schema_type, missing_properties }) => {
+                assert_eq!(schema_type, "Article");
+                assert_eq!(missing_properties, vec!["author", "datePublished"]);
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert!(service.validate_and_emit(incomplete, true).unwrap().starts_with("<!-- WARNING: schema incomplete -->"));
+
+        let untyped = serde_json::json!({ "@context": "https://schema.org", "name": "Thing" });
+        match service.validate_and_emit(untyped, true) {
+            Err(SchemaEmitError::InvalidStructure { errors, .. }) => assert_eq!(errors, vec!["/@type: Missing @type"]),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_validate_schema_accepts_nested_reviews() {
+        let review = crate::models::schema::ReviewSchema {
+            author: "Jane".to_string(),
+            rating: 4.5,
+            review_body: "Works well".to_string(),
+            date_published: chrono::Utc::now(),
+        };
+        let product = serde_json::json!({
+            "@context": "https://schema.org",
+            "@type": "Product",
+            "name": "Widget",
+            "review": [review.to_json()]
+        });
+        assert!(SchemaService::validate_schema(&product).valid);
+
+        let mut standalone = review.to_json();
+        standalone["@context"] = serde_json::json!("https://schema.org");
+        let result = SchemaService::validate_schema(&standalone);
+        assert!(result.errors.iter().any(|e| e.path == "/itemReviewed"));
+    }
+}
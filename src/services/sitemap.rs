@@ -4,14 +4,33 @@
 
 use crate::models::sitemap::{
     Sitemap, SitemapIndex, SitemapEntry, SitemapUrl, SitemapType,
-    SitemapConfig, ChangeFrequency, SitemapImage,
+    SitemapConfig, ChangeFrequency, SitemapImage, SitemapDiff, SitemapNews, SitemapVideo,
+    SitemapAlternate,
 };
+use crate::admin::sitemaps::{GenerationResult, NewsSitemapSettings};
+use crate::models::meta::MetaRobots;
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+
+/// File name of the published sitemap index
+pub const INDEX_FILENAME: &str = "sitemap_index.xml";
+
+type TrackedSitemaps = HashMap<SitemapType, (u64, Sitemap)>;
 
 /// Service for generating and managing XML sitemaps
+///
+/// Clones share the published files and the change tracking state.
+#[derive(Clone)]
 pub struct SitemapService {
     site_url: String,
     config: SitemapConfig,
+    meta_filter: Option<Arc<HashMap<String, MetaRobots>>>,
+    /// Content hash and URLs of the last generated sitemap of each type
+    last_generated: Arc<Mutex<TrackedSitemaps>>,
+    store: Arc<dyn SitemapStore>,
 }
 
 impl SitemapService {
@@ -19,6 +38,9 @@ impl SitemapService {
         Self {
             site_url: site_url.trim_end_matches('/').to_string(),
             config: SitemapConfig::default(),
+            meta_filter: None,
+            last_generated: Arc::new(Mutex::new(HashMap::new())),
+            store: Arc::new(InMemorySitemapStore::new()),
         }
     }
 
@@ -27,20 +49,247 @@ impl SitemapService {
         self
     }
 
-    /// Generate sitemap index
-    pub fn generate_index(&self, sitemaps: &[(&SitemapType, DateTime<Utc>)]) -> SitemapIndex {
+    /// Where generated sitemap files are written
+    pub fn with_store(mut self, store: Arc<dyn SitemapStore>) -> Self {
+        self.store = store;
+        self
+    }
+
+    /// Keep the published files and change tracking of another service,
+    /// e.g. when rebuilding it for new settings
+    pub fn share_state_with(mut self, other: &SitemapService) -> Self {
+        self.store = Arc::clone(&other.store);
+        self.last_generated = Arc::clone(&other.last_generated);
+        self
+    }
+
+    pub fn store(&self) -> &dyn SitemapStore {
+        self.store.as_ref()
+    }
+
+    /// Robots meta keyed by URL, used to drop noindex pages from generated sitemaps
+    pub fn with_meta_filter(mut self, meta_map: Arc<HashMap<String, MetaRobots>>) -> Self {
+        self.meta_filter = Some(meta_map);
+        self
+    }
+
+    /// Remove URLs whose robots meta is noindex
+    pub fn filter_noindex_urls(urls: Vec<SitemapUrl>, meta_map: &HashMap<String, MetaRobots>) -> Vec<SitemapUrl> {
+        urls.into_iter()
+            .filter(|url| meta_map.get(&url.loc).map(|robots| robots.index).unwrap_or(true))
+            .collect()
+    }
+
+    /// Apply the meta filter, if configured
+    fn apply_meta_filter(&self, mut sitemap: Sitemap) -> Sitemap {
+        if let Some(meta_map) = &self.meta_filter {
+            sitemap.urls = Self::filter_noindex_urls(sitemap.urls, meta_map);
+        }
+        sitemap
+    }
+
+    /// URL changes between two sitemaps
+    pub fn diff(old: &Sitemap, new: &Sitemap) -> SitemapDiff {
+        let old_urls: HashMap<&str, &SitemapUrl> = old.urls.iter().map(|u| (u.loc.as_str(), u)).collect();
+        let new_locs: std::collections::HashSet<&str> = new.urls.iter().map(|u| u.loc.as_str()).collect();
+        let mut diff = SitemapDiff::default();
+
+        for url in &new.urls {
+            match old_urls.get(url.loc.as_str()) {
+                None => diff.added.push(url.clone()),
+                Some(previous) => {
+                    if previous.lastmod != url.lastmod
+                        || previous.priority != url.priority
+                        || previous.changefreq != url.changefreq
+                    {
+                        diff.modified.push(url.clone());
+                    }
+                }
+            }
+        }
+
+        diff.removed = old.urls.iter()
+            .filter(|u| !new_locs.contains(u.loc.as_str()))
+            .map(|u| u.loc.clone())
+            .collect();
+
+        diff
+    }
+
+    /// Update a sitemap with the changes from `diff`
+    pub fn apply_diff(mut base: Sitemap, diff: SitemapDiff) -> Sitemap {
+        let mut modified: HashMap<String, SitemapUrl> = diff.modified.into_iter()
+            .map(|u| (u.loc.clone(), u))
+            .collect();
+
+        base.urls.retain(|u| !diff.removed.contains(&u.loc));
+        for url in base.urls.iter_mut() {
+            if let Some(updated) = modified.remove(&url.loc) {
+                *url = updated;
+            }
+        }
+        base.urls.extend(diff.added);
+        base.generated_at = Utc::now();
+
+        base
+    }
+
+    /// Compare a freshly built sitemap with the last one of its type
+    ///
+    /// Returns `None` when nothing changed and the stored file can be kept.
+    pub fn track_changes(&self, sitemap: &Sitemap) -> Option<SitemapDiff> {
+        let hash = Self::content_hash(sitemap);
+        let mut last_generated = self.last_generated.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let diff = match last_generated.get(&sitemap.sitemap_type) {
+            Some((last_hash, _)) if *last_hash == hash => return None,
+            Some((_, last)) => Self::diff(last, sitemap),
+            None => SitemapDiff {
+                added: sitemap.urls.clone(),
+                ..Default::default()
+            },
+        };
+
+        last_generated.insert(sitemap.sitemap_type, (hash, sitemap.clone()));
+        Some(diff)
+    }
+
+    /// URLs of the most recently generated sitemaps, optionally of one type
+    pub fn published_urls(&self, sitemap_type: Option<SitemapType>) -> Vec<SitemapUrl> {
+        let last_generated = self.last_generated.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut sitemaps: Vec<&Sitemap> = last_generated.values()
+            .map(|(_, sitemap)| sitemap)
+            .filter(|sitemap| sitemap_type.is_none_or(|t| sitemap.sitemap_type == t))
+            .collect();
+        sitemaps.sort_by_key(|sitemap| sitemap.filename());
+        sitemaps.into_iter().flat_map(|sitemap| sitemap.urls.iter().cloned()).collect()
+    }
+
+    /// Write changed sitemaps and the index to the store
+    ///
+    /// Sitemaps whose URLs are unchanged since the last run are not rewritten.
+    pub fn publish(&self, sitemaps: Vec<Sitemap>) -> GenerationResult {
+        let started = std::time::Instant::now();
+        let mut result = GenerationResult {
+            success: true,
+            sitemaps_generated: 0,
+            total_urls: 0,
+            generation_time_ms: 0,
+            sitemaps_unchanged: 0,
+            urls_added: 0,
+            urls_removed: 0,
+            urls_modified: 0,
+            errors: vec![],
+            warnings: vec![],
+        };
+        let mut files = Vec::new();
+
+        for sitemap in sitemaps {
+            let sitemap_type = sitemap.sitemap_type;
+            result.total_urls += sitemap.urls.len() as i64;
+            let diff = self.track_changes(&sitemap);
+            result.record_diff(diff.as_ref());
+
+            let chunks = Self::split_large_sitemap(sitemap, self.config.max_urls_per_sitemap);
+            if diff.is_some() {
+                let mut failed = false;
+                for chunk in &chunks {
+                    match self.store.write(&chunk.filename(), &chunk.to_xml()) {
+                        Ok(()) => result.sitemaps_generated += 1,
+                        Err(e) => {
+                            failed = true;
+                            result.errors.push(format!("Failed to write {}: {}", chunk.filename(), e));
+                        }
+                    }
+                }
+                // Retry the whole sitemap next run rather than treating it as unchanged
+                if failed {
+                    self.last_generated.lock().unwrap_or_else(PoisonError::into_inner).remove(&sitemap_type);
+                }
+            }
+            files.extend(chunks);
+        }
+
+        if let Err(e) = self.store.write(INDEX_FILENAME, &self.generate_index(&files).to_xml()) {
+            result.errors.push(format!("Failed to write {}: {}", INDEX_FILENAME, e));
+        }
+
+        result.generation_time_ms = started.elapsed().as_millis() as i64;
+        result.success = result.errors.is_empty();
+        result
+    }
+
+    /// Hash of a sitemap's URL entries
+    fn content_hash(sitemap: &Sitemap) -> u64 {
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        serde_json::to_string(&sitemap.urls).unwrap_or_default().hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Generate sitemap index with one entry per sitemap file
+    pub fn generate_index(&self, sitemaps: &[Sitemap]) -> SitemapIndex {
         let mut index = SitemapIndex::new();
 
-        for (sitemap_type, lastmod) in sitemaps {
+        for sitemap in sitemaps {
             index.sitemaps.push(SitemapEntry {
-                loc: format!("{}/{}", self.site_url, sitemap_type.filename()),
-                lastmod: Some(*lastmod),
+                loc: format!("{}/{}", self.site_url, sitemap.filename()),
+                lastmod: Some(sitemap.lastmod()),
             });
         }
 
         index
     }
 
+    /// Split a sitemap into numbered chunks of at most `max_urls` URLs
+    ///
+    /// A sitemap that already fits is returned unchanged, keeping its unnumbered file name.
+    pub fn split_large_sitemap(sitemap: Sitemap, max_urls: usize) -> Vec<Sitemap> {
+        let max_urls = max_urls.max(1);
+        if sitemap.urls.len() <= max_urls {
+            return vec![sitemap];
+        }
+
+        sitemap.urls.chunks(max_urls)
+            .enumerate()
+            .map(|(i, urls)| Sitemap {
+                sitemap_type: sitemap.sitemap_type,
+                urls: urls.to_vec(),
+                generated_at: sitemap.generated_at,
+                part: Some(i + 1),
+            })
+            .collect()
+    }
+
+    /// Rebuild an index pointing at sitemap files that already exist
+    pub fn rebuild_index_from_sitemaps(sitemap_urls: Vec<String>) -> SitemapIndex {
+        let mut index = SitemapIndex::new();
+        let lastmod = Utc::now();
+
+        for loc in sitemap_urls {
+            index.sitemaps.push(SitemapEntry {
+                loc,
+                lastmod: Some(lastmod),
+            });
+        }
+
+        index
+    }
+
+    /// Sitemap type whose filename the URL ends with
+    pub fn infer_sitemap_type_from_filename(url: &str) -> Option<SitemapType> {
+        let path = url.split(['?', '#']).next().unwrap_or_default();
+        let filename = path.rsplit('/').next().unwrap_or(path);
+        // Chunks of a split sitemap carry a number, e.g. post-sitemap2.xml
+        let stem = filename.trim_end_matches(".xml").trim_end_matches(|c: char| c.is_ascii_digit());
+        let filename = format!("{}.xml", stem);
+
+        SitemapType::all()
+            .into_iter()
+            .find(|sitemap_type| sitemap_type.filename() == filename)
+    }
+
     /// Generate posts sitemap
     pub fn generate_posts_sitemap(&self, posts: Vec<PostData>) -> Sitemap {
         let mut sitemap = Sitemap::new(SitemapType::Posts);
@@ -50,29 +299,118 @@ impl SitemapService {
                 continue;
             }
 
-            let mut url = SitemapUrl::new(post.url)
-                .with_lastmod(post.modified_at)
-                .with_changefreq(ChangeFrequency::Weekly)
-                .with_priority(0.8);
+            sitemap.urls.push(self.post_url(post));
+        }
 
-            if self.config.include_images {
-                for image in post.images {
-                    url.images.push(SitemapImage {
-                        loc: image.url,
-                        title: image.title,
-                        caption: image.caption,
-                        geo_location: None,
-                        license: None,
-                    });
-                }
+        self.apply_meta_filter(sitemap)
+    }
+
+    /// Generate posts sitemap, reporting progress every 100 URLs
+    pub async fn generate_with_progress<F: Fn(SitemapProgress)>(&self, posts: Vec<PostData>, callback: F) -> Sitemap {
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let service = self.clone();
+
+        // Building a large sitemap is CPU-bound, keep it off the async workers
+        let task = tokio::task::spawn_blocking(move || {
+            service.build_posts_sitemap(posts, |progress| {
+                let _ = tx.send(progress);
+            })
+        });
+
+        // The channel closes once the blocking task is done with the sender
+        while let Some(progress) = rx.recv().await {
+            callback(progress);
+        }
+
+        match task.await {
+            Ok(sitemap) => sitemap,
+            Err(e) => std::panic::resume_unwind(e.into_panic()),
+        }
+    }
+
+    /// Build the posts sitemap synchronously, passing progress to `report`
+    fn build_posts_sitemap(&self, posts: Vec<PostData>, report: impl Fn(SitemapProgress)) -> Sitemap {
+        const REPORT_EVERY: usize = 100;
+
+        let total = posts.len();
+        let mut kept = Vec::with_capacity(total);
+        for (i, post) in posts.into_iter().enumerate() {
+            let noindex = self.meta_filter.as_ref()
+                .and_then(|meta_map| meta_map.get(&post.url))
+                .is_some_and(|robots| !robots.index);
+            if !noindex && !self.is_excluded(&post.url) {
+                kept.push(post);
             }
+            if (i + 1) % REPORT_EVERY == 0 {
+                report(SitemapProgress { processed: i + 1, total, phase: ProgressPhase::Filtering });
+            }
+        }
+        report(SitemapProgress { processed: total, total, phase: ProgressPhase::Filtering });
 
+        let total = kept.len();
+        let mut sitemap = Sitemap::new(SitemapType::Posts);
+        // Header, namespaces and closing tag
+        let mut size_bytes = 512;
+        for (i, post) in kept.into_iter().enumerate() {
+            let url = self.post_url(post);
+            size_bytes += url.estimated_xml_size();
             sitemap.urls.push(url);
+            if (i + 1) % REPORT_EVERY == 0 {
+                report(SitemapProgress { processed: i + 1, total, phase: ProgressPhase::Building });
+            }
         }
+        report(SitemapProgress { processed: total, total, phase: ProgressPhase::Building });
+
+        if total > 50000 || size_bytes > 50 * 1024 * 1024 {
+            tracing::warn!("Posts sitemap exceeds limits: {} URLs, {} bytes", total, size_bytes);
+        }
+        report(SitemapProgress { processed: total, total, phase: ProgressPhase::Validating });
 
         sitemap
     }
 
+    /// Generate posts sitemap on a background task, streaming progress over a channel
+    pub async fn generate_posts_sitemap_async(
+        &self,
+        posts: Vec<PostData>,
+    ) -> (tokio::task::JoinHandle<Sitemap>, tokio::sync::mpsc::UnboundedReceiver<SitemapProgress>) {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        let service = self.clone();
+
+        let handle = tokio::spawn(async move {
+            service
+                .generate_with_progress(posts, move |progress| {
+                    // The caller may stop listening; generation carries on regardless
+                    let _ = tx.send(progress);
+                })
+                .await
+        });
+
+        (handle, rx)
+    }
+
+    /// Sitemap entry for a post
+    fn post_url(&self, post: PostData) -> SitemapUrl {
+        let mut url = SitemapUrl::new(post.url)
+            .with_lastmod(post.modified_at)
+            .with_changefreq(ChangeFrequency::Weekly)
+            .with_priority(0.8);
+
+        if self.config.include_images {
+            for image in post.images {
+                url.images.push(SitemapImage {
+                    loc: image.url,
+                    title: image.title,
+                    caption: image.caption,
+                    geo_location: None,
+                    license: None,
+                });
+            }
+        }
+
+        url
+    }
+
     /// Generate pages sitemap
     pub fn generate_pages_sitemap(&self, pages: Vec<PageData>) -> Sitemap {
         let mut sitemap = Sitemap::new(SitemapType::Pages);
@@ -97,7 +435,7 @@ impl SitemapService {
             sitemap.urls.push(url);
         }
 
-        sitemap
+        self.apply_meta_filter(sitemap)
     }
 
     /// Generate categories sitemap
@@ -117,7 +455,7 @@ impl SitemapService {
             sitemap.urls.push(url);
         }
 
-        sitemap
+        self.apply_meta_filter(sitemap)
     }
 
     /// Generate products sitemap
@@ -149,7 +487,130 @@ impl SitemapService {
             sitemap.urls.push(url);
         }
 
-        sitemap
+        self.apply_meta_filter(sitemap)
+    }
+
+    /// Generate Google News sitemap from recent articles
+    pub fn generate_news_sitemap(&self, articles: Vec<NewsArticleData>, settings: &NewsSitemapSettings) -> Sitemap {
+        // Google News only accepts articles from the last two days, at most 1000 of them
+        const MAX_NEWS_URLS: usize = 1000;
+
+        let cutoff = Utc::now() - chrono::Duration::days(settings.max_age_days.max(0) as i64);
+        let mut articles: Vec<NewsArticleData> = articles.into_iter()
+            .filter(|article| article.publication_date >= cutoff && !self.is_excluded(&article.url))
+            .collect();
+        articles.sort_by_key(|article| std::cmp::Reverse(article.publication_date));
+        articles.truncate(MAX_NEWS_URLS);
+
+        let mut sitemap = Sitemap::new(SitemapType::News);
+        for article in articles {
+            let publication_name = if article.publication_name.is_empty() {
+                settings.publication_name.clone()
+            } else {
+                article.publication_name
+            };
+            let publication_language = if article.publication_language.is_empty() {
+                settings.publication_language.clone()
+            } else {
+                article.publication_language
+            };
+
+            let mut url = SitemapUrl::new(article.url);
+            url.news = Some(SitemapNews {
+                publication_name,
+                publication_language,
+                publication_date: article.publication_date,
+                title: article.title,
+                keywords: article.keywords,
+                stock_tickers: vec![],
+            });
+            sitemap.urls.push(url);
+        }
+
+        self.apply_meta_filter(sitemap)
+    }
+
+    /// Generate video sitemap, grouping videos by the page they appear on
+    pub fn generate_video_sitemap(&self, videos: Vec<VideoSitemapData>) -> Sitemap {
+        let mut sitemap = Sitemap::new(SitemapType::Videos);
+
+        for video in videos {
+            if self.is_excluded(&video.url) {
+                continue;
+            }
+            // Google needs either the media file or a player to index a video
+            if video.content_loc.is_none() && video.player_loc.is_none() {
+                tracing::debug!("Skipping video without content or player location on {}", video.url);
+                continue;
+            }
+
+            let entry = SitemapVideo {
+                content_loc: video.content_loc,
+                player_loc: video.player_loc,
+                thumbnail_loc: video.thumbnail_loc,
+                title: video.title,
+                description: video.description,
+                duration: video.duration,
+                expiration_date: None,
+                rating: None,
+                view_count: None,
+                publication_date: video.publication_date,
+                family_friendly: true,
+                tags: video.tags,
+                category: None,
+                requires_subscription: false,
+                live: false,
+            };
+
+            match sitemap.urls.iter_mut().find(|url| url.loc == video.url) {
+                Some(url) => url.videos.push(entry),
+                None => {
+                    let mut url = SitemapUrl::new(video.url);
+                    url.videos.push(entry);
+                    sitemap.urls.push(url);
+                }
+            }
+        }
+
+        self.apply_meta_filter(sitemap)
+    }
+
+    /// Attach hreflang alternates to URLs, keyed by canonical URL
+    ///
+    /// With a `default_locale`, its alternate is repeated as the `x-default` entry.
+    pub fn add_hreflang_alternates(
+        urls: &mut [SitemapUrl],
+        alternates: &HashMap<String, Vec<(String, String)>>,
+        default_locale: Option<&str>,
+    ) {
+        for url in urls.iter_mut() {
+            let Some(languages) = alternates.get(&url.loc) else {
+                continue;
+            };
+
+            for (hreflang, href) in languages {
+                if !url.alternates.iter().any(|alt| alt.hreflang.eq_ignore_ascii_case(hreflang)) {
+                    url.alternates.push(SitemapAlternate {
+                        hreflang: hreflang.clone(),
+                        href: href.clone(),
+                    });
+                }
+            }
+
+            let default_href = default_locale.and_then(|locale| {
+                languages.iter()
+                    .find(|(hreflang, _)| hreflang.eq_ignore_ascii_case(locale))
+                    .map(|(_, href)| href.clone())
+            });
+            if let Some(href) = default_href {
+                if !url.alternates.iter().any(|alt| alt.hreflang == "x-default") {
+                    url.alternates.push(SitemapAlternate {
+                        hreflang: "x-default".to_string(),
+                        href,
+                    });
+                }
+            }
+        }
     }
 
     /// Check if URL is excluded
@@ -171,39 +632,193 @@ impl SitemapService {
             return results;
         }
 
-        // Google
-        results.push(PingResult {
-            search_engine: "Google".to_string(),
-            url: format!(
-                "https://www.google.com/ping?sitemap={}",
-                urlencoding::encode(&sitemap_url)
-            ),
-            success: true, // Would actually make HTTP request
-            message: None,
-        });
+        let endpoints = [
+            ("Google", "https://www.google.com/ping?sitemap="),
+            ("Bing", "https://www.bing.com/ping?sitemap="),
+        ];
 
-        // Bing
-        results.push(PingResult {
-            search_engine: "Bing".to_string(),
-            url: format!(
-                "https://www.bing.com/ping?sitemap={}",
-                urlencoding::encode(&sitemap_url)
-            ),
-            success: true,
-            message: None,
-        });
+        for (search_engine, endpoint) in endpoints {
+            let url = format!("{}{}", endpoint, urlencoding::encode(&sitemap_url));
+            results.push(Self::send_ping(search_engine, url).await);
+        }
 
         results
     }
 
+    /// Request a ping URL, retrying server errors with exponential back-off
+    #[cfg(feature = "ping")]
+    async fn send_ping(search_engine: &str, url: String) -> PingResult {
+        const MAX_ATTEMPTS: u32 = 3;
+
+        let client = match reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(10))
+            .build()
+        {
+            Ok(client) => client,
+            Err(e) => {
+                return PingResult {
+                    search_engine: search_engine.to_string(),
+                    url,
+                    success: false,
+                    message: Some(e.to_string()),
+                    status_code: None,
+                    response_time_ms: None,
+                };
+            }
+        };
+
+        let mut attempt = 1;
+        loop {
+            let started = std::time::Instant::now();
+            let response = client.get(&url).send().await;
+            let response_time_ms = Some(started.elapsed().as_millis() as u64);
+
+            match response {
+                Ok(response) if response.status().is_server_error() && attempt < MAX_ATTEMPTS => {
+                    tracing::debug!(
+                        "{} ping returned {}, retrying (attempt {})",
+                        search_engine, response.status(), attempt
+                    );
+                    tokio::time::sleep(std::time::Duration::from_millis(500 * 2u64.pow(attempt - 1))).await;
+                    attempt += 1;
+                }
+                Ok(response) => {
+                    let status = response.status();
+                    return PingResult {
+                        search_engine: search_engine.to_string(),
+                        url,
+                        success: status.is_success(),
+                        message: Some(format!("HTTP {}", status)),
+                        status_code: Some(status.as_u16()),
+                        response_time_ms,
+                    };
+                }
+                Err(e) => {
+                    tracing::warn!("{} ping failed: {}", search_engine, e);
+                    return PingResult {
+                        search_engine: search_engine.to_string(),
+                        url,
+                        success: false,
+                        message: Some(e.to_string()),
+                        status_code: e.status().map(|s| s.as_u16()),
+                        response_time_ms,
+                    };
+                }
+            }
+        }
+    }
+
+    /// Pinging is compiled out; report the URL without sending anything
+    #[cfg(not(feature = "ping"))]
+    async fn send_ping(search_engine: &str, url: String) -> PingResult {
+        PingResult {
+            search_engine: search_engine.to_string(),
+            url,
+            success: false,
+            message: Some("Ping not sent: the `ping` feature is disabled".to_string()),
+            status_code: None,
+            response_time_ms: None,
+        }
+    }
+
+    /// Check that sitemap URLs are reachable using HEAD requests
+    #[cfg(feature = "sitemap-validation")]
+    pub async fn validate_urls_reachability(
+        &self,
+        urls: &[SitemapUrl],
+        options: ReachabilityOptions,
+    ) -> ReachabilityReport {
+        self.validate_urls_reachability_with_progress(urls, options, |_| {}).await
+    }
+
+    /// Reachability check that reports the partial result after each URL
+    #[cfg(feature = "sitemap-validation")]
+    pub async fn validate_urls_reachability_with_progress<F: Fn(&ReachabilityReport)>(
+        &self,
+        urls: &[SitemapUrl],
+        options: ReachabilityOptions,
+        on_progress: F,
+    ) -> ReachabilityReport {
+        use rand::seq::SliceRandom;
+        use std::sync::Arc;
+        use tokio::sync::Semaphore;
+        use tokio::task::JoinSet;
+
+        let mut report = ReachabilityReport::default();
+
+        let client = match reqwest::Client::builder()
+            .timeout(std::time::Duration::from_millis(options.timeout_ms))
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+        {
+            Ok(client) => client,
+            Err(e) => {
+                tracing::warn!("Failed to build HTTP client for reachability check: {}", e);
+                return report;
+            }
+        };
+
+        let mut locs: Vec<String> = urls.iter().map(|u| u.loc.clone()).collect();
+        if let Some(sample_size) = options.sample_size {
+            if sample_size < locs.len() {
+                locs = locs
+                    .choose_multiple(&mut rand::thread_rng(), sample_size)
+                    .cloned()
+                    .collect();
+            }
+        }
+
+        let semaphore = Arc::new(Semaphore::new(options.max_concurrent.max(1)));
+        let mut tasks = JoinSet::new();
+
+        for loc in locs {
+            let client = client.clone();
+            let semaphore = semaphore.clone();
+            tasks.spawn(async move {
+                let _permit = semaphore.acquire_owned().await;
+                let status = client.head(&loc).send().await.map(|r| r.status());
+                (loc, status)
+            });
+        }
+
+        while let Some(joined) = tasks.join_next().await {
+            let Ok((loc, status)) = joined else {
+                continue;
+            };
+
+            report.checked += 1;
+            match status {
+                Ok(status) if status.is_success() => report.ok += 1,
+                Ok(status) if status.is_redirection() => report.redirects += 1,
+                Ok(status) if status.as_u16() == 404 || status.as_u16() == 410 => {
+                    report.not_found += 1;
+                    report.unreachable.push(loc);
+                }
+                Ok(status) if status.is_server_error() => {
+                    report.server_error += 1;
+                    report.unreachable.push(loc);
+                }
+                _ => report.unreachable.push(loc),
+            }
+            on_progress(&report);
+        }
+
+        report
+    }
+
     /// Get sitemap URL
     pub fn get_sitemap_url(&self, sitemap_type: &SitemapType) -> String {
-        format!("{}/{}", self.site_url, sitemap_type.filename())
+        self.get_file_url(sitemap_type.filename())
     }
 
     /// Get sitemap index URL
     pub fn get_index_url(&self) -> String {
-        format!("{}/sitemap_index.xml", self.site_url)
+        self.get_file_url(INDEX_FILENAME)
+    }
+
+    /// Public URL of a published sitemap file
+    pub fn get_file_url(&self, filename: &str) -> String {
+        format!("{}/{}", self.site_url, filename)
     }
 
     /// Validate sitemap
@@ -243,6 +858,58 @@ impl SitemapService {
             size_bytes: xml.len(),
         }
     }
+
+    /// Sum of per-URL crawl budget costs, see `SitemapUrl::estimated_crawl_budget_cost`
+    pub fn total_crawl_budget_estimate(sitemap: &Sitemap) -> f32 {
+        sitemap.urls.iter().map(SitemapUrl::estimated_crawl_budget_cost).sum()
+    }
+}
+
+/// Options for sitemap URL reachability checks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReachabilityOptions {
+    pub timeout_ms: u64,
+    pub max_concurrent: usize,
+    /// Check a random sample instead of every URL
+    pub sample_size: Option<usize>,
+}
+
+impl Default for ReachabilityOptions {
+    fn default() -> Self {
+        Self {
+            timeout_ms: 5000,
+            max_concurrent: 10,
+            sample_size: None,
+        }
+    }
+}
+
+/// Result of a sitemap URL reachability check
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReachabilityReport {
+    pub checked: usize,
+    pub ok: usize,
+    pub not_found: usize,
+    pub server_error: usize,
+    pub redirects: usize,
+    pub unreachable: Vec<String>,
+}
+
+/// Progress of a long-running sitemap generation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SitemapProgress {
+    pub processed: usize,
+    pub total: usize,
+    pub phase: ProgressPhase,
+}
+
+/// Stage of sitemap generation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProgressPhase {
+    Filtering,
+    Building,
+    Validating,
 }
 
 /// Post data for sitemap generation
@@ -273,6 +940,31 @@ pub struct ProductData {
     pub images: Vec<ImageData>,
 }
 
+/// News article data for sitemap generation
+pub struct NewsArticleData {
+    pub title: String,
+    pub url: String,
+    pub publication_date: DateTime<Utc>,
+    pub keywords: Vec<String>,
+    pub publication_name: String,
+    pub publication_language: String,
+}
+
+/// Video data for sitemap generation
+pub struct VideoSitemapData {
+    /// Page the video is embedded on
+    pub url: String,
+    pub thumbnail_loc: String,
+    pub title: String,
+    pub description: String,
+    pub content_loc: Option<String>,
+    pub player_loc: Option<String>,
+    /// Duration in seconds
+    pub duration: Option<i32>,
+    pub tags: Vec<String>,
+    pub publication_date: Option<DateTime<Utc>>,
+}
+
 /// Image data
 pub struct ImageData {
     pub url: String,
@@ -286,6 +978,8 @@ pub struct PingResult {
     pub url: String,
     pub success: bool,
     pub message: Option<String>,
+    pub status_code: Option<u16>,
+    pub response_time_ms: Option<u64>,
 }
 
 /// Validation result
@@ -298,3 +992,299 @@ pub struct ValidationResult {
 }
 
 use urlencoding;
+
+impl std::fmt::Debug for SitemapService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SitemapService")
+            .field("site_url", &self.site_url)
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Destination for published sitemap XML files
+pub trait SitemapStore: Send + Sync {
+    /// Write a file, replacing any previous version
+    fn write(&self, filename: &str, xml: &str) -> std::io::Result<()>;
+
+    fn read(&self, filename: &str) -> Option<String>;
+
+    /// Stored file names with their last write time, sorted by name
+    fn list(&self) -> Vec<(String, DateTime<Utc>)>;
+}
+
+/// Sitemap files in a directory, e.g. the site's web root
+#[derive(Debug, Clone)]
+pub struct FileSitemapStore {
+    dir: PathBuf,
+}
+
+impl FileSitemapStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+impl SitemapStore for FileSitemapStore {
+    fn write(&self, filename: &str, xml: &str) -> std::io::Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        std::fs::write(self.dir.join(filename), xml)
+    }
+
+    fn read(&self, filename: &str) -> Option<String> {
+        std::fs::read_to_string(self.dir.join(filename)).ok()
+    }
+
+    fn list(&self) -> Vec<(String, DateTime<Utc>)> {
+        let Ok(entries) = std::fs::read_dir(&self.dir) else {
+            return vec![];
+        };
+
+        let mut files: Vec<(String, DateTime<Utc>)> = entries
+            .filter_map(Result::ok)
+            .filter_map(|entry| {
+                let filename = entry.file_name().into_string().ok()?;
+                let modified = entry.metadata().ok()?.modified().ok()?;
+                filename.ends_with(".xml").then(|| (filename, DateTime::<Utc>::from(modified)))
+            })
+            .collect();
+        files.sort();
+        files
+    }
+}
+
+/// Sitemap files kept in memory
+#[derive(Debug, Default)]
+pub struct InMemorySitemapStore {
+    files: RwLock<HashMap<String, (String, DateTime<Utc>)>>,
+}
+
+impl InMemorySitemapStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl SitemapStore for InMemorySitemapStore {
+    fn write(&self, filename: &str, xml: &str) -> std::io::Result<()> {
+        self.files
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(filename.to_string(), (xml.to_string(), Utc::now()));
+        Ok(())
+    }
+
+    fn read(&self, filename: &str) -> Option<String> {
+        let files = self.files.read().unwrap_or_else(PoisonError::into_inner);
+        files.get(filename).map(|(xml, _)| xml.clone())
+    }
+
+    fn list(&self) -> Vec<(String, DateTime<Utc>)> {
+        let files = self.files.read().unwrap_or_else(PoisonError::into_inner);
+        let mut list: Vec<(String, DateTime<Utc>)> = files.iter()
+            .map(|(filename, (_, modified))| (filename.clone(), *modified))
+            .collect();
+        list.sort();
+        list
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_noindex_urls_excluded_from_sitemap() {
+        let mut meta_map = HashMap::new();
+        meta_map.insert("https://example.com/hidden".to_string(), MetaRobots::noindex());
+        meta_map.insert("https://example.com/visible".to_string(), MetaRobots::new());
+
+        let service = SitemapService::new("https://example.com".to_string())
+            .with_meta_filter(Arc::new(meta_map));
+
+        let posts = ["visible", "hidden", "unlisted"]
+            .iter()
+            .map(|slug| PostData {
+                url: format!("https://example.com/{}", slug),
+                modified_at: Utc::now(),
+                images: vec![],
+            })
+            .collect();
+
+        let xml = service.generate_posts_sitemap(posts).to_xml();
+
+        assert!(xml.contains("https://example.com/visible"));
+        assert!(xml.contains("https://example.com/unlisted"));
+        assert!(!xml.contains("https://example.com/hidden"));
+    }
+
+    #[tokio::test]
+    async fn test_generate_with_progress_reports_each_phase() {
+        let service = SitemapService::new("https://example.com".to_string());
+        let posts = (0..250)
+            .map(|i| PostData {
+                url: format!("https://example.com/post-{}", i),
+                modified_at: Utc::now(),
+                images: vec![],
+            })
+            .collect();
+
+        let reports = std::sync::Mutex::new(Vec::new());
+        let sitemap = service
+            .generate_with_progress(posts, |progress| reports.lock().unwrap().push(progress))
+            .await;
+        let reports = reports.into_inner().unwrap();
+
+        assert_eq!(sitemap.urls.len(), 250);
+        assert_eq!(reports.iter().filter(|p| p.phase == ProgressPhase::Building).count(), 3);
+        let last = reports.last().unwrap();
+        assert_eq!(last.phase, ProgressPhase::Validating);
+        assert_eq!(last.processed, 250);
+    }
+
+    #[test]
+    fn test_estimated_xml_size_matches_rendered_entry() {
+        let mut url = SitemapUrl::new("https://example.com/post".to_string())
+            .with_lastmod(Utc::now())
+            .with_changefreq(ChangeFrequency::Weekly)
+            .with_priority(0.8);
+        url.images.push(SitemapImage {
+            loc: "https://example.com/image.jpg".to_string(),
+            title: Some("Title".to_string()),
+            caption: Some("Caption".to_string()),
+            geo_location: None,
+            license: None,
+        });
+        url.alternates.push(SitemapAlternate {
+            hreflang: "de".to_string(),
+            href: "https://example.com/de/post".to_string(),
+        });
+
+        let mut one = Sitemap::new(SitemapType::Posts);
+        one.urls.push(url.clone());
+        let mut two = one.clone();
+        two.urls.push(url.clone());
+
+        assert_eq!(two.to_xml().len() - one.to_xml().len(), url.estimated_xml_size());
+    }
+
+    #[test]
+    fn test_news_sitemap_skips_stale_articles() {
+        let service = SitemapService::new("https://example.com".to_string());
+        let settings = NewsSitemapSettings {
+            publication_name: "Example Times".to_string(),
+            ..Default::default()
+        };
+        let article = |slug: &str, age_hours: i64| NewsArticleData {
+            title: format!("Story {}", slug),
+            url: format!("https://example.com/{}", slug),
+            publication_date: Utc::now() - chrono::Duration::hours(age_hours),
+            keywords: vec!["local".to_string()],
+            publication_name: String::new(),
+            publication_language: String::new(),
+        };
+
+        let sitemap = service.generate_news_sitemap(vec![article("fresh", 3), article("stale", 72)], &settings);
+        let xml = sitemap.to_xml();
+
+        assert_eq!(sitemap.urls.len(), 1);
+        assert!(xml.contains("xmlns:news="));
+        assert!(xml.contains("<news:name>Example Times</news:name>"));
+        assert!(xml.contains("<news:language>en</news:language>"));
+        assert!(xml.contains("<news:keywords>local</news:keywords>"));
+        assert!(!xml.contains("https://example.com/stale"));
+    }
+
+    fn posts_sitemap(count: usize) -> Sitemap {
+        let mut sitemap = Sitemap::new(SitemapType::Posts);
+        sitemap.urls = (0..count)
+            .map(|i| SitemapUrl::new(format!("https://example.com/post-{}", i)))
+            .collect();
+        sitemap
+    }
+
+    #[test]
+    fn test_split_large_sitemap_numbers_chunks() {
+        let chunks = SitemapService::split_large_sitemap(posts_sitemap(5), 2);
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[2].urls.len(), 1);
+        assert!(chunks.iter().all(|c| c.sitemap_type == SitemapType::Posts));
+
+        let filenames: Vec<String> = chunks.iter().map(Sitemap::filename).collect();
+        assert_eq!(filenames, vec!["post-sitemap1.xml", "post-sitemap2.xml", "post-sitemap3.xml"]);
+
+        let index = SitemapService::new("https://example.com".to_string()).generate_index(&chunks);
+        assert_eq!(index.sitemaps.len(), 3);
+        assert_eq!(index.sitemaps[1].loc, "https://example.com/post-sitemap2.xml");
+        assert_eq!(
+            SitemapService::infer_sitemap_type_from_filename(&index.sitemaps[1].loc),
+            Some(SitemapType::Posts)
+        );
+    }
+
+    #[test]
+    fn test_publish_skips_unchanged_sitemaps() {
+        let service = SitemapService::new("https://example.com".to_string());
+
+        let first = service.publish(vec![posts_sitemap(2)]);
+        assert_eq!(first.sitemaps_generated, 1);
+        assert_eq!(first.urls_added, 2);
+        assert!(service.store().read("post-sitemap.xml").unwrap().contains("https://example.com/post-1"));
+        assert!(service.store().read(INDEX_FILENAME).unwrap().contains("post-sitemap.xml"));
+
+        // A rebuilt service sharing the state sees the previous run
+        let rebuilt = SitemapService::new("https://example.com".to_string()).share_state_with(&service);
+        let second = rebuilt.publish(vec![posts_sitemap(2)]);
+        assert_eq!(second.sitemaps_generated, 0);
+        assert_eq!(second.sitemaps_unchanged, 1);
+
+        let third = rebuilt.publish(vec![posts_sitemap(3)]);
+        assert_eq!(third.sitemaps_generated, 1);
+        assert_eq!(third.urls_added, 1);
+        assert_eq!(service.published_urls(Some(SitemapType::Posts)).len(), 3);
+        assert!(service.published_urls(Some(SitemapType::Pages)).is_empty());
+    }
+
+    #[test]
+    fn test_split_large_sitemap_exactly_max_urls() {
+        let chunks = SitemapService::split_large_sitemap(posts_sitemap(3), 3);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].urls.len(), 3);
+        assert_eq!(chunks[0].filename(), "post-sitemap.xml");
+    }
+
+    #[test]
+    fn test_split_large_sitemap_single_item() {
+        let chunks = SitemapService::split_large_sitemap(posts_sitemap(1), 1);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].part, None);
+        assert_eq!(chunks[0].urls[0].loc, "https://example.com/post-0");
+    }
+
+    #[test]
+    fn test_hreflang_alternates_with_x_default() {
+        let mut urls = vec![
+            SitemapUrl::new("https://example.com/about".to_string()),
+            SitemapUrl::new("https://example.com/contact".to_string()),
+        ];
+        let mut alternates = HashMap::new();
+        alternates.insert("https://example.com/about".to_string(), vec![
+            ("en".to_string(), "https://example.com/about".to_string()),
+            ("de".to_string(), "https://example.com/de/uber-uns".to_string()),
+        ]);
+
+        SitemapService::add_hreflang_alternates(&mut urls, &alternates, Some("en"));
+
+        let hreflangs: Vec<&str> = urls[0].alternates.iter().map(|a| a.hreflang.as_str()).collect();
+        assert_eq!(hreflangs, vec!["en", "de", "x-default"]);
+        assert_eq!(urls[0].alternates[2].href, "https://example.com/about");
+        assert!(urls[1].alternates.is_empty());
+
+        let mut sitemap = Sitemap::new(SitemapType::Hreflang);
+        sitemap.urls = urls;
+        let xml = sitemap.to_xml();
+        assert!(xml.contains("xmlns:xhtml="));
+        assert!(xml.contains("hreflang=\"x-default\""));
+        assert_eq!(sitemap.filename(), "hreflang-sitemap.xml");
+    }
+}
@@ -3,9 +3,15 @@
 //! Plugin settings configuration and defaults.
 
 use serde::{Deserialize, Serialize};
+use crate::models::meta::{ContentType, MetaRobots, SeoMeta};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::{OnceLock, PoisonError, RwLock};
+use uuid::Uuid;
 
 /// Main settings structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct SeoSettings {
     pub site_url: String,
     pub site_name: String,
@@ -42,6 +48,7 @@ impl Default for SeoSettings {
 
 /// General settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct GeneralSettings {
     pub enable_seo_analysis: bool,
     pub enable_readability_analysis: bool,
@@ -68,6 +75,7 @@ impl Default for GeneralSettings {
 
 /// Title settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TitleSettings {
     pub rewrite_titles: bool,
     pub force_rewrite: bool,
@@ -102,6 +110,7 @@ impl Default for TitleSettings {
 
 /// Meta settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct MetaSettings {
     pub generate_description: bool,
     pub description_length: i32,
@@ -111,6 +120,20 @@ pub struct MetaSettings {
     pub noindex_subpages: bool,
     pub noindex_search: bool,
     pub noindex_archives: bool,
+    #[serde(default)]
+    pub canonical_strategy: CanonicalStrategy,
+}
+
+/// How canonical URLs are normalized before output
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CanonicalStrategy {
+    TrailingSlash,
+    NoTrailingSlash,
+    #[default]
+    Preserve,
+    /// `"pattern => replacement"` regex; a bare pattern removes its matches
+    Custom(String),
 }
 
 impl Default for MetaSettings {
@@ -124,12 +147,14 @@ impl Default for MetaSettings {
             noindex_subpages: false,
             noindex_search: true,
             noindex_archives: false,
+            canonical_strategy: CanonicalStrategy::Preserve,
         }
     }
 }
 
 /// Sitemap settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct SitemapSettings {
     pub enabled: bool,
     pub include_posts: bool,
@@ -164,6 +189,7 @@ impl Default for SitemapSettings {
 
 /// Schema settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct SchemaSettings {
     pub enabled: bool,
     pub organization_type: String,
@@ -210,11 +236,14 @@ pub struct LocalBusinessSettings {
 
 /// Social media settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct SocialSettings {
     pub enabled: bool,
     pub facebook: FacebookSettings,
     pub twitter: TwitterSettings,
     pub default_image: Option<String>,
+    /// Mastodon account, as `@user@instance` or a profile URL
+    pub mastodon: Option<String>,
 }
 
 impl Default for SocialSettings {
@@ -224,11 +253,13 @@ impl Default for SocialSettings {
             facebook: FacebookSettings::default(),
             twitter: TwitterSettings::default(),
             default_image: None,
+            mastodon: None,
         }
     }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct FacebookSettings {
     pub opengraph_enabled: bool,
     pub app_id: Option<String>,
@@ -248,6 +279,7 @@ impl Default for FacebookSettings {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TwitterSettings {
     pub cards_enabled: bool,
     pub card_type: String,
@@ -268,12 +300,15 @@ impl Default for TwitterSettings {
 
 /// Redirect settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct RedirectSettings {
     pub enabled: bool,
     pub log_404s: bool,
     pub max_404_logs: i32,
     pub case_insensitive: bool,
     pub auto_redirect_slug_change: bool,
+    /// Hops a redirect chain may have before it is flagged
+    pub max_redirect_chain: i32,
 }
 
 impl Default for RedirectSettings {
@@ -284,12 +319,53 @@ impl Default for RedirectSettings {
             max_404_logs: 1000,
             case_insensitive: true,
             auto_redirect_slug_change: true,
+            max_redirect_chain: crate::models::redirect::DEFAULT_MAX_REDIRECT_CHAIN as i32,
+        }
+    }
+}
+
+impl SitemapSettings {
+    /// Settings in the form the sitemap service expects
+    pub fn to_sitemap_config(&self) -> crate::models::sitemap::SitemapConfig {
+        use crate::models::sitemap::SitemapType;
+
+        let content_types = [
+            (self.include_posts, SitemapType::Posts),
+            (self.include_pages, SitemapType::Pages),
+            (self.include_categories, SitemapType::Categories),
+            (self.include_tags, SitemapType::Tags),
+            (self.include_authors, SitemapType::Authors),
+        ];
+
+        crate::models::sitemap::SitemapConfig {
+            enabled: self.enabled,
+            max_urls_per_sitemap: self.max_entries_per_sitemap.max(1) as usize,
+            include_images: self.include_images,
+            ping_search_engines: self.ping_on_publish,
+            content_types: content_types.into_iter()
+                .filter_map(|(included, sitemap_type)| included.then_some(sitemap_type))
+                .collect(),
+            ..Default::default()
+        }
+    }
+}
+
+impl RedirectSettings {
+    /// Settings in the form the redirect service expects
+    pub fn to_redirect_settings(&self) -> crate::models::redirect::RedirectSettings {
+        crate::models::redirect::RedirectSettings {
+            enabled: self.enabled,
+            log_404s: self.log_404s,
+            case_insensitive: self.case_insensitive,
+            max_redirect_chain: self.max_redirect_chain.max(1) as usize,
+            ..Default::default()
         }
     }
 }
 
 /// Robots.txt settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct RobotsSettings {
     pub enabled: bool,
     pub custom_rules: String,
@@ -308,8 +384,22 @@ impl Default for RobotsSettings {
     }
 }
 
+impl RobotsSettings {
+    /// Settings in the form the robots.txt service expects
+    pub fn to_robots_txt_settings(&self) -> crate::models::robots::RobotsTxtSettings {
+        crate::models::robots::RobotsTxtSettings {
+            enabled: self.enabled,
+            include_sitemap: self.include_sitemap,
+            block_ai_crawlers: self.block_ai_crawlers,
+            custom_rules: self.custom_rules.clone(),
+            ..Default::default()
+        }
+    }
+}
+
 /// Advanced settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AdvancedSettings {
     pub clean_permalinks: bool,
     pub remove_replytocom: bool,
@@ -352,26 +442,82 @@ impl SeoSettings {
 
         // Validate site URL
         if self.site_url.is_empty() {
-            warnings.push("Site URL is not set".to_string());
-        } else if !self.site_url.starts_with("http://") && !self.site_url.starts_with("https://") {
-            errors.push("Site URL must start with http:// or https://".to_string());
+            warnings.push(ValidationIssue::field("site_url", "Site URL is not set"));
+        } else if !self.site_url.starts_with("https://") {
+            errors.push(ValidationIssue::field("site_url", "Site URL must start with https://"));
         }
 
         // Validate site name
         if self.site_name.is_empty() {
-            warnings.push("Site name is not set".to_string());
+            warnings.push(ValidationIssue::field("site_name", "Site name is not set"));
         }
 
         // Validate meta description length
         if self.meta.description_length < 50 {
-            warnings.push("Meta description length is very short".to_string());
+            warnings.push(ValidationIssue::field("meta.description_length", "Meta description length is very short"));
         } else if self.meta.description_length > 320 {
-            warnings.push("Meta description length is very long".to_string());
+            warnings.push(ValidationIssue::field("meta.description_length", "Meta description length is very long"));
         }
 
         // Validate sitemap settings
-        if self.sitemap.max_entries_per_sitemap > 50000 {
-            errors.push("Sitemap max entries cannot exceed 50000".to_string());
+        if self.sitemap.max_entries_per_sitemap < 1 {
+            errors.push(ValidationIssue::field("sitemap.max_entries_per_sitemap", "Sitemap max entries must be at least 1"));
+        } else if self.sitemap.max_entries_per_sitemap > 50000 {
+            errors.push(ValidationIssue::field("sitemap.max_entries_per_sitemap", "Sitemap max entries cannot exceed 50000"));
+        }
+
+        // Validate schema settings
+        if self.schema.enabled && self.schema.organization_name.trim().is_empty() {
+            warnings.push(ValidationIssue::field(
+                "schema.organization_name",
+                "Organization name is not set, so no Organization schema will be output",
+            ));
+        }
+
+        // Validate social settings
+        if let Some(app_id) = &self.social.facebook.app_id {
+            if app_id.is_empty() || !app_id.chars().all(|c| c.is_ascii_digit()) {
+                errors.push(ValidationIssue::field("social.facebook.app_id", "Facebook app ID must be numeric"));
+            }
+        }
+        if let Some(username) = &self.social.twitter.site_username {
+            if !username.starts_with('@') || username.len() < 2 {
+                errors.push(ValidationIssue::field("social.twitter.site_username", "Twitter username must start with @"));
+            }
+        }
+        if let Some(mastodon) = &self.social.mastodon {
+            if crate::models::social::MastodonMetaData::parse_account(mastodon).is_none() {
+                errors.push(ValidationIssue::field(
+                    "social.mastodon",
+                    "Mastodon account must be @user@instance or https://instance/@user",
+                ));
+            }
+        }
+
+        // Validate redirect settings
+        if !(1..=20).contains(&self.redirects.max_redirect_chain) {
+            errors.push(ValidationIssue::field(
+                "redirects.max_redirect_chain",
+                "Maximum redirect chain must be between 1 and 20",
+            ));
+        }
+
+        // Validate URL fields
+        let mut urls: Vec<(String, &str)> = vec![
+            ("schema.organization_logo".to_string(), self.schema.organization_logo.as_deref().unwrap_or_default()),
+            ("schema.organization_url".to_string(), self.schema.organization_url.as_deref().unwrap_or_default()),
+            ("social.default_image".to_string(), self.social.default_image.as_deref().unwrap_or_default()),
+            ("social.facebook.default_image".to_string(), self.social.facebook.default_image.as_deref().unwrap_or_default()),
+            ("social.twitter.default_image".to_string(), self.social.twitter.default_image.as_deref().unwrap_or_default()),
+        ];
+        urls.extend(self.schema.social_profiles.iter()
+            .enumerate()
+            .map(|(i, url)| (format!("schema.social_profiles[{}]", i), url.as_str())));
+
+        for (field, url) in urls {
+            if !url.is_empty() && !url.starts_with("https://") {
+                errors.push(ValidationIssue::field(&field, "URL must start with https://"));
+            }
         }
 
         ValidationResult {
@@ -382,65 +528,982 @@ impl SeoSettings {
     }
 }
 
+/// Robots decisions
+impl SeoSettings {
+    /// Robots directives the settings impose on a page
+    pub fn effective_robots_for(&self, ctx: &ContentContext) -> MetaRobots {
+        self.meta.effective_robots_for(ctx)
+    }
+}
+
+impl MetaSettings {
+    /// Robots directives the meta settings impose on a page
+    pub fn effective_robots_for(&self, ctx: &ContentContext) -> MetaRobots {
+        if (ctx.is_search && self.noindex_search)
+            || (ctx.is_archive && self.noindex_archives)
+            || (ctx.is_paginated && self.noindex_subpages)
+        {
+            return MetaRobots::noindex();
+        }
+
+        MetaRobots::new()
+    }
+}
+
+/// Yoast SEO post meta compatibility
+impl SeoSettings {
+    /// Map content meta to Yoast `_yoast_wpseo_*` post meta keys
+    pub fn to_wp_seo_json(meta: &SeoMeta) -> Value {
+        let mut value = json!({
+            "_yoast_wpseo_title": meta.title.clone().unwrap_or_default(),
+            "_yoast_wpseo_metadesc": meta.description.clone().unwrap_or_default(),
+            "_yoast_wpseo_focuskw": meta.focus_keyword.clone().unwrap_or_default(),
+            "_yoast_wpseo_meta-robots-noindex": if meta.robots.index { "0" } else { "1" },
+            "_yoast_wpseo_meta-robots-nofollow": if meta.robots.follow { "0" } else { "1" },
+        });
+
+        if meta.use_custom_canonical {
+            if let Some(canonical) = &meta.canonical_url {
+                value["_yoast_wpseo_canonical"] = json!(canonical);
+            }
+        }
+
+        value
+    }
+
+    /// Build content meta from Yoast `_yoast_wpseo_*` post meta keys
+    pub fn from_wp_seo_json(value: &Value) -> SeoMeta {
+        let text = |key: &str| {
+            value.get(key)
+                .and_then(Value::as_str)
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(str::to_string)
+        };
+        // Yoast stores "1" for on, "2" or "0" for off/default
+        let flag = |key: &str| match value.get(key) {
+            Some(Value::String(s)) => s.trim() == "1",
+            Some(Value::Number(n)) => n.as_i64() == Some(1),
+            Some(Value::Bool(b)) => *b,
+            _ => false,
+        };
+
+        let mut meta = SeoMeta::new(Uuid::nil(), ContentType::Post);
+
+        meta.title = text("_yoast_wpseo_title");
+        meta.use_custom_title = meta.title.is_some();
+        meta.description = text("_yoast_wpseo_metadesc");
+        meta.use_custom_description = meta.description.is_some();
+        meta.focus_keyword = text("_yoast_wpseo_focuskw");
+        meta.robots.index = !flag("_yoast_wpseo_meta-robots-noindex");
+        meta.robots.follow = !flag("_yoast_wpseo_meta-robots-nofollow");
+        meta.canonical_url = text("_yoast_wpseo_canonical");
+        meta.use_custom_canonical = meta.canonical_url.is_some();
+
+        meta
+    }
+}
+
+/// JSON Merge Patch (RFC 7396) updates
+impl SeoSettings {
+    /// Apply a merge patch; `null` resets a field to its default
+    ///
+    /// `base` is left untouched when the patch fails to apply or validate.
+    pub fn apply_merge_patch(base: &mut SeoSettings, patch: Value) -> Result<(), PatchError> {
+        if !patch.is_object() {
+            return Err(PatchError::InvalidJson("merge patch must be a JSON object".to_string()));
+        }
+
+        let mut target = serde_json::to_value(&*base).map_err(|e| PatchError::InvalidJson(e.to_string()))?;
+        let defaults = serde_json::to_value(SeoSettings::default())
+            .map_err(|e| PatchError::InvalidJson(e.to_string()))?;
+        merge_patch(&mut target, Some(&defaults), patch);
+
+        let patched: SeoSettings = serde_json::from_value(target)
+            .map_err(|e| PatchError::InvalidJson(e.to_string()))?;
+
+        let validation = patched.validate();
+        if !validation.valid {
+            return Err(PatchError::ValidationFailed(validation));
+        }
+
+        *base = patched;
+        Ok(())
+    }
+}
+
+/// Merge `patch` into `target`, resetting nulled keys from `defaults`
+fn merge_patch(target: &mut Value, defaults: Option<&Value>, patch: Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch;
+        return;
+    };
+    if !target.is_object() {
+        *target = json!({});
+    }
+    let Some(map) = target.as_object_mut() else {
+        return;
+    };
+
+    for (key, value) in patch {
+        let default = defaults.and_then(|d| d.get(&key));
+        match value {
+            Value::Null => match default {
+                Some(default) => {
+                    map.insert(key, default.clone());
+                }
+                None => {
+                    map.remove(&key);
+                }
+            },
+            value => merge_patch(map.entry(key).or_insert(Value::Null), default, value),
+        }
+    }
+}
+
+/// Settings merge patch failure
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum PatchError {
+    #[error("invalid merge patch: {0}")]
+    InvalidJson(String),
+    #[error("patched settings are invalid: {}", .0.error_messages().join("; "))]
+    ValidationFailed(ValidationResult),
+}
+
+/// Migration of settings stored by older plugin versions
+impl SeoSettings {
+    /// Upgrade a settings blob stored by plugin `version` to the current layout
+    ///
+    /// Fields missing from the blob take their defaults. Renames made before 1.0:
+    /// `titles.separator` moved to `separator`, `sitemap.max_urls` became
+    /// `sitemap.max_entries_per_sitemap`, `social.twitter.username` became
+    /// `site_username` with a leading `@`, and `advanced.cache_ttl_minutes`
+    /// became `cache_ttl` in seconds.
+    pub fn migrate(version: &str, raw: Value) -> Result<SeoSettings, MigrationError> {
+        let from = parse_version(version).ok_or_else(|| MigrationError::InvalidVersion(version.to_string()))?;
+        let current = parse_version(crate::VERSION).unwrap_or((1, 0, 0));
+        if from > current {
+            return Err(MigrationError::UnsupportedVersion(version.to_string()));
+        }
+
+        let Value::Object(mut root) = raw else {
+            return Err(MigrationError::InvalidSettings("settings must be a JSON object".to_string()));
+        };
+
+        if from < (1, 0, 0) {
+            if let Some(separator) = root.get_mut("titles")
+                .and_then(Value::as_object_mut)
+                .and_then(|titles| titles.remove("separator"))
+            {
+                root.entry("separator").or_insert(separator);
+            }
+
+            if let Some(sitemap) = root.get_mut("sitemap").and_then(Value::as_object_mut) {
+                if let Some(max_urls) = sitemap.remove("max_urls") {
+                    sitemap.entry("max_entries_per_sitemap").or_insert(max_urls);
+                }
+            }
+
+            if let Some(twitter) = root.get_mut("social")
+                .and_then(|social| social.get_mut("twitter"))
+                .and_then(Value::as_object_mut)
+            {
+                if let Some(username) = twitter.remove("username") {
+                    let username = username.as_str()
+                        .filter(|u| !u.is_empty())
+                        .map(|u| json!(format!("@{}", u.trim_start_matches('@'))))
+                        .unwrap_or(Value::Null);
+                    twitter.entry("site_username").or_insert(username);
+                }
+            }
+
+            if let Some(advanced) = root.get_mut("advanced").and_then(Value::as_object_mut) {
+                if let Some(minutes) = advanced.remove("cache_ttl_minutes").and_then(|m| m.as_i64()) {
+                    advanced.entry("cache_ttl").or_insert(json!(minutes * 60));
+                }
+            }
+        }
+
+        serde_json::from_value(Value::Object(root)).map_err(|e| MigrationError::InvalidSettings(e.to_string()))
+    }
+}
+
+/// `major.minor.patch` from a version string such as `"0.9"` or `"v1.0.2"`
+fn parse_version(version: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = version.trim().trim_start_matches('v').split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next().map_or(Some(0), |p| p.parse().ok())?;
+    let patch = parts.next().map_or(Some(0), |p| p.parse().ok())?;
+    Some((major, minor, patch))
+}
+
+/// Settings migration failure
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum MigrationError {
+    #[error("invalid settings version '{0}'")]
+    InvalidVersion(String),
+    #[error("settings from version {0} are newer than this plugin")]
+    UnsupportedVersion(String),
+    #[error("stored settings are invalid: {0}")]
+    InvalidSettings(String),
+}
+
+/// JSON Schema (draft-07) for settings forms
+impl SeoSettings {
+    /// Separators offered in the title settings UI
+    pub const SEPARATORS: [&'static str; 8] = [" - ", " | ", " – ", " — ", " · ", " • ", " » ", " / "];
+
+    /// Hand-authored JSON Schema describing `SeoSettings`
+    pub fn to_json_schema() -> Value {
+        let boolean = |description: &str| json!({ "type": "boolean", "description": description });
+        let text = |description: &str, max: u32| json!({
+            "type": "string", "description": description, "maxLength": max,
+        });
+        let optional_url = |description: &str| json!({
+            "type": ["string", "null"], "description": format!("{}, starting with https://", description),
+            "maxLength": 2048, "pattern": "^(https://.+)?$",
+        });
+        let string_list = |description: &str| json!({
+            "type": "array", "description": description, "items": { "type": "string", "maxLength": 2048 },
+        });
+
+        let general = json!({
+            "type": "object",
+            "title": "General",
+            "properties": {
+                "enable_seo_analysis": boolean("Run SEO analysis in the editor"),
+                "enable_readability_analysis": boolean("Run readability analysis in the editor"),
+                "show_seo_metabox": boolean("Show the SEO metabox on edit screens"),
+                "default_robots_index": boolean("Index new content by default"),
+                "default_robots_follow": boolean("Follow links in new content by default"),
+                "strip_category_base": boolean("Remove the category base from URLs"),
+                "redirect_attachment_pages": boolean("Redirect attachment pages to their parent"),
+            },
+        });
+
+        let titles = json!({
+            "type": "object",
+            "title": "Titles",
+            "properties": {
+                "rewrite_titles": boolean("Rewrite document titles from templates"),
+                "force_rewrite": boolean("Override titles set by the theme"),
+                "home_title": text("Home page title template", 200),
+                "post_title": text("Post title template", 200),
+                "page_title": text("Page title template", 200),
+                "category_title": text("Category archive title template", 200),
+                "tag_title": text("Tag archive title template", 200),
+                "author_title": text("Author archive title template", 200),
+                "date_title": text("Date archive title template", 200),
+                "search_title": text("Search results title template", 200),
+                "not_found_title": text("404 page title template", 200),
+            },
+        });
+
+        let meta = json!({
+            "type": "object",
+            "title": "Meta",
+            "properties": {
+                "generate_description": boolean("Generate descriptions when none is set"),
+                "description_length": {
+                    "type": "integer",
+                    "description": "Maximum generated description length",
+                    "minimum": 50,
+                    "maximum": 320,
+                },
+                "use_excerpt_as_description": boolean("Prefer the excerpt for descriptions"),
+                "add_canonical": boolean("Output canonical links"),
+                "add_next_prev": boolean("Output rel next/prev links"),
+                "noindex_subpages": boolean("Noindex paginated subpages"),
+                "noindex_search": boolean("Noindex search result pages"),
+                "noindex_archives": boolean("Noindex archive pages"),
+                "canonical_strategy": {
+                    "description": "Trailing slash handling for canonical URLs, or a custom regex transform",
+                    "oneOf": [
+                        { "type": "string", "enum": ["trailing_slash", "no_trailing_slash", "preserve"] },
+                        {
+                            "type": "object",
+                            "required": ["custom"],
+                            "properties": { "custom": { "type": "string", "minLength": 1, "maxLength": 500 } },
+                        },
+                    ],
+                },
+            },
+        });
+
+        let sitemap = json!({
+            "type": "object",
+            "title": "Sitemap",
+            "properties": {
+                "enabled": boolean("Generate XML sitemaps"),
+                "include_posts": boolean("Include posts"),
+                "include_pages": boolean("Include pages"),
+                "include_categories": boolean("Include categories"),
+                "include_tags": boolean("Include tags"),
+                "include_authors": boolean("Include author archives"),
+                "include_images": boolean("Include image entries"),
+                "max_entries_per_sitemap": {
+                    "type": "integer",
+                    "description": "URLs per sitemap file",
+                    "minimum": 1,
+                    "maximum": 50000,
+                },
+                "ping_on_publish": boolean("Notify search engines on publish"),
+                "excluded_posts": string_list("Post IDs excluded from sitemaps"),
+                "excluded_categories": string_list("Category IDs excluded from sitemaps"),
+            },
+        });
+
+        let schema = json!({
+            "type": "object",
+            "title": "Schema",
+            "properties": {
+                "enabled": boolean("Output JSON-LD structured data"),
+                "organization_type": {
+                    "type": "string",
+                    "description": "Whether the site represents an organization or a person",
+                    "enum": ["Organization", "Person"],
+                },
+                "organization_name": text("Organization or person name", 200),
+                "organization_logo": optional_url("Logo URL"),
+                "organization_url": optional_url("Organization URL"),
+                "social_profiles": string_list("Social profile URLs (sameAs)"),
+                "article_type": {
+                    "type": "string",
+                    "description": "Default Article schema type",
+                    "enum": ["Article", "BlogPosting", "NewsArticle", "TechArticle", "ScholarlyArticle"],
+                },
+                "enable_breadcrumbs": boolean("Output BreadcrumbList schema"),
+                "local_business": {
+                    "type": ["object", "null"],
+                    "description": "Local business details",
+                    "required": ["business_type", "name", "street_address", "city", "postal_code", "country"],
+                    "properties": {
+                        "business_type": text("Schema.org LocalBusiness subtype", 100),
+                        "name": text("Business name", 200),
+                        "street_address": text("Street address", 200),
+                        "city": text("City", 100),
+                        "state": text("State or region", 100),
+                        "postal_code": text("Postal code", 20),
+                        "country": text("Country", 100),
+                        "latitude": { "type": ["number", "null"], "minimum": -90, "maximum": 90 },
+                        "longitude": { "type": ["number", "null"], "minimum": -180, "maximum": 180 },
+                    },
+                },
+            },
+        });
+
+        let social = json!({
+            "type": "object",
+            "title": "Social",
+            "properties": {
+                "enabled": boolean("Output social meta tags"),
+                "default_image": optional_url("Fallback share image"),
+                "facebook": {
+                    "type": "object",
+                    "properties": {
+                        "opengraph_enabled": boolean("Output Open Graph tags"),
+                        "app_id": { "type": ["string", "null"], "maxLength": 64 },
+                        "admin_id": { "type": ["string", "null"], "maxLength": 64 },
+                        "default_image": optional_url("Fallback Open Graph image"),
+                    },
+                },
+                "twitter": {
+                    "type": "object",
+                    "properties": {
+                        "cards_enabled": boolean("Output Twitter card tags"),
+                        "card_type": {
+                            "type": "string",
+                            "description": "Default Twitter card type",
+                            "enum": ["summary", "summary_large_image", "app", "player"],
+                        },
+                        "site_username": { "type": ["string", "null"], "maxLength": 16 },
+                        "default_image": optional_url("Fallback Twitter image"),
+                    },
+                },
+                "mastodon": {
+                    "type": ["string", "null"],
+                    "description": "Mastodon account, @user@instance or profile URL",
+                    "maxLength": 200,
+                },
+            },
+        });
+
+        let redirects = json!({
+            "type": "object",
+            "title": "Redirects",
+            "properties": {
+                "enabled": boolean("Process redirect rules"),
+                "log_404s": boolean("Log 404 errors"),
+                "max_404_logs": {
+                    "type": "integer",
+                    "description": "404 log entries to keep",
+                    "minimum": 0,
+                    "maximum": 100000,
+                },
+                "case_insensitive": boolean("Match source URLs case-insensitively"),
+                "auto_redirect_slug_change": boolean("Add a redirect when a slug changes"),
+                "max_redirect_chain": {
+                    "type": "integer",
+                    "description": "Hops a redirect chain may have before it is flagged",
+                    "minimum": 1,
+                    "maximum": 20,
+                },
+            },
+        });
+
+        let robots = json!({
+            "type": "object",
+            "title": "Robots.txt",
+            "properties": {
+                "enabled": boolean("Serve a generated robots.txt"),
+                "custom_rules": text("Extra robots.txt rules", 10000),
+                "block_ai_crawlers": boolean("Disallow known AI crawlers"),
+                "include_sitemap": boolean("Reference the sitemap index"),
+            },
+        });
+
+        let advanced = json!({
+            "type": "object",
+            "title": "Advanced",
+            "properties": {
+                "clean_permalinks": boolean("Strip unregistered query parameters"),
+                "remove_replytocom": boolean("Remove ?replytocom parameters"),
+                "redirect_ugly_urls": boolean("Redirect ?p= URLs to permalinks"),
+                "remove_feed_links": boolean("Remove feed links from the head"),
+                "remove_rsd_link": boolean("Remove the RSD link"),
+                "remove_wlw_link": boolean("Remove the WLW manifest link"),
+                "remove_shortlink": boolean("Remove the shortlink"),
+                "disable_author_archives": boolean("Disable author archives"),
+                "disable_date_archives": boolean("Disable date archives"),
+                "output_head_clean": boolean("Strip unrelated tags from the head"),
+                "cache_enabled": boolean("Cache analysis results"),
+                "cache_ttl": {
+                    "type": "integer",
+                    "description": "Cache lifetime in seconds",
+                    "minimum": 0,
+                    "maximum": 604800,
+                },
+            },
+        });
+
+        json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "$id": "https://rustpress.dev/schemas/rustseo-settings.json",
+            "title": "RustSEO Settings",
+            "description": "Site-wide SEO configuration for the RustSEO plugin",
+            "type": "object",
+            "required": [
+                "site_url", "site_name", "separator", "general", "titles", "meta",
+                "sitemap", "schema", "social", "redirects", "robots", "advanced",
+            ],
+            "properties": {
+                "site_url": {
+                    "type": "string",
+                    "title": "Site URL",
+                    "description": "Absolute base URL, starting with https://",
+                    "maxLength": 2048,
+                    "pattern": "^(https://.+)?$",
+                },
+                "site_name": {
+                    "type": "string",
+                    "title": "Site name",
+                    "description": "Name used in titles and structured data",
+                    "maxLength": 100,
+                },
+                "separator": {
+                    "type": "string",
+                    "title": "Title separator",
+                    "description": "Separator placed between title parts",
+                    "enum": Self::SEPARATORS,
+                },
+                "general": general,
+                "titles": titles,
+                "meta": meta,
+                "sitemap": sitemap,
+                "schema": schema,
+                "social": social,
+                "redirects": redirects,
+                "robots": robots,
+                "advanced": advanced,
+            },
+        })
+    }
+
+    /// Check settings JSON against `to_json_schema`, returning one message per problem
+    pub fn validate_against_schema(settings_json: &Value) -> Vec<String> {
+        let mut errors = Vec::new();
+        check_schema_node(settings_json, &Self::to_json_schema(), "$", &mut errors);
+        errors
+    }
+}
+
+/// Validate the subset of JSON Schema keywords used by `to_json_schema`
+fn check_schema_node(value: &Value, schema: &Value, path: &str, errors: &mut Vec<String>) {
+    if let Some(expected) = schema.get("type") {
+        let allowed: Vec<&str> = match expected {
+            Value::String(t) => vec![t.as_str()],
+            Value::Array(types) => types.iter().filter_map(Value::as_str).collect(),
+            _ => vec![],
+        };
+        let matches = allowed.iter().any(|t| match *t {
+            "object" => value.is_object(),
+            "array" => value.is_array(),
+            "string" => value.is_string(),
+            "boolean" => value.is_boolean(),
+            "integer" => value.is_i64() || value.is_u64(),
+            "number" => value.is_number(),
+            "null" => value.is_null(),
+            _ => true,
+        });
+        if !allowed.is_empty() && !matches {
+            errors.push(format!("{}: expected {}", path, allowed.join(" or ")));
+            return;
+        }
+    }
+
+    if let Some(options) = schema.get("enum").and_then(Value::as_array) {
+        if !options.contains(value) {
+            errors.push(format!("{}: {} is not one of the allowed values", path, value));
+        }
+    }
+
+    match value {
+        Value::Object(map) => {
+            if let Some(required) = schema.get("required").and_then(Value::as_array) {
+                for key in required.iter().filter_map(Value::as_str) {
+                    if !map.contains_key(key) {
+                        errors.push(format!("{}.{}: required property is missing", path, key));
+                    }
+                }
+            }
+            if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+                for (key, child_schema) in properties {
+                    if let Some(child) = map.get(key) {
+                        check_schema_node(child, child_schema, &format!("{}.{}", path, key), errors);
+                    }
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(item_schema) = schema.get("items") {
+                for (i, item) in items.iter().enumerate() {
+                    check_schema_node(item, item_schema, &format!("{}[{}]", path, i), errors);
+                }
+            }
+        }
+        Value::String(s) => {
+            let len = s.chars().count() as u64;
+            if let Some(min) = schema.get("minLength").and_then(Value::as_u64) {
+                if len < min {
+                    errors.push(format!("{}: must be at least {} characters", path, min));
+                }
+            }
+            if let Some(max) = schema.get("maxLength").and_then(Value::as_u64) {
+                if len > max {
+                    errors.push(format!("{}: must be at most {} characters", path, max));
+                }
+            }
+            if let Some(pattern) = schema.get("pattern").and_then(Value::as_str) {
+                if let Ok(re) = regex::Regex::new(pattern) {
+                    if !re.is_match(s) {
+                        errors.push(format!("{}: does not match pattern {}", path, pattern));
+                    }
+                }
+            }
+        }
+        Value::Number(n) => {
+            let n = n.as_f64().unwrap_or_default();
+            if let Some(min) = schema.get("minimum").and_then(Value::as_f64) {
+                if n < min {
+                    errors.push(format!("{}: must be at least {}", path, min));
+                }
+            }
+            if let Some(max) = schema.get("maximum").and_then(Value::as_f64) {
+                if n > max {
+                    errors.push(format!("{}: must be at most {}", path, max));
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Single-field validation for live form feedback
+impl SeoSettings {
+    /// Validate one setting addressed by its dotted path, e.g. `"meta.description_length"`
+    ///
+    /// Fields without a dedicated validator are checked against the JSON schema.
+    pub fn validate_partial(field_path: &str, value: Value) -> ValidationResult {
+        let mut result = Self::validate_field_value(field_path, value);
+        for issue in result.errors.iter_mut().chain(result.warnings.iter_mut()) {
+            issue.field.get_or_insert_with(|| field_path.to_string());
+        }
+        result
+    }
+
+    fn validate_field_value(field_path: &str, value: Value) -> ValidationResult {
+        if let Some(validator) = field_validators().get(field_path) {
+            return validator(value);
+        }
+
+        let schema = Self::to_json_schema();
+        let node = field_path
+            .split('.')
+            .try_fold(&schema, |node, key| node.get("properties").and_then(|p| p.get(key)));
+        match node {
+            Some(node) => {
+                let mut errors = Vec::new();
+                check_schema_node(&value, node, field_path, &mut errors);
+                ValidationResult::from_messages(errors, vec![])
+            }
+            None => ValidationResult::from_messages(vec![format!("{}: unknown setting", field_path)], vec![]),
+        }
+    }
+}
+
+type FieldValidator = Box<dyn Fn(Value) -> ValidationResult + Send + Sync>;
+
+/// Title template fields that accept `%%variable%%` placeholders
+const TITLE_TEMPLATE_FIELDS: [&str; 9] = [
+    "titles.home_title",
+    "titles.post_title",
+    "titles.page_title",
+    "titles.category_title",
+    "titles.tag_title",
+    "titles.author_title",
+    "titles.date_title",
+    "titles.search_title",
+    "titles.not_found_title",
+];
+
+/// Dispatch table from field path to its validator, built on first use
+fn field_validators() -> &'static HashMap<&'static str, FieldValidator> {
+    static VALIDATORS: OnceLock<HashMap<&'static str, FieldValidator>> = OnceLock::new();
+
+    VALIDATORS.get_or_init(|| {
+        let mut table: HashMap<&'static str, FieldValidator> = HashMap::new();
+
+        table.insert("site_url", Box::new(|value| match value.as_str() {
+            None => ValidationResult::type_error("site_url", "a string"),
+            Some("") => ValidationResult::from_messages(vec![], vec!["Site URL is not set".to_string()]),
+            Some(url) if !url.starts_with("https://") => {
+                ValidationResult::from_messages(vec!["Site URL must start with https://".to_string()], vec![])
+            }
+            Some(_) => ValidationResult::from_messages(vec![], vec![]),
+        }));
+
+        table.insert("site_name", Box::new(|value| match value.as_str() {
+            None => ValidationResult::type_error("site_name", "a string"),
+            Some(name) if name.trim().is_empty() => {
+                ValidationResult::from_messages(vec![], vec!["Site name is not set".to_string()])
+            }
+            Some(_) => ValidationResult::from_messages(vec![], vec![]),
+        }));
+
+        table.insert("separator", Box::new(|value| match value.as_str() {
+            None => ValidationResult::type_error("separator", "a string"),
+            Some(sep) if !SeoSettings::SEPARATORS.contains(&sep) => ValidationResult::from_messages(
+                vec![],
+                vec![format!("\"{}\" is not one of the standard separators", sep)],
+            ),
+            Some(_) => ValidationResult::from_messages(vec![], vec![]),
+        }));
+
+        for field in TITLE_TEMPLATE_FIELDS {
+            table.insert(field, Box::new(move |value| match value.as_str() {
+                None => ValidationResult::type_error(field, "a string"),
+                Some(template) => validate_title_template(template),
+            }));
+        }
+
+        table.insert("meta.description_length", Box::new(|value| match value.as_i64() {
+            None => ValidationResult::type_error("meta.description_length", "an integer"),
+            Some(length) if length <= 0 => ValidationResult::from_messages(
+                vec!["Meta description length must be positive".to_string()],
+                vec![],
+            ),
+            Some(length) if length < 50 => {
+                ValidationResult::from_messages(vec![], vec!["Meta description length is very short".to_string()])
+            }
+            Some(length) if length > 320 => {
+                ValidationResult::from_messages(vec![], vec!["Meta description length is very long".to_string()])
+            }
+            Some(_) => ValidationResult::from_messages(vec![], vec![]),
+        }));
+
+        table.insert("sitemap.max_entries_per_sitemap", Box::new(|value| match value.as_i64() {
+            None => ValidationResult::type_error("sitemap.max_entries_per_sitemap", "an integer"),
+            Some(entries) if !(1..=50000).contains(&entries) => ValidationResult::from_messages(
+                vec!["Sitemap max entries must be between 1 and 50000".to_string()],
+                vec![],
+            ),
+            Some(_) => ValidationResult::from_messages(vec![], vec![]),
+        }));
+
+        table.insert("redirects.max_404_logs", Box::new(|value| match value.as_i64() {
+            None => ValidationResult::type_error("redirects.max_404_logs", "an integer"),
+            Some(logs) if logs < 0 => {
+                ValidationResult::from_messages(vec!["404 log limit cannot be negative".to_string()], vec![])
+            }
+            Some(_) => ValidationResult::from_messages(vec![], vec![]),
+        }));
+
+        table.insert("redirects.max_redirect_chain", Box::new(|value| match value.as_i64() {
+            None => ValidationResult::type_error("redirects.max_redirect_chain", "an integer"),
+            Some(hops) if !(1..=20).contains(&hops) => ValidationResult::from_messages(
+                vec!["Maximum redirect chain must be between 1 and 20".to_string()],
+                vec![],
+            ),
+            Some(_) => ValidationResult::from_messages(vec![], vec![]),
+        }));
+
+        table.insert("advanced.cache_ttl", Box::new(|value| match value.as_i64() {
+            None => ValidationResult::type_error("advanced.cache_ttl", "an integer"),
+            Some(ttl) if ttl < 0 => {
+                ValidationResult::from_messages(vec!["Cache TTL cannot be negative".to_string()], vec![])
+            }
+            Some(ttl) if ttl < 60 => {
+                ValidationResult::from_messages(vec![], vec!["Cache TTL under a minute adds little benefit".to_string()])
+            }
+            Some(_) => ValidationResult::from_messages(vec![], vec![]),
+        }));
+
+        table.insert("meta.canonical_strategy", Box::new(|value| {
+            match serde_json::from_value::<CanonicalStrategy>(value) {
+                Ok(CanonicalStrategy::Custom(rule)) => {
+                    let pattern = rule.split("=>").next().unwrap_or_default().trim();
+                    match regex::Regex::new(pattern) {
+                        Ok(_) => ValidationResult::from_messages(vec![], vec![]),
+                        Err(e) => ValidationResult::from_messages(
+                            vec![format!("Invalid canonical pattern: {}", e)],
+                            vec![],
+                        ),
+                    }
+                }
+                Ok(_) => ValidationResult::from_messages(vec![], vec![]),
+                Err(e) => ValidationResult::from_messages(
+                    vec![format!("meta.canonical_strategy: {}", e)],
+                    vec![],
+                ),
+            }
+        }));
+
+        table
+    })
+}
+
+/// Flag broken `%%variables%%` and templates that would render empty
+fn validate_title_template(template: &str) -> ValidationResult {
+    let mut warnings = Vec::new();
+    if template.trim().is_empty() {
+        warnings.push("Title template is empty; the default title will be used".to_string());
+    }
+
+    ValidationResult::from_messages(crate::admin::settings::ContentTypeSeo::validate_template(template), warnings)
+}
+
+/// Apply the canonical strategy to a URL
+pub fn normalize_canonical_url(url: &str, strategy: &CanonicalStrategy) -> String {
+    let url = url.trim();
+
+    match strategy {
+        CanonicalStrategy::Preserve => url.to_string(),
+        CanonicalStrategy::TrailingSlash | CanonicalStrategy::NoTrailingSlash => {
+            let split = url.find(['?', '#']).unwrap_or(url.len());
+            let (path, suffix) = url.split_at(split);
+            // Leave the slash after the host alone
+            let is_root = path.split_once("://")
+                .map(|(_, rest)| !rest.trim_end_matches('/').contains('/'))
+                .unwrap_or(path == "/");
+            let last_segment = path.trim_end_matches('/').rsplit('/').next().unwrap_or_default();
+
+            let path = if *strategy == CanonicalStrategy::TrailingSlash {
+                if path.ends_with('/') || (!is_root && last_segment.contains('.')) {
+                    path.to_string()
+                } else {
+                    format!("{}/", path)
+                }
+            } else if is_root {
+                path.to_string()
+            } else {
+                path.trim_end_matches('/').to_string()
+            };
+
+            format!("{}{}", path, suffix)
+        }
+        CanonicalStrategy::Custom(rule) => {
+            let (pattern, replacement) = rule.split_once(" => ").unwrap_or((rule.as_str(), ""));
+            match regex::Regex::new(pattern.trim()) {
+                Ok(re) => re.replace_all(url, replacement.trim()).into_owned(),
+                Err(e) => {
+                    tracing::warn!("Invalid custom canonical rule '{}': {}", rule, e);
+                    url.to_string()
+                }
+            }
+        }
+    }
+}
+
+/// Page context used for robots decisions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContentContext {
+    pub content_type: ContentType,
+    /// Paginated subpage (page 2 onwards)
+    pub is_paginated: bool,
+    pub is_search: bool,
+    /// Date archive
+    pub is_archive: bool,
+    pub is_404: bool,
+}
+
+impl ContentContext {
+    pub fn new(content_type: ContentType) -> Self {
+        Self {
+            content_type,
+            is_paginated: false,
+            is_search: false,
+            is_archive: false,
+            is_404: false,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidationResult {
     pub valid: bool,
-    pub errors: Vec<String>,
-    pub warnings: Vec<String>,
+    pub errors: Vec<ValidationIssue>,
+    pub warnings: Vec<ValidationIssue>,
+}
+
+/// A validation error or warning, optionally tied to a settings field
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidationIssue {
+    /// Dotted path of the offending field, e.g. `"social.facebook.app_id"`
+    pub field: Option<String>,
+    pub message: String,
+}
+
+impl ValidationIssue {
+    pub fn new(message: &str) -> Self {
+        Self {
+            field: None,
+            message: message.to_string(),
+        }
+    }
+
+    pub fn field(field: &str, message: &str) -> Self {
+        Self {
+            field: Some(field.to_string()),
+            message: message.to_string(),
+        }
+    }
+}
+
+impl ValidationResult {
+    /// Result that is valid when there are no errors
+    pub fn from_messages(errors: Vec<String>, warnings: Vec<String>) -> Self {
+        let issues = |messages: Vec<String>| messages.iter().map(|m| ValidationIssue::new(m)).collect::<Vec<_>>();
+        Self {
+            valid: errors.is_empty(),
+            errors: issues(errors),
+            warnings: issues(warnings),
+        }
+    }
+
+    /// Error messages without their fields
+    pub fn error_messages(&self) -> Vec<&str> {
+        self.errors.iter().map(|e| e.message.as_str()).collect()
+    }
+
+    fn type_error(field: &str, expected: &str) -> Self {
+        Self::from_messages(vec![format!("{}: expected {}", field, expected)], vec![])
+    }
+}
+
+/// Source of values for `%%variable%%` placeholders in title templates
+pub trait TitleVariableResolver: Send + Sync {
+    /// Value for a variable, named without its `%%` markers
+    fn resolve(&self, variable: &str, context: &TitleContext) -> Option<String>;
+
+    /// Variables this resolver can fill in
+    fn variables(&self) -> Vec<TitleVariable>;
+}
+
+/// Resolver for the built-in title variables
+pub struct DefaultTitleVariableResolver;
+
+impl TitleVariableResolver for DefaultTitleVariableResolver {
+    fn resolve(&self, variable: &str, context: &TitleContext) -> Option<String> {
+        let value = match variable {
+            "sitename" => context.site_name.clone(),
+            "tagline" => context.tagline.clone(),
+            "title" => context.title.clone(),
+            "sep" => context.separator.clone(),
+            "excerpt" => context.excerpt.clone(),
+            "term_title" => context.term_title.clone(),
+            "name" => context.author_name.clone(),
+            "date" => context.date.clone(),
+            "searchphrase" => context.search_phrase.clone(),
+            "page" => context.page.to_string(),
+            "currentyear" => context.current_year.clone(),
+            "currentmonth" => context.current_month.clone(),
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    fn variables(&self) -> Vec<TitleVariable> {
+        [
+            ("%%sitename%%", "Site name"),
+            ("%%tagline%%", "Site tagline"),
+            ("%%title%%", "Post/page title"),
+            ("%%sep%%", "Separator"),
+            ("%%excerpt%%", "Post excerpt"),
+            ("%%term_title%%", "Category/tag name"),
+            ("%%name%%", "Author name"),
+            ("%%date%%", "Date"),
+            ("%%searchphrase%%", "Search query"),
+            ("%%page%%", "Page number"),
+            ("%%currentyear%%", "Current year"),
+            ("%%currentmonth%%", "Current month"),
+        ]
+        .into_iter()
+        .map(|(variable, description)| TitleVariable {
+            variable: variable.to_string(),
+            description: description.to_string(),
+        })
+        .collect()
+    }
+}
+
+/// Registered resolvers, built-in variables first
+fn title_resolvers() -> &'static RwLock<Vec<Box<dyn TitleVariableResolver>>> {
+    static RESOLVERS: OnceLock<RwLock<Vec<Box<dyn TitleVariableResolver>>>> = OnceLock::new();
+    RESOLVERS.get_or_init(|| RwLock::new(vec![Box::new(DefaultTitleVariableResolver)]))
+}
+
+/// Add a resolver for custom title variables
+///
+/// Resolvers are asked in registration order, so built-in variables cannot be overridden.
+pub fn register_title_resolver(resolver: Box<dyn TitleVariableResolver>) {
+    title_resolvers()
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(resolver);
 }
 
 /// Title variable replacements
 pub fn get_title_variables() -> Vec<TitleVariable> {
-    vec![
-        TitleVariable {
-            variable: "%%sitename%%".to_string(),
-            description: "Site name".to_string(),
-        },
-        TitleVariable {
-            variable: "%%tagline%%".to_string(),
-            description: "Site tagline".to_string(),
-        },
-        TitleVariable {
-            variable: "%%title%%".to_string(),
-            description: "Post/page title".to_string(),
-        },
-        TitleVariable {
-            variable: "%%sep%%".to_string(),
-            description: "Separator".to_string(),
-        },
-        TitleVariable {
-            variable: "%%excerpt%%".to_string(),
-            description: "Post excerpt".to_string(),
-        },
-        TitleVariable {
-            variable: "%%term_title%%".to_string(),
-            description: "Category/tag name".to_string(),
-        },
-        TitleVariable {
-            variable: "%%name%%".to_string(),
-            description: "Author name".to_string(),
-        },
-        TitleVariable {
-            variable: "%%date%%".to_string(),
-            description: "Date".to_string(),
-        },
-        TitleVariable {
-            variable: "%%searchphrase%%".to_string(),
-            description: "Search query".to_string(),
-        },
-        TitleVariable {
-            variable: "%%page%%".to_string(),
-            description: "Page number".to_string(),
-        },
-        TitleVariable {
-            variable: "%%currentyear%%".to_string(),
-            description: "Current year".to_string(),
-        },
-        TitleVariable {
-            variable: "%%currentmonth%%".to_string(),
-            description: "Current month".to_string(),
-        },
-    ]
+    let resolvers = title_resolvers().read().unwrap_or_else(PoisonError::into_inner);
+    let mut variables: Vec<TitleVariable> = Vec::new();
+
+    for variable in resolvers.iter().flat_map(|r| r.variables()) {
+        if !variables.iter().any(|v| v.variable == variable.variable) {
+            variables.push(variable);
+        }
+    }
+
+    variables
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -450,24 +1513,37 @@ pub struct TitleVariable {
 }
 
 /// Replace title variables with actual values
+///
+/// Variables no resolver recognises are left in place.
 pub fn replace_title_variables(
     template: &str,
     context: &TitleContext,
 ) -> String {
-    let mut result = template.to_string();
-
-    result = result.replace("%%sitename%%", &context.site_name);
-    result = result.replace("%%tagline%%", &context.tagline);
-    result = result.replace("%%title%%", &context.title);
-    result = result.replace("%%sep%%", &context.separator);
-    result = result.replace("%%excerpt%%", &context.excerpt);
-    result = result.replace("%%term_title%%", &context.term_title);
-    result = result.replace("%%name%%", &context.author_name);
-    result = result.replace("%%date%%", &context.date);
-    result = result.replace("%%searchphrase%%", &context.search_phrase);
-    result = result.replace("%%page%%", &context.page.to_string());
-    result = result.replace("%%currentyear%%", &context.current_year);
-    result = result.replace("%%currentmonth%%", &context.current_month);
+    let resolvers = title_resolvers().read().unwrap_or_else(PoisonError::into_inner);
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("%%") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("%%") else {
+            break;
+        };
+
+        result.push_str(&rest[..start]);
+        let name = &after[..end];
+        match resolvers.iter().find_map(|r| r.resolve(name, context)) {
+            Some(value) => {
+                result.push_str(&value);
+                rest = &after[end + 2..];
+            }
+            None => {
+                // The closing marker may open the next variable
+                result.push_str("%%");
+                rest = after;
+            }
+        }
+    }
+    result.push_str(rest);
 
     result
 }
@@ -487,3 +1563,252 @@ pub struct TitleContext {
     pub current_year: String,
     pub current_month: String,
 }
+
+impl TitleContext {
+    /// Build a context for a content item from its meta and the site settings
+    ///
+    /// The excerpt comes from the meta description and the date from when the
+    /// meta was created. Term title is only set for category and tag archives.
+    pub fn from_content(meta: &SeoMeta, site_settings: &SeoSettings, content_title: &str) -> Self {
+        let now = chrono::Utc::now();
+        let term_title = match meta.content_type {
+            ContentType::Category | ContentType::Tag => content_title.to_string(),
+            _ => String::new(),
+        };
+
+        Self {
+            site_name: site_settings.site_name.clone(),
+            tagline: String::new(),
+            title: content_title.to_string(),
+            separator: site_settings.separator.clone(),
+            excerpt: meta.description.clone().unwrap_or_default(),
+            term_title,
+            author_name: String::new(),
+            date: meta.created_at.format("%B %-d, %Y").to_string(),
+            search_phrase: String::new(),
+            page: 1,
+            current_year: now.format("%Y").to_string(),
+            current_month: now.format("%B").to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_title_context_from_content() {
+        let mut meta = SeoMeta::new(Uuid::nil(), ContentType::Post);
+        meta.description = Some("A short guide".to_string());
+        let settings = SeoSettings {
+            site_name: "Example".to_string(),
+            separator: "|".to_string(),
+            ..SeoSettings::default()
+        };
+
+        let context = TitleContext::from_content(&meta, &settings, "Hello World");
+        assert_eq!(
+            replace_title_variables("%%title%% %%sep%% %%sitename%%", &context),
+            "Hello World | Example"
+        );
+        assert_eq!(replace_title_variables("%%excerpt%%", &context), "A short guide");
+        assert!(context.term_title.is_empty());
+    }
+
+    struct ShopResolver;
+
+    impl TitleVariableResolver for ShopResolver {
+        fn resolve(&self, variable: &str, _context: &TitleContext) -> Option<String> {
+            (variable == "shop_name").then(|| "Acme Store".to_string())
+        }
+
+        fn variables(&self) -> Vec<TitleVariable> {
+            vec![TitleVariable {
+                variable: "%%shop_name%%".to_string(),
+                description: "Shop name".to_string(),
+            }]
+        }
+    }
+
+    #[test]
+    fn test_custom_title_resolver() {
+        register_title_resolver(Box::new(ShopResolver));
+        let context = TitleContext {
+            title: "Boots".to_string(),
+            separator: "-".to_string(),
+            ..TitleContext::default()
+        };
+
+        assert_eq!(
+            replace_title_variables("%%title%% %%sep%% %%shop_name%% %%unknown%%", &context),
+            "Boots - Acme Store %%unknown%%"
+        );
+        assert!(get_title_variables().iter().any(|v| v.variable == "%%shop_name%%"));
+        assert!(get_title_variables().len() >= 13);
+    }
+
+    #[test]
+    fn test_validate_reports_fields() {
+        let mut settings = SeoSettings {
+            site_url: "http://example.com".to_string(),
+            site_name: "Example".to_string(),
+            ..SeoSettings::default()
+        };
+        settings.social.facebook.app_id = Some("12ab".to_string());
+        settings.social.twitter.site_username = Some("example".to_string());
+        settings.sitemap.max_entries_per_sitemap = 0;
+        settings.redirects.max_redirect_chain = 25;
+        settings.schema.organization_logo = Some("http://example.com/logo.png".to_string());
+
+        let result = settings.validate();
+        let fields: Vec<&str> = result.errors.iter().filter_map(|e| e.field.as_deref()).collect();
+        assert!(!result.valid);
+        assert!(result.warnings.iter().any(|w| w.field.as_deref() == Some("schema.organization_name")));
+        assert_eq!(fields, vec![
+            "site_url",
+            "sitemap.max_entries_per_sitemap",
+            "social.facebook.app_id",
+            "social.twitter.site_username",
+            "redirects.max_redirect_chain",
+            "schema.organization_logo",
+        ]);
+
+        settings.site_url = "https://example.com".to_string();
+        settings.social.facebook.app_id = Some("1234567890".to_string());
+        settings.social.twitter.site_username = Some("@example".to_string());
+        settings.sitemap.max_entries_per_sitemap = 1000;
+        settings.redirects.max_redirect_chain = 5;
+        settings.schema.organization_name = "Example Ltd".to_string();
+        settings.schema.organization_logo = Some("https://example.com/logo.png".to_string());
+        assert!(settings.validate().valid);
+    }
+
+    #[test]
+    fn test_default_settings_are_valid() {
+        let result = SeoSettings::default().validate();
+        assert!(result.valid, "{:?}", result.errors);
+    }
+
+    #[test]
+    fn test_json_schema_requires_https_urls() {
+        let mut settings = SeoSettings {
+            site_url: "http://example.com".to_string(),
+            ..SeoSettings::default()
+        };
+        settings.schema.organization_logo = Some("http://example.com/logo.png".to_string());
+
+        let errors = SeoSettings::validate_against_schema(&serde_json::to_value(&settings).unwrap());
+        assert!(errors.iter().any(|e| e.starts_with("$.site_url:")), "{:?}", errors);
+        assert!(errors.iter().any(|e| e.starts_with("$.schema.organization_logo:")), "{:?}", errors);
+
+        settings.site_url = "https://example.com".to_string();
+        settings.schema.organization_logo = Some("https://example.com/logo.png".to_string());
+        assert!(SeoSettings::validate_against_schema(&serde_json::to_value(&settings).unwrap()).is_empty());
+    }
+
+    #[test]
+    fn test_merge_patch_null_resets_to_default() {
+        let defaults = json!({"meta": {"description_length": 160, "generate_description": true}});
+        let mut target = json!({"meta": {"description_length": 120, "generate_description": false}, "extra": 1});
+
+        merge_patch(&mut target, Some(&defaults), json!({"meta": {"description_length": null}, "extra": null}));
+        assert_eq!(target, json!({"meta": {"description_length": 160, "generate_description": false}}));
+    }
+
+    #[test]
+    fn test_merge_patch_nested_objects() {
+        let mut target = json!({"social": {"twitter": {"site_username": "@old", "enabled": true}}});
+
+        merge_patch(&mut target, None, json!({"social": {"twitter": {"site_username": "@new"}, "enabled": false}}));
+        assert_eq!(target, json!({
+            "social": {"twitter": {"site_username": "@new", "enabled": true}, "enabled": false}
+        }));
+    }
+
+    #[test]
+    fn test_merge_patch_non_object_patch() {
+        let mut target = json!({"schema": {"social_profiles": ["https://a.example"]}});
+        merge_patch(&mut target, None, json!({"schema": {"social_profiles": ["https://b.example"]}}));
+        assert_eq!(target, json!({"schema": {"social_profiles": ["https://b.example"]}}));
+
+        merge_patch(&mut target, None, json!("replaced"));
+        assert_eq!(target, json!("replaced"));
+
+        let mut settings = SeoSettings::default();
+        assert!(matches!(
+            SeoSettings::apply_merge_patch(&mut settings, json!([1, 2])),
+            Err(PatchError::InvalidJson(_))
+        ));
+    }
+
+    #[test]
+    fn test_apply_merge_patch_keeps_unpatched_fields() {
+        let mut settings = SeoSettings {
+            site_name: "Example".to_string(),
+            ..SeoSettings::default()
+        };
+        settings.meta.description_length = 120;
+
+        SeoSettings::apply_merge_patch(&mut settings, json!({"site_url": "https://example.com"})).unwrap();
+        assert_eq!(settings.site_name, "Example");
+        assert_eq!(settings.meta.description_length, 120);
+        assert_eq!(settings.site_url, "https://example.com");
+    }
+
+    #[test]
+    fn test_validate_partial_sets_field() {
+        let result = SeoSettings::validate_partial("redirects.max_redirect_chain", json!(0));
+        assert_eq!(result.errors[0].field.as_deref(), Some("redirects.max_redirect_chain"));
+    }
+
+    #[test]
+    fn test_deserialize_fills_missing_fields() {
+        let settings: SeoSettings = serde_json::from_value(json!({
+            "site_url": "https://example.com",
+            "advanced": { "cache_enabled": false },
+        }))
+        .unwrap();
+
+        assert_eq!(settings.site_url, "https://example.com");
+        assert!(!settings.advanced.cache_enabled);
+        assert_eq!(settings.advanced.cache_ttl, AdvancedSettings::default().cache_ttl);
+        assert_eq!(settings.separator, " - ");
+    }
+
+    #[test]
+    fn test_migrate_from_v0_9() {
+        let blob = json!({
+            "site_url": "https://example.com",
+            "site_name": "Example",
+            "titles": { "separator": " | ", "post_title": "%%title%%" },
+            "sitemap": { "enabled": true, "max_urls": 2000 },
+            "social": { "twitter": { "username": "example" } },
+            "advanced": { "cache_ttl_minutes": 30 },
+        });
+
+        let settings = SeoSettings::migrate("0.9", blob).unwrap();
+        assert_eq!(settings.separator, " | ");
+        assert_eq!(settings.titles.post_title, "%%title%%");
+        assert_eq!(settings.titles.home_title, TitleSettings::default().home_title);
+        assert_eq!(settings.sitemap.max_entries_per_sitemap, 2000);
+        assert_eq!(settings.social.twitter.site_username.as_deref(), Some("@example"));
+        assert_eq!(settings.advanced.cache_ttl, 1800);
+        assert_eq!(settings.redirects.max_redirect_chain, 5);
+    }
+
+    #[test]
+    fn test_migrate_rejects_bad_input() {
+        assert!(matches!(SeoSettings::migrate("nine", json!({})), Err(MigrationError::InvalidVersion(_))));
+        assert!(matches!(SeoSettings::migrate("99.0", json!({})), Err(MigrationError::UnsupportedVersion(_))));
+        assert!(matches!(SeoSettings::migrate("1.0.0", json!([])), Err(MigrationError::InvalidSettings(_))));
+    }
+
+    #[test]
+    fn test_title_context_separator_defaults() {
+        let meta = SeoMeta::new(Uuid::nil(), ContentType::Category);
+        let context = TitleContext::from_content(&meta, &SeoSettings::default(), "News");
+
+        assert_eq!(replace_title_variables("%%term_title%%%%sep%%%%page%%", &context), "News - 1");
+    }
+}